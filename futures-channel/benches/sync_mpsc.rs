@@ -6,6 +6,7 @@ use crate::test::Bencher;
 use {
     futures::{
         channel::mpsc::{self, Sender, UnboundedSender},
+        executor::block_on_stream,
         ready,
         stream::{Stream, StreamExt},
         sink::Sink,
@@ -13,6 +14,7 @@ use {
     },
     futures_test::task::noop_context,
     std::pin::Pin,
+    std::thread,
 };
 
 /// Single producer, single consumer
@@ -74,6 +76,46 @@ fn unbounded_uncontended(b: &mut Bencher) {
 }
 
 
+/// Single producer, single consumer, sending everything before receiving
+#[bench]
+fn unbounded_burst(b: &mut Bencher) {
+    let mut cx = noop_context();
+    b.iter(|| {
+        let (tx, mut rx) = mpsc::unbounded();
+
+        for i in 0..1000 {
+            UnboundedSender::unbounded_send(&tx, i).expect("send");
+        }
+        for i in 0..1000 {
+            assert_eq!(Poll::Ready(Some(i)), rx.poll_next_unpin(&mut cx));
+        }
+    })
+}
+
+/// 4 producer threads, single consumer
+#[bench]
+fn unbounded_4_threads(b: &mut Bencher) {
+    b.iter(|| {
+        let (tx, rx) = mpsc::unbounded();
+
+        let threads: Vec<_> = (0..4).map(|_| {
+            let tx = tx.clone();
+            thread::spawn(move || {
+                for i in 0..250 {
+                    UnboundedSender::unbounded_send(&tx, i).expect("send");
+                }
+            })
+        }).collect();
+        drop(tx);
+
+        assert_eq!(block_on_stream(rx).count(), 1000);
+        for thread in threads {
+            thread.join().unwrap();
+        }
+    })
+}
+
+
 /// A Stream that continuously sends incrementing number of the queue
 struct TestSender {
     tx: Sender<u32>,
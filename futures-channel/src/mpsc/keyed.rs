@@ -0,0 +1,224 @@
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use std::pin::Pin;
+
+use super::{channel, Receiver, SendError, Sender, TryRecvError, TrySendError};
+
+/// Creates a group of `keys` bounded mpsc channels, which are sent to by key
+/// and received from by a single receiver.
+///
+/// Keys are the indices `0..keys`, so items with keys of another type can be
+/// routed by mapping each key to an index, for example by hashing it modulo
+/// `keys`. Each key has its own queue with its own `buffer`, with the same
+/// capacity model as a channel created with [`channel`](channel), so a key
+/// whose items aren't being received only blocks the senders of that key.
+///
+/// The [`KeyedReceiver`] returned implements the
+/// [`Stream`](futures_core::stream::Stream) trait, yielding `(key, item)`
+/// pairs and taking turns between the keys which have items ready, so that a
+/// busy key can't starve the others. It can also receive from a single key
+/// with [`poll_next_key`](KeyedReceiver::poll_next_key).
+///
+/// # Panics
+///
+/// Panics if `keys` is zero.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await)]
+/// # futures::executor::block_on(async {
+/// use futures::channel::mpsc;
+/// use futures::sink::SinkExt;
+/// use futures::stream::StreamExt;
+///
+/// let (mut tx, rx) = mpsc::channel_per_key::<&str>(2, 4);
+/// tx.for_key(0).send("a").await.unwrap();
+/// tx.for_key(0).send("b").await.unwrap();
+/// tx.for_key(1).send("c").await.unwrap();
+/// drop(tx);
+///
+/// // Keys take turns.
+/// let items: Vec<_> = rx.collect().await;
+/// assert_eq!(items, vec![(0, "a"), (1, "c"), (0, "b")]);
+/// # });
+/// ```
+pub fn channel_per_key<T>(keys: usize, buffer: usize) -> (KeyedSender<T>, KeyedReceiver<T>) {
+    assert!(keys > 0, "a keyed channel needs at least one key");
+    let (senders, receivers) = (0..keys).map(|_| channel(buffer)).unzip();
+    (KeyedSender { senders }, KeyedReceiver { receivers, next: 0 })
+}
+
+/// The transmission end of a keyed channel group.
+///
+/// This value is created by the [`channel_per_key`](channel_per_key)
+/// function. Cloning it clones the [`Sender`] of every key.
+#[derive(Debug)]
+pub struct KeyedSender<T> {
+    senders: Vec<Sender<T>>,
+}
+
+impl<T> Clone for KeyedSender<T> {
+    fn clone(&self) -> Self {
+        KeyedSender { senders: self.senders.clone() }
+    }
+}
+
+impl<T> KeyedSender<T> {
+    /// Returns the number of keys of the channel group.
+    pub fn keys(&self) -> usize {
+        self.senders.len()
+    }
+
+    /// Returns the sender of `key`, which implements `Sink`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is not less than the number of keys.
+    pub fn for_key(&mut self, key: usize) -> &mut Sender<T> {
+        &mut self.senders[key]
+    }
+
+    /// Attempts to send a message with the given key, see
+    /// [`Sender::try_send`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is not less than the number of keys.
+    pub fn try_send(&mut self, key: usize, msg: T) -> Result<(), TrySendError<T>> {
+        self.senders[key].try_send(msg)
+    }
+
+    /// Polls whether a message can be sent with the given key, see
+    /// [`Sender::poll_ready`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is not less than the number of keys.
+    pub fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+        key: usize,
+    ) -> Poll<Result<(), SendError>> {
+        self.senders[key].poll_ready(cx)
+    }
+
+    /// Sends a message with the given key, see [`Sender::start_send`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is not less than the number of keys.
+    pub fn start_send(&mut self, key: usize, msg: T) -> Result<(), SendError> {
+        self.senders[key].start_send(msg)
+    }
+
+    /// Returns whether the receiver has been closed or dropped.
+    pub fn is_closed(&self) -> bool {
+        self.senders.iter().all(Sender::is_closed)
+    }
+
+    /// Closes the channels of all keys.
+    pub fn close_channel(&mut self) {
+        for sender in &mut self.senders {
+            sender.close_channel();
+        }
+    }
+}
+
+/// The receiving end of a keyed channel group.
+///
+/// This value is created by the [`channel_per_key`](channel_per_key)
+/// function. As a stream, it ends once the channels of all keys have ended.
+#[derive(Debug)]
+pub struct KeyedReceiver<T> {
+    receivers: Vec<Receiver<T>>,
+    // The key to look at first on the next poll
+    next: usize,
+}
+
+impl<T> Unpin for KeyedReceiver<T> {}
+
+impl<T> KeyedReceiver<T> {
+    /// Returns the number of keys of the channel group.
+    pub fn keys(&self) -> usize {
+        self.receivers.len()
+    }
+
+    /// Closes the channels of all keys, without dropping the receiver.
+    ///
+    /// Like [`Receiver::close`], the messages which are already buffered can
+    /// still be received.
+    pub fn close(&mut self) {
+        for receiver in &mut self.receivers {
+            receiver.close();
+        }
+    }
+
+    /// Attempts to receive the next message with the given key.
+    ///
+    /// Returns `Poll::Ready(None)` once the channel of that key has ended,
+    /// including on later calls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is not less than the number of keys.
+    pub fn poll_next_key(&mut self, cx: &mut Context<'_>, key: usize) -> Poll<Option<T>> {
+        let receiver = &mut self.receivers[key];
+        if receiver.is_terminated() {
+            return Poll::Ready(None);
+        }
+        Pin::new(receiver).poll_next(cx)
+    }
+
+    /// Tries to receive the next message with the given key without
+    /// notifying a context if it is empty, see [`Receiver::try_next`].
+    ///
+    /// Returns `Ok(None)` once the channel of that key has ended, including
+    /// on later calls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is not less than the number of keys.
+    pub fn try_next_key(&mut self, key: usize) -> Result<Option<T>, TryRecvError> {
+        let receiver = &mut self.receivers[key];
+        if receiver.is_terminated() {
+            return Ok(None);
+        }
+        receiver.try_next()
+    }
+}
+
+impl<T> FusedStream for KeyedReceiver<T> {
+    fn is_terminated(&self) -> bool {
+        self.receivers.iter().all(Receiver::is_terminated)
+    }
+}
+
+impl<T> Stream for KeyedReceiver<T> {
+    type Item = (usize, T);
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<(usize, T)>> {
+        let keys = self.receivers.len();
+        let mut all_ended = true;
+        for i in 0..keys {
+            let key = (self.next + i) % keys;
+            match self.poll_next_key(cx, key) {
+                Poll::Ready(Some(msg)) => {
+                    // The keys after this one get the first look next time.
+                    self.next = (key + 1) % keys;
+                    return Poll::Ready(Some((key, msg)));
+                }
+                Poll::Ready(None) => {}
+                Poll::Pending => all_ended = false,
+            }
+        }
+        if all_ended {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
@@ -93,6 +93,9 @@ use crate::mpsc::queue::Queue;
 
 mod queue;
 
+mod keyed;
+pub use self::keyed::{channel_per_key, KeyedReceiver, KeyedSender};
+
 #[derive(Debug)]
 struct SenderInner<T> {
     // Channel state shared between the sender and receiver.
@@ -332,6 +335,24 @@ impl SenderTask {
 /// guaranteed slot in the channel capacity, and on top of that there are
 /// `buffer` "first come, first serve" slots available to all senders.
 ///
+/// # Capacity model
+///
+/// A sender is either *unparked*, in which case its guaranteed slot is free
+/// and its next send is certain to succeed, or *parked*, in which case its
+/// guaranteed slot holds a message that was sent while the shared slots were
+/// all taken. A send always succeeds while the sender is unparked: the
+/// message goes into a shared slot if one is free, and into the sender's
+/// guaranteed slot otherwise, parking the sender. A parked sender is
+/// unparked once the receiver has taken enough messages out of the channel
+/// to free up its guaranteed slot.
+///
+/// [`Sender::poll_ready`] only reports whether the sender is unparked. It
+/// does not reserve anything, so a sender that is polled ready and then
+/// dropped, or that never goes on to send, does not take capacity away from
+/// the other senders. Dropping a parked sender likewise does not leak its
+/// slot: the message it sent stays in the channel, and its slot is gone once
+/// the receiver takes that message.
+///
 /// The [`Receiver`](Receiver) returned implements the
 /// [`Stream`](futures_core::stream::Stream) trait, while [`Sender`](Sender) implements
 /// `Sink`.
@@ -613,7 +634,9 @@ impl<T> Sender<T> {
     ///
     /// This function should only be called after
     /// [`poll_ready`](Sender::poll_ready) has reported that the channel is
-    /// ready to receive a message.
+    /// ready to receive a message. If this sender has been parked in the
+    /// meantime, the message is rejected with an error for which
+    /// [`is_full`](SendError::is_full) returns `true`.
     pub fn start_send(&mut self, msg: T) -> Result<(), SendError> {
         self.try_send(msg)
             .map_err(|e| e.err)
@@ -631,6 +654,11 @@ impl<T> Sender<T> {
     ///   capacity, in which case the current task is queued to be notified once
     ///   capacity is available;
     /// - `Poll::Ready(Err(SendError))` if the receiver has been dropped.
+    ///
+    /// A successful poll does not reserve capacity in the channel: it only
+    /// reports that this sender's guaranteed slot is free, and nothing needs
+    /// to be released if no message is sent afterwards. See the
+    /// [capacity model](fn.channel.html#capacity-model) for details.
     pub fn poll_ready(
         &mut self,
         cx: &mut Context<'_>,
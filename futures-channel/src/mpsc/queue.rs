@@ -28,25 +28,29 @@
 //! A mostly lock-free multi-producer, single consumer queue for sending
 //! messages between asynchronous tasks.
 //!
-//! The queue implementation is essentially the same one used for mpsc channels
-//! in the standard library.
+//! Messages are stored in blocks of `BLOCK_CAP` slots which are linked
+//! together, so that a heap allocation is only made once per block rather
+//! than once per message. The design is that of the unbounded list-based
+//! channel of `crossbeam-channel`, simplified for a single consumer.
 //!
 //! Note that the current implementation of this queue has a caveat of the `pop`
 //! method, and see the method for more information about it. Due to this
 //! caveat, this queue may not be appropriate for all use-cases.
 
-// http://www.1024cores.net/home/lock-free-algorithms
-//                         /queues/non-intrusive-mpsc-node-based-queue
-
-// NOTE: this implementation is lifted from the standard library and only
-//       slightly modified
-
 pub(super) use self::PopResult::*;
 
 use std::thread;
 use std::cell::UnsafeCell;
 use std::ptr;
-use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+
+// Each block covers `LAP` consecutive indices. The last index of a lap isn't
+// backed by a slot: a pusher that sees it knows that another pusher is busy
+// installing the next block, and waits for it to do so.
+const LAP: usize = 32;
+
+// The number of messages a block holds.
+const BLOCK_CAP: usize = LAP - 1;
 
 /// A result of the `pop` function.
 pub(super) enum PopResult<T> {
@@ -62,9 +66,16 @@ pub(super) enum PopResult<T> {
 }
 
 #[derive(Debug)]
-struct Node<T> {
-    next: AtomicPtr<Node<T>>,
-    value: Option<T>,
+struct Slot<T> {
+    value: UnsafeCell<Option<T>>,
+    // Set once `value` has been written.
+    ready: AtomicBool,
+}
+
+#[derive(Debug)]
+struct Block<T> {
+    next: AtomicPtr<Block<T>>,
+    slots: [Slot<T>; BLOCK_CAP],
 }
 
 /// The multi-producer single-consumer structure. This is not cloneable, but it
@@ -72,19 +83,34 @@ struct Node<T> {
 /// popper at a time (many pushers are allowed).
 #[derive(Debug)]
 pub(super) struct Queue<T> {
-    head: AtomicPtr<Node<T>>,
-    tail: UnsafeCell<*mut Node<T>>,
+    // The index of the next message to be pushed, and the block it goes to.
+    tail_index: AtomicUsize,
+    tail_block: AtomicPtr<Block<T>>,
+    // The index of the next message to be popped, and the block it is in.
+    // These are only ever touched by the popper.
+    head_index: UnsafeCell<usize>,
+    head_block: UnsafeCell<*mut Block<T>>,
 }
 
 unsafe impl<T: Send> Send for Queue<T> { }
 unsafe impl<T: Send> Sync for Queue<T> { }
 
-impl<T> Node<T> {
-    unsafe fn new(v: Option<T>) -> *mut Node<T> {
-        Box::into_raw(Box::new(Node {
+// Not derived, as that would require `T: Default`.
+impl<T> Default for Slot<T> {
+    fn default() -> Slot<T> {
+        Slot {
+            value: UnsafeCell::new(None),
+            ready: AtomicBool::new(false),
+        }
+    }
+}
+
+impl<T> Block<T> {
+    fn new() -> Box<Block<T>> {
+        Box::new(Block {
             next: AtomicPtr::new(ptr::null_mut()),
-            value: v,
-        }))
+            slots: Default::default(),
+        })
     }
 }
 
@@ -92,19 +118,63 @@ impl<T> Queue<T> {
     /// Creates a new queue that is safe to share among multiple producers and
     /// one consumer.
     pub(super) fn new() -> Queue<T> {
-        let stub = unsafe { Node::new(None) };
+        let block = Box::into_raw(Block::new());
         Queue {
-            head: AtomicPtr::new(stub),
-            tail: UnsafeCell::new(stub),
+            tail_index: AtomicUsize::new(0),
+            tail_block: AtomicPtr::new(block),
+            head_index: UnsafeCell::new(0),
+            head_block: UnsafeCell::new(block),
         }
     }
 
     /// Pushes a new value onto this queue.
     pub(super) fn push(&self, t: T) {
-        unsafe {
-            let n = Node::new(Some(t));
-            let prev = self.head.swap(n, Ordering::AcqRel);
-            (*prev).next.store(n, Ordering::Release);
+        let mut next_block = None;
+        let mut index = self.tail_index.load(Ordering::Acquire);
+        let mut block = self.tail_block.load(Ordering::Acquire);
+
+        loop {
+            let offset = index % LAP;
+
+            // Another pusher is installing the next block.
+            if offset == BLOCK_CAP {
+                thread::yield_now();
+                index = self.tail_index.load(Ordering::Acquire);
+                block = self.tail_block.load(Ordering::Acquire);
+                continue;
+            }
+
+            // If the slot about to be claimed is the last one of its block,
+            // allocate the next block up front, so that other pushers don't
+            // have to wait on the allocation.
+            if offset + 1 == BLOCK_CAP && next_block.is_none() {
+                next_block = Some(Block::new());
+            }
+
+            match self.tail_index.compare_exchange_weak(
+                index,
+                index.wrapping_add(1),
+                Ordering::SeqCst,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => unsafe {
+                    if offset + 1 == BLOCK_CAP {
+                        let next_block = Box::into_raw(next_block.unwrap());
+                        self.tail_block.store(next_block, Ordering::Release);
+                        self.tail_index.store(index.wrapping_add(2), Ordering::Release);
+                        (*block).next.store(next_block, Ordering::Release);
+                    }
+
+                    let slot = &(*block).slots[offset];
+                    *slot.value.get() = Some(t);
+                    slot.ready.store(true, Ordering::Release);
+                    return;
+                },
+                Err(current) => {
+                    index = current;
+                    block = self.tail_block.load(Ordering::Acquire);
+                }
+            }
         }
     }
 
@@ -121,19 +191,32 @@ impl<T> Queue<T> {
     ///
     /// This function is unsafe because only one thread can call it at a time.
     pub(super) unsafe fn pop(&self) -> PopResult<T> {
-        let tail = *self.tail.get();
-        let next = (*tail).next.load(Ordering::Acquire);
-
-        if !next.is_null() {
-            *self.tail.get() = next;
-            assert!((*tail).value.is_none());
-            assert!((*next).value.is_some());
-            let ret = (*next).value.take().unwrap();
-            drop(Box::from_raw(tail));
-            return Data(ret);
+        let index = *self.head_index.get();
+        let block = *self.head_block.get();
+        let offset = index % LAP;
+        let slot = &(*block).slots[offset];
+
+        if !slot.ready.load(Ordering::Acquire) {
+            // The slot is either not claimed yet, or claimed by a pusher that
+            // hasn't written its message yet.
+            return if self.tail_index.load(Ordering::Acquire) == index {
+                Empty
+            } else {
+                Inconsistent
+            };
         }
 
-        if self.head.load(Ordering::Acquire) == tail {Empty} else {Inconsistent}
+        let ret = (*slot.value.get()).take().unwrap();
+        if offset + 1 == BLOCK_CAP {
+            // Every slot of the block has been popped, so the pushers are done
+            // with it as well: each of them marks its slot ready last.
+            *self.head_block.get() = (*block).next.load(Ordering::Acquire);
+            *self.head_index.get() = index.wrapping_add(2);
+            drop(Box::from_raw(block));
+        } else {
+            *self.head_index.get() = index.wrapping_add(1);
+        }
+        Data(ret)
     }
 
     /// Pop an element similarly to `pop` function, but spin-wait on inconsistent
@@ -166,8 +249,9 @@ impl<T> Queue<T> {
 
 impl<T> Drop for Queue<T> {
     fn drop(&mut self) {
+        // Messages that were never popped are dropped along with their blocks.
         unsafe {
-            let mut cur = *self.tail.get();
+            let mut cur = *self.head_block.get();
             while !cur.is_null() {
                 let next = (*cur).next.load(Ordering::Relaxed);
                 drop(Box::from_raw(cur));
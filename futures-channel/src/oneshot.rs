@@ -28,9 +28,20 @@ pub struct Sender<T> {
     inner: Arc<Inner<T>>,
 }
 
+/// A future for a value that will be provided by another asynchronous task,
+/// transformed by a function once it arrives.
+///
+/// This is created by the [`channel_with_map`] function.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct MapReceiver<T, F> {
+    rx: Receiver<T>,
+    f: Option<F>,
+}
+
 // The channels do not ever project Pin to the inner T
 impl<T> Unpin for Receiver<T> {}
 impl<T> Unpin for Sender<T> {}
+impl<T, F> Unpin for MapReceiver<T, F> {}
 
 /// Internal state of the `Receiver`/`Sender` pair above. This is all used as
 /// the internal synchronization between the two for send/recv operations.
@@ -111,6 +122,33 @@ pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
     (sender, receiver)
 }
 
+/// Creates a new one-shot channel whose receiver applies `f` to the value
+/// when it is delivered.
+///
+/// This behaves exactly like [`channel`], except that the receiver resolves
+/// to `f(value)` rather than `value`. This saves wrapping the receiver in a
+/// separate combinator for the common pattern of converting a reply as soon
+/// as it arrives. `f` is not called if the sender is dropped without sending
+/// a value.
+///
+/// # Examples
+///
+/// ```
+/// use futures::channel::oneshot;
+/// use futures::executor::block_on;
+///
+/// let (sender, receiver) = oneshot::channel_with_map(|n: u32| n.to_string());
+///
+/// sender.send(3).unwrap();
+/// assert_eq!(block_on(receiver), Ok("3".to_string()));
+/// ```
+pub fn channel_with_map<T, U, F>(f: F) -> (Sender<T>, MapReceiver<T, F>)
+    where F: FnOnce(T) -> U,
+{
+    let (sender, rx) = channel();
+    (sender, MapReceiver { rx, f: Some(f) })
+}
+
 impl<T> Inner<T> {
     fn new() -> Inner<T> {
         Inner {
@@ -425,6 +463,42 @@ impl<T> Future for Receiver<T> {
     }
 }
 
+impl<T, F> MapReceiver<T, F> {
+    /// Gracefully close this receiver, preventing any subsequent attempts to
+    /// send to it.
+    ///
+    /// See [`Receiver::close`] for details.
+    pub fn close(&mut self) {
+        self.rx.close()
+    }
+}
+
+impl<T, U, F> Future for MapReceiver<T, F>
+    where F: FnOnce(T) -> U,
+{
+    type Output = Result<U, Canceled>;
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<U, Canceled>> {
+        let value = match Pin::new(&mut self.rx).poll(cx) {
+            Poll::Ready(value) => value,
+            Poll::Pending => return Poll::Pending,
+        };
+        let f = self.f.take().expect("MapReceiver polled after completion");
+        Poll::Ready(value.map(f))
+    }
+}
+
+impl<T: fmt::Debug, F> fmt::Debug for MapReceiver<T, F> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("MapReceiver")
+            .field("rx", &self.rx)
+            .finish()
+    }
+}
+
 impl<T> Drop for Receiver<T> {
     fn drop(&mut self) {
         self.inner.drop_rx()
@@ -0,0 +1,43 @@
+use futures::channel::mpsc;
+use futures::stream::StreamExt;
+use futures::task::Poll;
+use futures_test::task::noop_context;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+#[test]
+fn unbounded_allocates_per_block() {
+    const MESSAGES: usize = 1000;
+
+    let mut cx = noop_context();
+    let (tx, mut rx) = mpsc::unbounded();
+
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for i in 0..MESSAGES {
+        tx.unbounded_send(i).unwrap();
+    }
+    for i in 0..MESSAGES {
+        assert_eq!(rx.poll_next_unpin(&mut cx), Poll::Ready(Some(i)));
+    }
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+
+    assert!(allocations <= MESSAGES / 16, "{} allocations", allocations);
+}
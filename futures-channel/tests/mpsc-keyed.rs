@@ -0,0 +1,110 @@
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::sink::SinkExt;
+use futures::stream::{FusedStream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::{new_count_waker, noop_context};
+use std::task::Context;
+use std::thread;
+
+#[test]
+fn keys_take_turns() {
+    let (mut tx, rx) = mpsc::channel_per_key(3, 10);
+    for i in 0..4 {
+        tx.try_send(0, i).unwrap();
+    }
+    tx.try_send(2, 10).unwrap();
+    tx.try_send(2, 11).unwrap();
+    drop(tx);
+
+    let items: Vec<_> = block_on(rx.collect());
+    assert_eq!(items, vec![(0, 0), (2, 10), (0, 1), (2, 11), (0, 2), (0, 3)]);
+}
+
+#[test]
+fn full_key_does_not_block_other_keys() {
+    let (mut tx, mut rx) = mpsc::channel_per_key(2, 0);
+    let mut cx = noop_context();
+
+    tx.try_send(0, "a").unwrap();
+    assert!(tx.try_send(0, "b").unwrap_err().is_full());
+    assert!(tx.poll_ready(&mut cx, 0).is_pending());
+    assert_eq!(tx.poll_ready(&mut cx, 1), Poll::Ready(Ok(())));
+    tx.start_send(1, "c").unwrap();
+
+    assert_eq!(rx.poll_next_key(&mut cx, 1), Poll::Ready(Some("c")));
+    assert!(rx.poll_next_key(&mut cx, 1).is_pending());
+    assert_eq!(rx.try_next_key(0).unwrap(), Some("a"));
+    assert_eq!(tx.poll_ready(&mut cx, 0), Poll::Ready(Ok(())));
+}
+
+#[test]
+fn receiver_is_woken_by_any_key() {
+    let (mut tx, mut rx) = mpsc::channel_per_key(2, 1);
+    let (waker, count) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    assert!(rx.poll_next_unpin(&mut cx).is_pending());
+    tx.try_send(1, 5).unwrap();
+    assert_eq!(count, 1);
+    assert_eq!(rx.poll_next_unpin(&mut cx), Poll::Ready(Some((1, 5))));
+}
+
+#[test]
+fn ends_once_every_key_has_ended() {
+    let (tx, mut rx) = mpsc::channel_per_key(2, 1);
+    let mut tx2 = tx.clone();
+    drop(tx);
+    assert_eq!(tx2.keys(), 2);
+    assert_eq!(rx.keys(), 2);
+
+    tx2.for_key(0).close_channel();
+    let mut cx = noop_context();
+    assert_eq!(rx.poll_next_key(&mut cx, 0), Poll::Ready(None));
+    assert_eq!(rx.poll_next_key(&mut cx, 0), Poll::Ready(None));
+    assert_eq!(rx.try_next_key(0).unwrap(), None);
+    assert!(rx.poll_next_unpin(&mut cx).is_pending());
+    assert!(!rx.is_terminated());
+
+    tx2.try_send(1, 7).unwrap();
+    drop(tx2);
+    assert_eq!(rx.poll_next_unpin(&mut cx), Poll::Ready(Some((1, 7))));
+    assert_eq!(rx.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(rx.is_terminated());
+}
+
+#[test]
+fn close_receiver() {
+    let (mut tx, mut rx) = mpsc::channel_per_key(2, 1);
+    tx.try_send(0, 1).unwrap();
+    assert!(!tx.is_closed());
+    rx.close();
+    assert!(tx.is_closed());
+    assert!(tx.try_send(1, 2).unwrap_err().is_disconnected());
+    assert_eq!(block_on(rx.collect::<Vec<_>>()), vec![(0, 1)]);
+}
+
+#[test]
+fn senders_on_threads() {
+    let (tx, rx) = mpsc::channel_per_key(4, 1);
+    let threads: Vec<_> = (0..4)
+        .map(|key| {
+            let mut tx = tx.clone();
+            thread::spawn(move || {
+                for i in 0..100 {
+                    block_on(tx.for_key(key).send(i)).unwrap();
+                }
+            })
+        })
+        .collect();
+    drop(tx);
+
+    let items: Vec<_> = block_on(rx.collect());
+    for t in threads {
+        t.join().unwrap();
+    }
+    for key in 0..4 {
+        let per_key: Vec<_> = items.iter().filter(|(k, _)| *k == key).map(|(_, i)| *i).collect();
+        assert_eq!(per_key, (0..100).collect::<Vec<_>>());
+    }
+}
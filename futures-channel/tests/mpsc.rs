@@ -8,6 +8,7 @@ use futures::sink::{Sink, SinkExt};
 use futures::task::{Context, Poll};
 use futures_test::task::{new_count_waker, noop_context};
 use pin_utils::pin_mut;
+use std::pin::Pin;
 use std::sync::{Arc, Mutex};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::thread;
@@ -231,6 +232,55 @@ fn stress_shared_unbounded() {
     t.join().ok().unwrap();
 }
 
+#[test]
+fn stress_unbounded_keeps_order_per_sender() {
+    const AMT: u32 = 10000;
+    const NTHREADS: u32 = 8;
+    let (tx, rx) = mpsc::unbounded::<(u32, u32)>();
+
+    let t = thread::spawn(move|| {
+        let result: Vec<_> = block_on(rx.collect());
+        assert_eq!(result.len(), (AMT * NTHREADS) as usize);
+        let mut next = vec![0; NTHREADS as usize];
+        for (sender, i) in result {
+            assert_eq!(next[sender as usize], i);
+            next[sender as usize] += 1;
+        }
+    });
+
+    for sender in 0..NTHREADS {
+        let tx = tx.clone();
+
+        thread::spawn(move|| {
+            for i in 0..AMT {
+                tx.unbounded_send((sender, i)).unwrap();
+            }
+        });
+    }
+
+    drop(tx);
+
+    t.join().ok().unwrap();
+}
+
+#[test]
+fn unbounded_drops_unreceived_messages() {
+    let item = Arc::new(());
+    let (tx, mut rx) = mpsc::unbounded();
+
+    for _ in 0..100 {
+        tx.unbounded_send(item.clone()).unwrap();
+    }
+    for _ in 0..40 {
+        assert!(block_on(rx.next()).is_some());
+    }
+    assert_eq!(Arc::strong_count(&item), 61);
+
+    drop(tx);
+    drop(rx);
+    assert_eq!(Arc::strong_count(&item), 1);
+}
+
 #[test]
 fn stress_shared_bounded_hard() {
     const AMT: u32 = 10000;
@@ -573,3 +623,76 @@ fn send_backpressure_multi_senders() {
     let item = block_on(rx.next()).unwrap();
     assert_eq!(item, 2);
 }
+
+#[test]
+fn ready_then_drop_keeps_capacity() {
+    let mut cx = noop_context();
+    let (mut tx1, mut rx) = mpsc::channel::<i32>(0);
+
+    // Senders that are polled ready and dropped without sending, repeatedly,
+    // must not eat into the capacity available to the remaining sender.
+    for _ in 0..10 {
+        let mut tx2 = tx1.clone();
+        assert_eq!(tx2.poll_ready(&mut cx), Poll::Ready(Ok(())));
+        drop(tx2);
+    }
+
+    assert_eq!(tx1.poll_ready(&mut cx), Poll::Ready(Ok(())));
+    assert!(tx1.start_send(1).is_ok());
+    assert_eq!(tx1.poll_ready(&mut cx), Poll::Pending);
+    assert_eq!(rx.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(tx1.poll_ready(&mut cx), Poll::Ready(Ok(())));
+    assert!(tx1.start_send(2).is_ok());
+    assert_eq!(rx.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+}
+
+#[test]
+fn ready_then_drop_sink() {
+    let mut cx = noop_context();
+    let (tx1, mut rx) = mpsc::channel::<i32>(1);
+    let mut tx2 = tx1.clone();
+    pin_mut!(tx1);
+
+    // Both senders are ready, but only one of them sends.
+    assert_eq!(tx1.as_mut().poll_ready(&mut cx), Poll::Ready(Ok(())));
+    assert_eq!(Pin::new(&mut tx2).poll_ready(&mut cx), Poll::Ready(Ok(())));
+    drop(tx2);
+
+    // The shared slot is still available, followed by the guaranteed one.
+    assert!(tx1.as_mut().start_send(1).is_ok());
+    assert_eq!(tx1.as_mut().poll_ready(&mut cx), Poll::Ready(Ok(())));
+    assert!(tx1.as_mut().start_send(2).is_ok());
+    assert_eq!(tx1.as_mut().poll_ready(&mut cx), Poll::Pending);
+
+    assert_eq!(rx.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(tx1.as_mut().poll_ready(&mut cx), Poll::Ready(Ok(())));
+    assert_eq!(rx.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+}
+
+#[test]
+fn parked_then_drop_does_not_steal_wakeup() {
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let (mut tx1, mut rx) = mpsc::channel::<i32>(0);
+    let mut tx2 = tx1.clone();
+
+    // Both senders use up their guaranteed slot and are parked.
+    assert!(tx1.start_send(1).is_ok());
+    assert!(tx2.start_send(2).is_ok());
+    assert_eq!(tx1.poll_ready(&mut cx), Poll::Pending);
+    assert_eq!(tx2.poll_ready(&mut cx), Poll::Pending);
+    drop(tx1);
+
+    // Taking the first message frees the dropped sender's slot; the second
+    // message still occupies the slot of `tx2`.
+    assert_eq!(rx.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(tx2.poll_ready(&mut cx), Poll::Pending);
+
+    let before = counter.get();
+    assert_eq!(rx.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(counter.get(), before + 1);
+    assert_eq!(tx2.poll_ready(&mut cx), Poll::Ready(Ok(())));
+    assert!(tx2.start_send(3).is_ok());
+    assert_eq!(rx.poll_next_unpin(&mut cx), Poll::Ready(Some(3)));
+}
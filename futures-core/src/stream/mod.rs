@@ -9,6 +9,19 @@ use core::task::{Context, Poll};
 /// statically type your result or need to add some indirection.
 pub type BoxStream<'a, T> = Pin<alloc::boxed::Box<dyn Stream<Item = T> + Send + 'a>>;
 
+#[cfg(feature = "alloc")]
+/// `BoxStream`, but without the `Send` requirement.
+pub type LocalBoxStream<'a, T> = Pin<alloc::boxed::Box<dyn Stream<Item = T> + 'a>>;
+
+#[cfg(feature = "alloc")]
+/// `BoxStream`, but also requiring the stream to be `Sync`, so that it can be
+/// shared by reference between threads.
+pub type SyncBoxStream<'a, T> = Pin<alloc::boxed::Box<dyn Stream<Item = T> + Send + Sync + 'a>>;
+
+#[cfg(feature = "alloc")]
+/// `SyncBoxStream`, but without the `Send` requirement.
+pub type LocalSyncBoxStream<'a, T> = Pin<alloc::boxed::Box<dyn Stream<Item = T> + Sync + 'a>>;
+
 /// A stream of values produced asynchronously.
 ///
 /// If `Future<Output = T>` is an asynchronous version of `T`, then `Stream<Item
@@ -55,6 +68,37 @@ pub trait Stream {
         self: Pin<&mut Self>,
         cx: &mut Context<'_>,
     ) -> Poll<Option<Self::Item>>;
+
+    /// Returns the bounds on the remaining length of the stream.
+    ///
+    /// Specifically, `size_hint()` returns a tuple where the first element
+    /// is the lower bound, and the second element is the upper bound.
+    ///
+    /// The second half of the tuple that is returned is an
+    /// [`Option`]`<`[`usize`]`>`. A [`None`] here means that either there is
+    /// no known upper bound, or the upper bound is larger than [`usize`].
+    ///
+    /// # Implementation notes
+    ///
+    /// It is not enforced that a stream implementation yields the declared
+    /// number of elements. A buggy stream may yield less than the lower bound
+    /// or more than the upper bound of elements.
+    ///
+    /// `size_hint()` is primarily intended to be used for optimizations such
+    /// as reserving space for the elements of the stream, but must not be
+    /// trusted to e.g. omit bounds checks in unsafe code. An incorrect
+    /// implementation of `size_hint()` should not lead to memory safety
+    /// violations.
+    ///
+    /// That said, the implementation should provide a correct estimation,
+    /// because otherwise it would be a violation of the trait's protocol.
+    ///
+    /// The default implementation returns `(0, `[`None`]`)` which is correct
+    /// for any stream.
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
 }
 
 impl<S: ?Sized + Stream + Unpin> Stream for &mut S {
@@ -66,6 +110,10 @@ impl<S: ?Sized + Stream + Unpin> Stream for &mut S {
     ) -> Poll<Option<Self::Item>> {
         S::poll_next(Pin::new(&mut **self), cx)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
 }
 
 impl<P> Stream for Pin<P>
@@ -81,6 +129,10 @@ where
     ) -> Poll<Option<Self::Item>> {
         self.get_mut().as_mut().poll_next(cx)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
 }
 
 /// A `Stream` or `TryStream` which tracks whether or not the underlying stream
@@ -157,6 +209,10 @@ mod if_alloc {
         ) -> Poll<Option<Self::Item>> {
             Pin::new(&mut **self).poll_next(cx)
         }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (**self).size_hint()
+        }
     }
 
     #[cfg(feature = "std")]
@@ -169,6 +225,10 @@ mod if_alloc {
         ) -> Poll<Option<S::Item>> {
             unsafe { Pin::map_unchecked_mut(self, |x| &mut x.0) }.poll_next(cx)
         }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.0.size_hint()
+        }
     }
 
     impl<T: Unpin> Stream for ::alloc::collections::VecDeque<T> {
@@ -180,6 +240,10 @@ mod if_alloc {
         ) -> Poll<Option<Self::Item>> {
             Poll::Ready(self.pop_front())
         }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.len(), Some(self.len()))
+        }
     }
 
     impl<S: ?Sized + FusedStream> FusedStream for Box<S> {
@@ -55,20 +55,33 @@ pub trait LocalSpawn {
 
 /// An error that occurred during spawning.
 pub struct SpawnError {
-    _hidden: (),
+    kind: SpawnErrorKind,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SpawnErrorKind {
+    Shutdown,
+    Overloaded,
 }
 
 impl fmt::Debug for SpawnError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            SpawnErrorKind::Shutdown => "shutdown",
+            SpawnErrorKind::Overloaded => "overloaded",
+        };
         f.debug_tuple("SpawnError")
-            .field(&"shutdown")
+            .field(&kind)
             .finish()
     }
 }
 
 impl fmt::Display for SpawnError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Executor is shutdown")
+        match self.kind {
+            SpawnErrorKind::Shutdown => write!(f, "Executor is shutdown"),
+            SpawnErrorKind::Overloaded => write!(f, "Executor is overloaded"),
+        }
     }
 }
 
@@ -78,12 +91,23 @@ impl std::error::Error for SpawnError {}
 impl SpawnError {
     /// Spawning failed because the executor has been shut down.
     pub fn shutdown() -> Self {
-        Self { _hidden: () }
+        Self { kind: SpawnErrorKind::Shutdown }
     }
 
     /// Check whether spawning failed to the executor being shut down.
     pub fn is_shutdown(&self) -> bool {
-        true
+        self.kind == SpawnErrorKind::Shutdown
+    }
+
+    /// Spawning failed because the executor has too many tasks waiting to be
+    /// run, and rejects new ones to shed load.
+    pub fn overloaded() -> Self {
+        Self { kind: SpawnErrorKind::Overloaded }
+    }
+
+    /// Check whether spawning failed due to the executor being overloaded.
+    pub fn is_overloaded(&self) -> bool {
+        self.kind == SpawnErrorKind::Overloaded
     }
 }
 
@@ -15,7 +15,10 @@
 #[cfg(feature = "std")]
 mod local_pool;
 #[cfg(feature = "std")]
-pub use crate::local_pool::{block_on, block_on_stream, BlockingStream, LocalPool, LocalSpawner};
+pub use crate::local_pool::{
+    block_on, block_on_interruptible, block_on_stream, BlockingStream, Interrupted, LocalPool,
+    LocalSpawner, NestedSpawn,
+};
 
 #[cfg(feature = "std")]
 mod unpark_mutex;
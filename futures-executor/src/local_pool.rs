@@ -8,9 +8,12 @@ use futures_util::stream::StreamExt;
 use pin_utils::pin_mut;
 use std::cell::{RefCell};
 use std::ops::{Deref, DerefMut};
+use std::fmt;
 use std::rc::{Rc, Weak};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread::{self, Thread};
+use std::time::Duration;
 
 /// A single-threaded task pool for polling futures to completion.
 ///
@@ -23,10 +26,51 @@ use std::thread::{self, Thread};
 /// [`spawner()`](LocalPool::spawner) method. Because the executor is
 /// single-threaded, it supports a special form of task spawning for non-`Send`
 /// futures, via [`spawn_local_obj`](futures_core::task::LocalSpawn::spawn_local_obj).
+///
+/// # Scheduling
+///
+/// Tasks are polled in the order they are woken, and newly spawned tasks in
+/// the order they were spawned. Tasks which are spawned while the pool is
+/// running, for example by one of its tasks, are added to the pool behind
+/// the tasks which are already ready to be polled. Whether they are run by
+/// the same call to [`run_until_stalled`](LocalPool::run_until_stalled) or
+/// [`try_run_one`](LocalPool::try_run_one), or only by the next one, is
+/// decided by the pool's [`NestedSpawn`] policy.
 #[derive(Debug)]
 pub struct LocalPool {
     pool: FuturesUnordered<LocalFutureObj<'static, ()>>,
     incoming: Rc<Incoming>,
+    nested_spawn: NestedSpawn,
+}
+
+/// When a [`LocalPool`](LocalPool) runs the tasks spawned onto it while it
+/// is already running.
+///
+/// A *pass* is a single call to
+/// [`run_until_stalled`](LocalPool::run_until_stalled) or
+/// [`try_run_one`](LocalPool::try_run_one). [`run`](LocalPool::run) and
+/// [`run_until`](LocalPool::run_until) run passes until they are done, so
+/// with either policy they run all the tasks spawned while they run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NestedSpawn {
+    /// Tasks spawned during a pass are run by that same pass, which doesn't
+    /// stall until they can't make progress either. This is the default.
+    ///
+    /// A task which keeps spawning new tasks keeps the pass running.
+    SamePass,
+    /// Tasks spawned during a pass are only added to the pool at the start
+    /// of the next pass.
+    ///
+    /// Each pass then only runs the tasks which were spawned before it
+    /// started, so every pass is bounded even if tasks keep spawning new
+    /// ones, and each generation of spawned tasks runs in its own pass.
+    NextPass,
+}
+
+impl Default for NestedSpawn {
+    fn default() -> Self {
+        NestedSpawn::SamePass
+    }
 }
 
 /// A handle to a [`LocalPool`](LocalPool) that implements
@@ -39,21 +83,30 @@ pub struct LocalSpawner {
 type Incoming = RefCell<Vec<LocalFutureObj<'static, ()>>>;
 
 pub(crate) struct ThreadNotify {
-    thread: Thread
+    thread: Thread,
+    // Set on every wakeup, so that a timed out park can be told apart from
+    // an actual wakeup.
+    unparked: AtomicBool,
 }
 
 thread_local! {
     static CURRENT_THREAD_NOTIFY: Arc<ThreadNotify> = Arc::new(ThreadNotify {
         thread: thread::current(),
+        unparked: AtomicBool::new(false),
     });
 }
 
 impl ArcWake for ThreadNotify {
     fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.unparked.store(true, Ordering::Release);
         arc_self.thread.unpark();
     }
 }
 
+// How long `block_on_interruptible` sleeps at most between two calls to its
+// interruption checker.
+const INTERRUPT_CHECK_INTERVAL: Duration = Duration::from_millis(50);
+
 // Set up and run a basic single-threaded spawner loop, invoking `f` on each
 // turn.
 fn run_executor<T, F: FnMut(&mut Context<'_>) -> Poll<T>>(mut f: F) -> T {
@@ -88,12 +141,48 @@ fn poll_executor<T, F: FnMut(&mut Context<'_>) -> T>(mut f: F) -> T {
 impl LocalPool {
     /// Create a new, empty pool of tasks.
     pub fn new() -> LocalPool {
+        LocalPool::with_nested_spawn(NestedSpawn::default())
+    }
+
+    /// Create a new, empty pool of tasks, which runs the tasks spawned
+    /// while it is running according to `nested_spawn`.
+    ///
+    /// ```
+    /// use futures::executor::{LocalPool, NestedSpawn};
+    /// use futures::future::lazy;
+    /// use futures::task::LocalSpawnExt;
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    ///
+    /// let mut pool = LocalPool::with_nested_spawn(NestedSpawn::NextPass);
+    /// let mut spawner = pool.spawner();
+    /// let ran = Rc::new(Cell::new(false));
+    ///
+    /// let (mut inner_spawner, inner_ran) = (spawner.clone(), ran.clone());
+    /// spawner.spawn_local(lazy(move |_| {
+    ///     inner_spawner.spawn_local(lazy(move |_| inner_ran.set(true))).unwrap();
+    /// })).unwrap();
+    ///
+    /// // The nested task is only run by the second pass.
+    /// pool.run_until_stalled();
+    /// assert!(!ran.get());
+    /// pool.run_until_stalled();
+    /// assert!(ran.get());
+    /// ```
+    pub fn with_nested_spawn(nested_spawn: NestedSpawn) -> LocalPool {
         LocalPool {
             pool: FuturesUnordered::new(),
             incoming: Default::default(),
+            nested_spawn,
         }
     }
 
+    /// Returns the policy this pool follows for tasks spawned while it is
+    /// running.
+    pub fn nested_spawn(&self) -> NestedSpawn {
+        self.nested_spawn
+    }
+
     /// Get a clonable handle to the pool as a [`Spawn`].
     pub fn spawner(&self) -> LocalSpawner {
         LocalSpawner {
@@ -194,12 +283,16 @@ impl LocalPool {
     /// Though only one task will be completed, progress may be made on multiple tasks.
     pub fn try_run_one(&mut self) -> bool {
         poll_executor(|ctx| {
-            let ret = self.poll_pool_once(ctx);
+            self.admit_incoming();
+            loop {
+                let ret = self.poll_pool_once(ctx);
 
-            // return if we really have executed a future
-            match ret {
-                Poll::Ready(Some(_)) => true,
-                _ => false
+                // return if we really have executed a future
+                match ret {
+                    Poll::Ready(Some(_)) => return true,
+                    _ if self.has_same_pass_incoming() => continue,
+                    _ => return false,
+                }
             }
         })
     }
@@ -231,52 +324,65 @@ impl LocalPool {
     /// in the pool will try to make progress.
     pub fn run_until_stalled(&mut self) {
         poll_executor(|ctx| {
-            loop {
-                let result = self.poll_pool_once(ctx);
-
-                // if there are no more ready futures exit
-                match result {
-                    Poll::Pending | Poll::Ready(None) => return,
-                    _ => continue
-                }
-            }
+            let _ = self.poll_pass(ctx);
         })
     }
 
     // Make maximal progress on the entire pool of spawned task, returning `Ready`
     // if the pool is empty and `Pending` if no further progress can be made.
     fn poll_pool(&mut self, cx: &mut Context<'_>) -> Poll<()> {
-        // state for the FuturesUnordered, which will never be used
         loop {
-            let ret = self.poll_pool_once(cx);
+            let ret = self.poll_pass(cx);
 
-            // we queued up some new tasks; add them and poll again
+            // tasks were spawned during the pass; run another one for them
             if !self.incoming.borrow().is_empty() {
                 continue;
             }
 
-            // no queued tasks; we may be done
+            return ret;
+        }
+    }
+
+    // Run a single pass over the pool, returning `Ready` if the pool is empty
+    // and `Pending` if no further progress can be made in this pass.
+    fn poll_pass(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        self.admit_incoming();
+        loop {
+            let ret = self.poll_pool_once(cx);
+
+            // if there are no more ready futures the pass is over, unless
+            // tasks spawned during it are to be run by it
             match ret {
+                Poll::Ready(Some(())) => {}
+                _ if self.has_same_pass_incoming() => {}
                 Poll::Pending => return Poll::Pending,
                 Poll::Ready(None) => return Poll::Ready(()),
-                _ => {}
             }
         }
     }
 
     // Try make minimal progress on the pool of spawned tasks
     fn poll_pool_once(&mut self, cx: &mut Context<'_>) -> Poll<Option<()>> {
-        // empty the incoming queue of newly-spawned tasks
-        {
-            let mut incoming = self.incoming.borrow_mut();
-            for task in incoming.drain(..) {
-                self.pool.push(task)
-            }
+        // with `SamePass`, tasks spawned by the last poll join right away
+        if self.nested_spawn == NestedSpawn::SamePass {
+            self.admit_incoming();
         }
 
         // try to execute the next ready future
         self.pool.poll_next_unpin(cx)
     }
+
+    // Empty the incoming queue of newly-spawned tasks into the pool
+    fn admit_incoming(&mut self) {
+        let mut incoming = self.incoming.borrow_mut();
+        for task in incoming.drain(..) {
+            self.pool.push(task)
+        }
+    }
+
+    fn has_same_pass_incoming(&self) -> bool {
+        self.nested_spawn == NestedSpawn::SamePass && !self.incoming.borrow().is_empty()
+    }
 }
 
 impl Default for LocalPool {
@@ -296,6 +402,87 @@ pub fn block_on<F: Future>(f: F) -> F::Output {
     run_executor(|cx| f.as_mut().poll(cx))
 }
 
+/// Run a future to completion on the current thread, unless `interrupted`
+/// reports an interruption first.
+///
+/// This works like [`block_on`](block_on), except that `interrupted` is called
+/// every time the thread wakes up, and at least every 50 milliseconds while
+/// the future isn't making progress. As soon as it returns `true`, the future
+/// is dropped and [`Interrupted`](Interrupted) is returned.
+///
+/// Since the checker is polled, it only needs to read some state: for
+/// example, a flag that a signal handler sets when the user hits Ctrl-C.
+/// Setting an atomic flag is safe to do from a signal handler, unlike most
+/// other ways of waking up the blocked thread.
+///
+/// # Examples
+///
+/// ```
+/// use futures::channel::oneshot;
+/// use futures::executor::block_on_interruptible;
+/// use futures::future;
+/// use std::sync::atomic::{AtomicBool, Ordering};
+///
+/// static CTRL_C: AtomicBool = AtomicBool::new(false);
+///
+/// assert_eq!(block_on_interruptible(future::ready(1), || false), Ok(1));
+///
+/// // Normally set from a signal handler.
+/// CTRL_C.store(true, Ordering::SeqCst);
+/// let (_tx, never) = oneshot::channel::<()>();
+/// let res = block_on_interruptible(never, || CTRL_C.load(Ordering::SeqCst));
+/// assert!(res.is_err());
+/// ```
+pub fn block_on_interruptible<F, I>(f: F, mut interrupted: I) -> Result<F::Output, Interrupted>
+    where F: Future,
+          I: FnMut() -> bool,
+{
+    pin_mut!(f);
+
+    let _enter = enter()
+        .expect("cannot execute `LocalPool` executor from within \
+                 another executor");
+
+    CURRENT_THREAD_NOTIFY.with(|thread_notify| {
+        let waker = waker_ref(thread_notify);
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            if interrupted() {
+                return Err(Interrupted { _a: () });
+            }
+            thread_notify.unparked.store(false, Ordering::Release);
+            if let Poll::Ready(t) = f.as_mut().poll(&mut cx) {
+                return Ok(t);
+            }
+            // Only poll the future again once it has been woken up.
+            loop {
+                thread::park_timeout(INTERRUPT_CHECK_INTERVAL);
+                if thread_notify.unparked.load(Ordering::Acquire) {
+                    break;
+                }
+                if interrupted() {
+                    return Err(Interrupted { _a: () });
+                }
+            }
+        }
+    })
+}
+
+/// An error returned by [`block_on_interruptible`](block_on_interruptible)
+/// if it was interrupted before the future completed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Interrupted {
+    _a: (),
+}
+
+impl fmt::Display for Interrupted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "blocking on a future was interrupted")
+    }
+}
+
+impl std::error::Error for Interrupted {}
+
 /// Turn a stream into a blocking iterator.
 ///
 /// When `next` is called on the resulting `BlockingStream`, the caller
@@ -4,7 +4,9 @@ use futures_core::future::{Future, FutureObj};
 use futures_core::task::{Context, Poll, Spawn, SpawnError};
 use futures_util::future::FutureExt;
 use futures_util::task::{ArcWake, waker_ref};
+use std::any::Any;
 use std::io;
+use std::panic::{self, AssertUnwindSafe};
 use std::sync::{Arc, Mutex};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::mpsc;
@@ -30,8 +32,12 @@ pub struct ThreadPoolBuilder {
     name_prefix: Option<String>,
     after_start: Option<Arc<dyn Fn(usize) + Send + Sync>>,
     before_stop: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+    on_task_panic: Option<Arc<PanicHook>>,
+    max_queue_len: Option<usize>,
 }
 
+type PanicHook = dyn Fn(Box<dyn Any + Send + 'static>) + Send + Sync;
+
 trait AssertSendSync: Send + Sync {}
 impl AssertSendSync for ThreadPool {}
 
@@ -40,6 +46,10 @@ struct PoolState {
     rx: Mutex<mpsc::Receiver<Message>>,
     cnt: AtomicUsize,
     size: usize,
+    on_task_panic: Option<Arc<PanicHook>>,
+    // Number of spawned tasks which haven't started running yet
+    queued: AtomicUsize,
+    max_queue_len: Option<usize>,
 }
 
 impl fmt::Debug for ThreadPool {
@@ -55,11 +65,14 @@ impl fmt::Debug for ThreadPoolBuilder {
         f.debug_struct("ThreadPoolBuilder")
             .field("pool_size", &self.pool_size)
             .field("name_prefix", &self.name_prefix)
+            .field("max_queue_len", &self.max_queue_len)
             .finish()
     }
 }
 
 enum Message {
+    // A newly spawned task, which counts towards `max_queue_len`
+    Spawn(Task),
     Run(Task),
     Close,
 }
@@ -104,6 +117,10 @@ impl Spawn for ThreadPool {
     ) -> Result<(), SpawnError> {
         (&*self).spawn_obj(future)
     }
+
+    fn status(&self) -> Result<(), SpawnError> {
+        Spawn::status(&self)
+    }
 }
 
 impl Spawn for &ThreadPool {
@@ -111,6 +128,12 @@ impl Spawn for &ThreadPool {
         &mut self,
         future: FutureObj<'static, ()>,
     ) -> Result<(), SpawnError> {
+        if let Some(max_queue_len) = self.state.max_queue_len {
+            if self.state.queued.fetch_add(1, Ordering::SeqCst) >= max_queue_len {
+                self.state.queued.fetch_sub(1, Ordering::SeqCst);
+                return Err(SpawnError::overloaded());
+            }
+        }
         let task = Task {
             future,
             wake_handle: Arc::new(WakeHandle {
@@ -119,9 +142,18 @@ impl Spawn for &ThreadPool {
             }),
             exec: self.clone(),
         };
-        self.state.send(Message::Run(task));
+        self.state.send(Message::Spawn(task));
         Ok(())
     }
+
+    fn status(&self) -> Result<(), SpawnError> {
+        match self.state.max_queue_len {
+            Some(max_queue_len) if self.state.queued.load(Ordering::SeqCst) >= max_queue_len => {
+                Err(SpawnError::overloaded())
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 impl PoolState {
@@ -140,6 +172,12 @@ impl PoolState {
         loop {
             let msg = self.rx.lock().unwrap().recv().unwrap();
             match msg {
+                Message::Spawn(task) => {
+                    if self.max_queue_len.is_some() {
+                        self.queued.fetch_sub(1, Ordering::SeqCst);
+                    }
+                    task.run()
+                }
                 Message::Run(task) => task.run(),
                 Message::Close => break,
             }
@@ -178,6 +216,8 @@ impl ThreadPoolBuilder {
             name_prefix: None,
             after_start: None,
             before_stop: None,
+            on_task_panic: None,
+            max_queue_len: None,
         }
     }
 
@@ -240,6 +280,40 @@ impl ThreadPoolBuilder {
         self
     }
 
+    /// Execute closure `f` whenever a task running on the pool panics.
+    ///
+    /// The closure receives the panic payload. The task that panicked is
+    /// dropped, and the worker thread it ran on goes on to run other tasks.
+    ///
+    /// By default, a panicking task unwinds and takes down the worker thread
+    /// it ran on. Tasks that need the panic to reach whoever awaits their
+    /// result can instead be spawned through
+    /// [`remote_handle`](futures_util::future::FutureExt::remote_handle),
+    /// which resumes the panic when the returned handle is polled.
+    pub fn on_task_panic<F>(&mut self, f: F) -> &mut Self
+        where F: Fn(Box<dyn Any + Send + 'static>) + Send + Sync + 'static
+    {
+        self.on_task_panic = Some(Arc::new(f));
+        self
+    }
+
+    /// Limit the number of spawned tasks waiting for a worker thread to pick
+    /// them up.
+    ///
+    /// Once `max_queue_len` tasks have been spawned that haven't started
+    /// running yet, spawning another task fails with a
+    /// [`SpawnError`](futures_core::task::SpawnError) for which
+    /// [`is_overloaded`](futures_core::task::SpawnError::is_overloaded)
+    /// returns `true`, rather than queueing it. Services can use this to shed
+    /// load when the pool can't keep up. Tasks that have already started and
+    /// are woken up again are always queued.
+    ///
+    /// By default, the number of queued tasks isn't limited.
+    pub fn max_queue_len(&mut self, max_queue_len: usize) -> &mut Self {
+        self.max_queue_len = Some(max_queue_len);
+        self
+    }
+
     /// Create a [`ThreadPool`](ThreadPool) with the given configuration.
     ///
     /// # Panics
@@ -253,6 +327,9 @@ impl ThreadPoolBuilder {
                 rx: Mutex::new(rx),
                 cnt: AtomicUsize::new(1),
                 size: self.pool_size,
+                on_task_panic: self.on_task_panic.clone(),
+                queued: AtomicUsize::new(0),
+                max_queue_len: self.max_queue_len,
             }),
         };
         assert!(self.pool_size > 0);
@@ -306,10 +383,18 @@ impl Task {
             wake_handle.mutex.start_poll();
 
             loop {
-                let res = future.poll_unpin(&mut cx);
+                let res = panic::catch_unwind(AssertUnwindSafe(|| future.poll_unpin(&mut cx)));
                 match res {
-                    Poll::Pending => {}
-                    Poll::Ready(()) => return wake_handle.mutex.complete(),
+                    Ok(Poll::Pending) => {}
+                    Ok(Poll::Ready(())) => return wake_handle.mutex.complete(),
+                    Err(payload) => {
+                        wake_handle.mutex.complete();
+                        drop(future);
+                        match &exec.state.on_task_panic {
+                            Some(on_task_panic) => return on_task_panic(payload),
+                            None => panic::resume_unwind(payload),
+                        }
+                    }
                 }
                 let task = Task {
                     future,
@@ -348,6 +433,7 @@ impl ArcWake for WakeHandle {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use futures_util::future::lazy;
     use std::sync::mpsc;
 
     #[test]
@@ -362,4 +448,67 @@ mod tests {
         let count = rx.into_iter().count();
         assert_eq!(count, 2);
     }
+
+    #[test]
+    fn test_on_task_panic() {
+        let (tx, rx) = mpsc::channel();
+        let tx = Mutex::new(tx);
+        let pool = ThreadPoolBuilder::new()
+            .pool_size(1)
+            .on_task_panic(move |payload| {
+                let msg = *payload.downcast::<&str>().unwrap();
+                tx.lock().unwrap().send(msg).unwrap();
+            })
+            .create()
+            .unwrap();
+
+        (&pool).spawn_obj(FutureObj::new(Box::new(lazy(|_| {
+            panic!("boom");
+        })))).unwrap();
+        assert_eq!(rx.recv().unwrap(), "boom");
+
+        // The worker thread survived and keeps running tasks.
+        let (done_tx, done_rx) = mpsc::channel();
+        (&pool).spawn_obj(FutureObj::new(Box::new(lazy(move |_| {
+            done_tx.send(()).unwrap();
+        })))).unwrap();
+        done_rx.recv().unwrap();
+    }
+
+    #[test]
+    fn test_max_queue_len() {
+        let pool = ThreadPoolBuilder::new()
+            .pool_size(1)
+            .max_queue_len(1)
+            .create()
+            .unwrap();
+
+        // Keep the only worker busy until `unblock_tx` fires.
+        let (started_tx, started_rx) = mpsc::channel();
+        let (unblock_tx, unblock_rx) = mpsc::channel::<()>();
+        (&pool).spawn_obj(FutureObj::new(Box::new(lazy(move |_| {
+            started_tx.send(()).unwrap();
+            unblock_rx.recv().unwrap();
+        })))).unwrap();
+        started_rx.recv().unwrap();
+
+        let (done_tx, done_rx) = mpsc::channel();
+        let done_tx2 = done_tx.clone();
+        (&pool).spawn_obj(FutureObj::new(Box::new(lazy(move |_| {
+            done_tx.send(()).unwrap();
+        })))).unwrap();
+        assert!((&pool).status().unwrap_err().is_overloaded());
+        let err = (&pool).spawn_obj(FutureObj::new(Box::new(lazy(|_| ())))).unwrap_err();
+        assert!(err.is_overloaded());
+        assert!(!err.is_shutdown());
+
+        unblock_tx.send(()).unwrap();
+        done_rx.recv().unwrap();
+
+        // The queue drained, so spawning succeeds again.
+        (&pool).spawn_obj(FutureObj::new(Box::new(lazy(move |_| {
+            done_tx2.send(()).unwrap();
+        })))).unwrap();
+        done_rx.recv().unwrap();
+    }
 }
@@ -1,5 +1,5 @@
 use futures::channel::oneshot;
-use futures::executor::LocalPool;
+use futures::executor::{block_on_interruptible, LocalPool, LocalSpawner, NestedSpawn};
 use futures::future::{Future, lazy, poll_fn};
 use futures::task::{Context, Poll, Spawn, LocalSpawn, Waker};
 use std::cell::{Cell, RefCell};
@@ -311,3 +311,157 @@ fn tasks_are_scheduled_fairly() {
 
     pool.run();
 }
+
+#[test]
+fn block_on_interruptible_completes() {
+    let (tx, rx) = oneshot::channel();
+    let checks = Cell::new(0);
+    let thread = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        tx.send(3).unwrap();
+    });
+    let res = block_on_interruptible(rx, || {
+        checks.set(checks.get() + 1);
+        false
+    });
+    assert_eq!(res, Ok(Ok(3)));
+    assert!(checks.get() >= 1);
+    thread.join().unwrap();
+}
+
+#[test]
+fn block_on_interruptible_drops_future_on_interruption() {
+    let pending = pending();
+    let alive = Rc::downgrade(&pending.0);
+    let checks = Cell::new(0);
+    // Interrupted on the third check, without the future ever being woken.
+    let res = block_on_interruptible(pending, || {
+        checks.set(checks.get() + 1);
+        checks.get() == 3
+    });
+    assert!(res.is_err());
+    assert_eq!(checks.get(), 3);
+    assert!(alive.upgrade().is_none());
+}
+
+// Gives the names of the tasks a task spawns, from its own name.
+type Children = Rc<dyn Fn(&str) -> Vec<String>>;
+
+// Spawns a task which logs `name`, spawns the tasks `children` gives for it
+// in order, and then stays pending.
+fn spawn_logged(
+    spawn: &LocalSpawner,
+    log: &Rc<RefCell<Vec<String>>>,
+    name: String,
+    children: Children,
+) {
+    let (spawner, log) = (spawn.clone(), log.clone());
+    let mut started = false;
+    spawner.clone().spawn_local_obj(Box::pin(poll_fn(move |_| {
+        if !started {
+            started = true;
+            log.borrow_mut().push(name.clone());
+            for child in children(&name) {
+                spawn_logged(&spawner, &log, child, children.clone());
+            }
+        }
+        Poll::<()>::Pending
+    })).into()).unwrap();
+}
+
+fn generations(depth: usize) -> Children {
+    Rc::new(move |name: &str| {
+        if name.len() < depth {
+            vec![format!("{}a", name), format!("{}b", name)]
+        } else {
+            vec![]
+        }
+    })
+}
+
+#[test]
+fn nested_spawn_runs_in_same_pass_by_default() {
+    let mut pool = LocalPool::new();
+    assert_eq!(pool.nested_spawn(), NestedSpawn::SamePass);
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    // The spawning tasks stay pending, so nothing completes during the pass.
+    spawn_logged(&pool.spawner(), &log, "r".to_string(), generations(3));
+    pool.run_until_stalled();
+    assert_eq!(*log.borrow(), ["r", "ra", "rb", "raa", "rab", "rba", "rbb"]);
+}
+
+#[test]
+fn nested_spawn_runs_in_next_pass() {
+    let mut pool = LocalPool::with_nested_spawn(NestedSpawn::NextPass);
+    assert_eq!(pool.nested_spawn(), NestedSpawn::NextPass);
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    spawn_logged(&pool.spawner(), &log, "r".to_string(), generations(3));
+    pool.run_until_stalled();
+    assert_eq!(*log.borrow(), ["r"]);
+    pool.run_until_stalled();
+    assert_eq!(*log.borrow(), ["r", "ra", "rb"]);
+    pool.run_until_stalled();
+    assert_eq!(*log.borrow(), ["r", "ra", "rb", "raa", "rab", "rba", "rbb"]);
+    pool.run_until_stalled();
+    assert_eq!(log.borrow().len(), 7);
+}
+
+#[test]
+fn nested_spawn_try_run_one() {
+    for &policy in &[NestedSpawn::SamePass, NestedSpawn::NextPass] {
+        let mut pool = LocalPool::with_nested_spawn(policy);
+        let mut spawn = pool.spawner();
+        let cnt = Rc::new(Cell::new(0));
+
+        let (mut inner_spawn, inner_cnt) = (spawn.clone(), cnt.clone());
+        spawn.spawn_local_obj(Box::pin(poll_fn(move |_| {
+            let cnt = inner_cnt.clone();
+            inner_spawn.spawn_local_obj(Box::pin(lazy(move |_| {
+                cnt.set(cnt.get() + 1);
+            })).into()).unwrap();
+            Poll::<()>::Pending
+        })).into()).unwrap();
+
+        // The spawned task completes in the same call only with `SamePass`.
+        let same_pass = policy == NestedSpawn::SamePass;
+        assert_eq!(pool.try_run_one(), same_pass);
+        assert_eq!(cnt.get(), if same_pass { 1 } else { 0 });
+        assert_eq!(pool.try_run_one(), !same_pass);
+        assert_eq!(cnt.get(), 1);
+    }
+}
+
+#[test]
+fn nested_spawn_run_completes_all_generations() {
+    for &policy in &[NestedSpawn::SamePass, NestedSpawn::NextPass] {
+        let mut pool = LocalPool::with_nested_spawn(policy);
+        let cnt = Rc::new(Cell::new(0));
+
+        fn spawn_chain(spawn: &LocalSpawner, cnt: Rc<Cell<usize>>, depth: usize) {
+            let mut spawner = spawn.clone();
+            let inner = spawner.clone();
+            spawner.spawn_local_obj(Box::pin(lazy(move |_| {
+                cnt.set(cnt.get() + 1);
+                if depth > 0 {
+                    spawn_chain(&inner, cnt, depth - 1);
+                }
+            })).into()).unwrap();
+        }
+
+        spawn_chain(&pool.spawner(), cnt.clone(), 9);
+        pool.run();
+        assert_eq!(cnt.get(), 10);
+
+        spawn_chain(&pool.spawner(), cnt.clone(), 9);
+        pool.run_until(poll_fn(|cx| {
+            if cnt.get() == 20 {
+                Poll::Ready(())
+            } else {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }));
+    }
+}
@@ -24,6 +24,8 @@ struct Select {
     complete: Option<Expr>,
     default: Option<Expr>,
     normal_fut_exprs: Vec<Expr>,
+    // `if <expr>` guard of each normal case, if it has one
+    normal_fut_guards: Vec<Option<Expr>>,
     normal_fut_handlers: Vec<(Pat, Expr)>,
 }
 
@@ -31,7 +33,7 @@ struct Select {
 enum CaseKind {
     Complete,
     Default,
-    Normal(Pat, Expr),
+    Normal(Pat, Expr, Option<Expr>),
 }
 
 impl Parse for Select {
@@ -41,6 +43,7 @@ impl Parse for Select {
             complete: None,
             default: None,
             normal_fut_exprs: vec![],
+            normal_fut_guards: vec![],
             normal_fut_handlers: vec![],
         };
 
@@ -74,7 +77,16 @@ impl Parse for Select {
                 let pat = input.parse()?;
                 input.parse::<Token![=]>()?;
                 let expr = input.parse()?;
-                CaseKind::Normal(pat, expr)
+
+                // `, if <expr>`
+                let guard = if input.peek(Token![,]) && input.peek2(Token![if]) {
+                    input.parse::<Token![,]>()?;
+                    input.parse::<Token![if]>()?;
+                    Some(input.parse()?)
+                } else {
+                    None
+                };
+                CaseKind::Normal(pat, expr, guard)
             };
 
             // `=> <expr>`
@@ -93,8 +105,9 @@ impl Parse for Select {
             match case_kind {
                 CaseKind::Complete => select.complete = Some(expr),
                 CaseKind::Default => select.default = Some(expr),
-                CaseKind::Normal(pat, fut_expr) => {
+                CaseKind::Normal(pat, fut_expr, guard) => {
                     select.normal_fut_exprs.push(fut_expr);
+                    select.normal_fut_guards.push(guard);
                     select.normal_fut_handlers.push((pat, expr));
                 },
             }
@@ -161,6 +174,22 @@ pub fn select(input: TokenStream) -> TokenStream {
         span,
     );
 
+    // Evaluate the guards before any of the future exprs, and bind the
+    // results w/ `let` so that they stay fixed while polling.
+    let mut guard_let_bindings = Vec::new();
+    let guards: Vec<_> = parsed.normal_fut_guards.into_iter()
+        .zip(variant_names.iter())
+        .map(|(guard, variant_name)| {
+            guard.map(|guard| {
+                let guard_name = Ident::new(&format!("__guard{}", variant_name), span);
+                guard_let_bindings.push(quote! {
+                    let #guard_name: bool = #guard;
+                });
+                guard_name
+            })
+        })
+        .collect();
+
     // bind non-`Ident` future exprs w/ `let`
     let mut future_let_bindings = Vec::with_capacity(parsed.normal_fut_exprs.len());
     let bound_future_names: Vec<_> = parsed.normal_fut_exprs.into_iter()
@@ -183,11 +212,13 @@ pub fn select(input: TokenStream) -> TokenStream {
 
     // For each future, make an `&mut dyn FnMut(&mut Context<'_>) -> Option<Poll<__PrivResult<...>>`
     // to use for polling that individual future. These will then be put in an array.
-    let poll_functions = bound_future_names.iter().zip(variant_names.iter())
-        .map(|(bound_future_name, variant_name)| {
+    // A future whose guard is false is treated the same as a completed one.
+    let poll_functions = bound_future_names.iter().zip(variant_names.iter()).zip(guards.iter())
+        .map(|((bound_future_name, variant_name), guard)| {
+            let disabled = guard.as_ref().map(|guard_name| quote!(!#guard_name ||));
             quote! {
                 let mut #variant_name = |__cx: &mut #futures_crate::task::Context<'_>| {
-                    if #futures_crate::future::FusedFuture::is_terminated(&#bound_future_name) {
+                    if #disabled #futures_crate::future::FusedFuture::is_terminated(&#bound_future_name) {
                         None
                     } else {
                         Some(#futures_crate::future::FutureExt::poll_unpin(
@@ -208,8 +239,8 @@ pub fn select(input: TokenStream) -> TokenStream {
         }
     } else {
         quote! {
-            panic!("all futures in select! were completed,\
-                    but no `complete =>` handler was provided")
+            panic!("all futures in select! were completed or disabled by \
+                    their guards, but no `complete =>` handler was provided")
         }
     };
 
@@ -255,6 +286,7 @@ pub fn select(input: TokenStream) -> TokenStream {
 
     TokenStream::from(quote! { {
         #enum_item
+        #( #guard_let_bindings )*
         #( #future_let_bindings )*
 
         let mut __poll_fn = |__cx: &mut #futures_crate::task::Context<'_>| {
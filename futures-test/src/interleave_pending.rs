@@ -1,6 +1,7 @@
 use futures_core::future::Future;
 use futures_core::stream::Stream;
 use futures_io::{self as io, AsyncBufRead, AsyncRead, AsyncWrite};
+use futures_sink::Sink;
 use pin_utils::{unsafe_pinned, unsafe_unpinned};
 use std::{
     pin::Pin,
@@ -14,6 +15,7 @@ use std::{
 /// * [`StreamTestExt`](crate::stream::StreamTestExt::interleave_pending)
 /// * [`AsyncReadTestExt`](crate::io::AsyncReadTestExt::interleave_pending)
 /// * [`AsyncWriteTestExt`](crate::io::AsyncWriteTestExt::interleave_pending_write)
+/// * [`SinkTestExt`](crate::sink::SinkTestExt::interleave_pending_sink)
 #[derive(Debug)]
 pub struct InterleavePending<T> {
     inner: T,
@@ -106,6 +108,68 @@ impl<St: Stream> Stream for InterleavePending<St> {
     }
 }
 
+impl<Si: Sink<Item>, Item> Sink<Item> for InterleavePending<Si> {
+    type SinkError = Si::SinkError;
+
+    fn poll_ready(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        let (sink, pended) = self.project();
+        if *pended {
+            let next = sink.poll_ready(cx);
+            if next.is_ready() {
+                *pended = false;
+            }
+            next
+        } else {
+            cx.waker().wake_by_ref();
+            *pended = true;
+            Poll::Pending
+        }
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::SinkError> {
+        self.inner().start_send(item)
+    }
+
+    fn poll_flush(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        let (sink, pended) = self.project();
+        if *pended {
+            let next = sink.poll_flush(cx);
+            if next.is_ready() {
+                *pended = false;
+            }
+            next
+        } else {
+            cx.waker().wake_by_ref();
+            *pended = true;
+            Poll::Pending
+        }
+    }
+
+    fn poll_close(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        let (sink, pended) = self.project();
+        if *pended {
+            let next = sink.poll_close(cx);
+            if next.is_ready() {
+                *pended = false;
+            }
+            next
+        } else {
+            cx.waker().wake_by_ref();
+            *pended = true;
+            Poll::Pending
+        }
+    }
+}
+
 impl<W: AsyncWrite> AsyncWrite for InterleavePending<W> {
     fn poll_write(
         self: Pin<&mut Self>,
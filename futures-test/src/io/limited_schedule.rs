@@ -0,0 +1,147 @@
+use futures_io::{self as io, AsyncBufRead, AsyncRead, AsyncWrite};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::{
+    cmp,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// I/O wrapper that limits the number of bytes written or read on each call
+/// according to a schedule of per-call limits.
+///
+/// See the [`limited_schedule`], [`limited_cycle`], [`limited_write_schedule`]
+/// and [`limited_write_cycle`] methods.
+///
+/// [`limited_schedule`]: super::AsyncReadTestExt::limited_schedule
+/// [`limited_cycle`]: super::AsyncReadTestExt::limited_cycle
+/// [`limited_write_schedule`]: super::AsyncWriteTestExt::limited_write_schedule
+/// [`limited_write_cycle`]: super::AsyncWriteTestExt::limited_write_cycle
+#[derive(Debug)]
+pub struct LimitedSchedule<Io> {
+    io: Io,
+    limits: Vec<usize>,
+    pos: usize,
+    cycle: bool,
+}
+
+impl<Io: Unpin> Unpin for LimitedSchedule<Io> {}
+
+impl<Io> LimitedSchedule<Io> {
+    unsafe_pinned!(io: Io);
+    unsafe_unpinned!(pos: usize);
+
+    pub(crate) fn new(io: Io, limits: Vec<usize>, cycle: bool) -> LimitedSchedule<Io> {
+        assert!(
+            !cycle || !limits.is_empty(),
+            "a cycling schedule must contain at least one limit",
+        );
+        LimitedSchedule { io, limits, pos: 0, cycle }
+    }
+
+    /// Returns the limit that applies to the next call, or `None` if a
+    /// one-shot schedule has been used up and calls are no longer limited.
+    pub fn next_limit(&self) -> Option<usize> {
+        self.limits.get(self.pos).cloned()
+    }
+
+    fn advance(mut self: Pin<&mut Self>) {
+        if self.pos < self.limits.len() {
+            *self.as_mut().pos() += 1;
+            if self.cycle && self.pos == self.limits.len() {
+                *self.as_mut().pos() = 0;
+            }
+        }
+    }
+
+    /// Acquires a reference to the underlying I/O object that this adaptor is
+    /// wrapping.
+    pub fn get_ref(&self) -> &Io {
+        &self.io
+    }
+
+    /// Acquires a mutable reference to the underlying I/O object that this
+    /// adaptor is wrapping.
+    pub fn get_mut(&mut self) -> &mut Io {
+        &mut self.io
+    }
+
+    /// Acquires a pinned mutable reference to the underlying I/O object that
+    /// this adaptor is wrapping.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut Io> {
+        self.io()
+    }
+
+    /// Consumes this adaptor returning the underlying I/O object.
+    pub fn into_inner(self) -> Io {
+        self.io
+    }
+}
+
+fn truncate(len: usize, limit: Option<usize>) -> usize {
+    match limit {
+        Some(limit) => cmp::min(limit, len),
+        None => len,
+    }
+}
+
+impl<W: AsyncWrite> AsyncWrite for LimitedSchedule<W> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let len = truncate(buf.len(), self.next_limit());
+        let res = self.as_mut().io().poll_write(cx, &buf[..len]);
+        if let Poll::Ready(Ok(_)) = res {
+            self.advance();
+        }
+        res
+    }
+
+    fn poll_flush(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<()>> {
+        self.io().poll_flush(cx)
+    }
+
+    fn poll_close(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<()>> {
+        self.io().poll_close(cx)
+    }
+}
+
+impl<R: AsyncRead> AsyncRead for LimitedSchedule<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let len = truncate(buf.len(), self.next_limit());
+        let res = self.as_mut().io().poll_read(cx, &mut buf[..len]);
+        if let Poll::Ready(Ok(_)) = res {
+            self.advance();
+        }
+        res
+    }
+}
+
+impl<R: AsyncBufRead> AsyncBufRead for LimitedSchedule<R> {
+    fn poll_fill_buf<'a>(
+        self: Pin<&'a mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<&'a [u8]>> {
+        let limit = self.next_limit();
+        match self.io().poll_fill_buf(cx) {
+            Poll::Ready(Ok(buf)) => Poll::Ready(Ok(&buf[..truncate(buf.len(), limit)])),
+            res => res,
+        }
+    }
+
+    fn consume(mut self: Pin<&mut Self>, amount: usize) {
+        self.as_mut().io().consume(amount);
+        self.advance();
+    }
+}
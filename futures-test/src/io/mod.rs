@@ -1,6 +1,7 @@
 //! Additional combinators for testing async IO.
 
 mod limited;
+mod limited_schedule;
 
 pub mod read;
 pub use read::AsyncReadTestExt;
@@ -3,6 +3,7 @@
 use futures_io::AsyncRead;
 
 pub use super::limited::Limited;
+pub use super::limited_schedule::LimitedSchedule;
 pub use crate::interleave_pending::InterleavePending;
 
 /// Additional combinators for testing async readers.
@@ -108,6 +109,119 @@ pub trait AsyncReadTestExt: AsyncRead {
     {
         Limited::new(self, limit)
     }
+
+    /// Limit the number of bytes allowed to be read on each call to
+    /// `poll_read` according to a one-shot schedule.
+    ///
+    /// The `n`th successful read is limited to `limits[n]` bytes. Once the
+    /// schedule is used up, reads are no longer limited. This makes it
+    /// possible to reproduce an exact fragmentation pattern, e.g. one taken
+    /// from a packet capture.
+    ///
+    /// The returned reader will also implement `AsyncBufRead` if the
+    /// underlying reader does, in which case the buffer returned by
+    /// `poll_fill_buf` is truncated to the current limit and each call to
+    /// `consume` moves on to the next limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// use futures::task::Poll;
+    /// use futures::io::AsyncRead;
+    /// use futures_test::task::noop_context;
+    /// use futures_test::io::AsyncReadTestExt;
+    /// use pin_utils::pin_mut;
+    ///
+    /// let reader = std::io::Cursor::new(&[1, 2, 3, 4, 5, 6]).limited_schedule(vec![1, 3]);
+    /// pin_mut!(reader);
+    ///
+    /// let mut cx = noop_context();
+    ///
+    /// let mut buf = [0; 10];
+    ///
+    /// assert_eq!(reader.as_mut().poll_read(&mut cx, &mut buf)?, Poll::Ready(1));
+    /// assert_eq!(&buf[..1], &[1]);
+    /// assert_eq!(reader.as_mut().poll_read(&mut cx, &mut buf)?, Poll::Ready(3));
+    /// assert_eq!(&buf[..3], &[2, 3, 4]);
+    /// assert_eq!(reader.as_mut().poll_read(&mut cx, &mut buf)?, Poll::Ready(2));
+    /// assert_eq!(&buf[..2], &[5, 6]);
+    ///
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    fn limited_schedule(self, limits: Vec<usize>) -> LimitedSchedule<Self>
+    where
+        Self: Sized,
+    {
+        LimitedSchedule::new(self, limits, false)
+    }
+
+    /// Limit the number of bytes allowed to be read on each call to
+    /// `poll_read` according to a schedule that repeats forever.
+    ///
+    /// This behaves like [`limited_schedule`](AsyncReadTestExt::limited_schedule),
+    /// except that the schedule starts over once it is used up.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `limits` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// use futures::task::Poll;
+    /// use futures::io::AsyncRead;
+    /// use futures_test::task::noop_context;
+    /// use futures_test::io::AsyncReadTestExt;
+    /// use pin_utils::pin_mut;
+    ///
+    /// let reader = std::io::Cursor::new(&[1, 2, 3, 4, 5, 6]).limited_cycle(vec![1, 2]);
+    /// pin_mut!(reader);
+    ///
+    /// let mut cx = noop_context();
+    ///
+    /// let mut buf = [0; 10];
+    ///
+    /// assert_eq!(reader.as_mut().poll_read(&mut cx, &mut buf)?, Poll::Ready(1));
+    /// assert_eq!(reader.as_mut().poll_read(&mut cx, &mut buf)?, Poll::Ready(2));
+    /// assert_eq!(reader.as_mut().poll_read(&mut cx, &mut buf)?, Poll::Ready(1));
+    /// assert_eq!(reader.as_mut().poll_read(&mut cx, &mut buf)?, Poll::Ready(2));
+    /// assert_eq!(&buf[..2], &[5, 6]);
+    /// assert_eq!(reader.as_mut().poll_read(&mut cx, &mut buf)?, Poll::Ready(0));
+    ///
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// ## `AsyncBufRead`
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// use futures::task::Poll;
+    /// use futures::io::AsyncBufRead;
+    /// use futures_test::task::noop_context;
+    /// use futures_test::io::AsyncReadTestExt;
+    /// use pin_utils::pin_mut;
+    ///
+    /// let reader = std::io::Cursor::new(&[1, 2, 3]).limited_cycle(vec![2]);
+    /// pin_mut!(reader);
+    ///
+    /// let mut cx = noop_context();
+    ///
+    /// assert_eq!(reader.as_mut().poll_fill_buf(&mut cx)?, Poll::Ready(&[1, 2][..]));
+    /// reader.as_mut().consume(2);
+    /// assert_eq!(reader.as_mut().poll_fill_buf(&mut cx)?, Poll::Ready(&[3][..]));
+    /// reader.as_mut().consume(1);
+    /// assert_eq!(reader.as_mut().poll_fill_buf(&mut cx)?, Poll::Ready(&[][..]));
+    ///
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    fn limited_cycle(self, limits: Vec<usize>) -> LimitedSchedule<Self>
+    where
+        Self: Sized,
+    {
+        LimitedSchedule::new(self, limits, true)
+    }
 }
 
 impl<R> AsyncReadTestExt for R where R: AsyncRead {}
@@ -3,6 +3,7 @@
 use futures_io::AsyncWrite;
 
 pub use super::limited::Limited;
+pub use super::limited_schedule::LimitedSchedule;
 pub use crate::interleave_pending::InterleavePending;
 
 /// Additional combinators for testing async writers.
@@ -82,6 +83,84 @@ pub trait AsyncWriteTestExt: AsyncWrite {
     {
         Limited::new(self, limit)
     }
+
+    /// Limit the number of bytes allowed to be written on each call to
+    /// `poll_write` according to a one-shot schedule.
+    ///
+    /// The `n`th successful write is limited to `limits[n]` bytes. Once the
+    /// schedule is used up, writes are no longer limited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// use futures::task::Poll;
+    /// use futures::io::AsyncWrite;
+    /// use futures_test::task::noop_context;
+    /// use futures_test::io::AsyncWriteTestExt;
+    /// use pin_utils::pin_mut;
+    ///
+    /// let writer = std::io::Cursor::new([0u8; 6]).limited_write_schedule(vec![1, 3]);
+    /// pin_mut!(writer);
+    ///
+    /// let mut cx = noop_context();
+    ///
+    /// assert_eq!(writer.as_mut().poll_write(&mut cx, &[1, 2])?, Poll::Ready(1));
+    /// assert_eq!(writer.get_ref().get_ref(), &[1, 0, 0, 0, 0, 0]);
+    /// assert_eq!(writer.as_mut().poll_write(&mut cx, &[2, 3, 4, 5])?, Poll::Ready(3));
+    /// assert_eq!(writer.get_ref().get_ref(), &[1, 2, 3, 4, 0, 0]);
+    /// assert_eq!(writer.as_mut().poll_write(&mut cx, &[5, 6])?, Poll::Ready(2));
+    /// assert_eq!(writer.get_ref().get_ref(), &[1, 2, 3, 4, 5, 6]);
+    ///
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    fn limited_write_schedule(self, limits: Vec<usize>) -> LimitedSchedule<Self>
+    where
+        Self: Sized,
+    {
+        LimitedSchedule::new(self, limits, false)
+    }
+
+    /// Limit the number of bytes allowed to be written on each call to
+    /// `poll_write` according to a schedule that repeats forever.
+    ///
+    /// This behaves like
+    /// [`limited_write_schedule`](AsyncWriteTestExt::limited_write_schedule),
+    /// except that the schedule starts over once it is used up.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `limits` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// use futures::task::Poll;
+    /// use futures::io::AsyncWrite;
+    /// use futures_test::task::noop_context;
+    /// use futures_test::io::AsyncWriteTestExt;
+    /// use pin_utils::pin_mut;
+    ///
+    /// let writer = std::io::Cursor::new([0u8; 6]).limited_write_cycle(vec![2, 1]);
+    /// pin_mut!(writer);
+    ///
+    /// let mut cx = noop_context();
+    ///
+    /// assert_eq!(writer.as_mut().poll_write(&mut cx, &[1, 2, 3])?, Poll::Ready(2));
+    /// assert_eq!(writer.as_mut().poll_write(&mut cx, &[3, 4, 5])?, Poll::Ready(1));
+    /// assert_eq!(writer.as_mut().poll_write(&mut cx, &[4, 5, 6])?, Poll::Ready(2));
+    /// assert_eq!(writer.as_mut().poll_write(&mut cx, &[6])?, Poll::Ready(1));
+    /// assert_eq!(writer.get_ref().get_ref(), &[1, 2, 3, 4, 5, 6]);
+    ///
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    fn limited_write_cycle(self, limits: Vec<usize>) -> LimitedSchedule<Self>
+    where
+        Self: Sized,
+    {
+        LimitedSchedule::new(self, limits, true)
+    }
 }
 
 impl<W> AsyncWriteTestExt for W where W: AsyncWrite {}
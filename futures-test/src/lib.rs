@@ -32,6 +32,9 @@ pub mod future;
 #[cfg(feature = "std")]
 pub mod stream;
 
+#[cfg(feature = "std")]
+pub mod sink;
+
 #[cfg(feature = "std")]
 pub mod io;
 
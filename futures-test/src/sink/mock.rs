@@ -0,0 +1,193 @@
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use futures_util::ready;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::time::Instant;
+
+/// A [`Sink`] whose readiness and flushing behaviour is scripted by the test
+/// and which records every item fed to it.
+///
+/// By default a `MockSink` is always ready and flushes instantly. Results
+/// queued with [`push_ready`](MockSink::push_ready) and
+/// [`push_flush`](MockSink::push_flush) are returned, one per call, by
+/// `poll_ready` and `poll_flush` respectively before falling back to that
+/// default. A scripted [`Poll::Pending`] wakes the task immediately so that
+/// executors keep polling.
+///
+/// With [`with_capacity`](MockSink::with_capacity), `poll_ready` only admits
+/// a new item once there is room among the unflushed items, flushing (and so
+/// consuming the flush script) when the sink is full, like a real buffered
+/// sink would.
+///
+/// The sink panics if `start_send` is called without a preceding successful
+/// `poll_ready`, or after `poll_close` has completed, so tests also catch
+/// misuse of the `Sink` protocol.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await)]
+/// # futures::executor::block_on(async {
+/// use futures::sink::SinkExt;
+/// use futures::task::Poll;
+/// use futures_test::sink::MockSink;
+///
+/// let mut sink = MockSink::<u32, &str>::new();
+/// sink.push_ready(Poll::Pending);
+/// sink.push_flush(Poll::Pending);
+///
+/// sink.send(1).await?;
+/// assert_eq!(sink.items(), vec![1]);
+/// assert_eq!(sink.flushed_len(), 1);
+///
+/// sink.push_ready(Poll::Ready(Err("full")));
+/// assert_eq!(sink.send(2).await, Err("full"));
+/// assert_eq!(sink.items(), vec![1]);
+/// # Ok::<(), &str>(()) }).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct MockSink<T, E = ()> {
+    fed: Vec<(Instant, T)>,
+    flushed: usize,
+    capacity: Option<usize>,
+    ready_script: VecDeque<Poll<Result<(), E>>>,
+    flush_script: VecDeque<Poll<Result<(), E>>>,
+    ready: bool,
+    closed: bool,
+}
+
+impl<T, E> Unpin for MockSink<T, E> {}
+
+impl<T, E> MockSink<T, E> {
+    /// Creates a sink which is always ready and has no capacity limit.
+    pub fn new() -> Self {
+        MockSink {
+            fed: Vec::new(),
+            flushed: 0,
+            capacity: None,
+            ready_script: VecDeque::new(),
+            flush_script: VecDeque::new(),
+            ready: false,
+            closed: false,
+        }
+    }
+
+    /// Creates a sink which holds at most `capacity` unflushed items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn with_capacity(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be greater than zero");
+        MockSink { capacity: Some(capacity), ..MockSink::new() }
+    }
+
+    /// Queues the result of a future call to `poll_ready`.
+    pub fn push_ready(&mut self, result: Poll<Result<(), E>>) {
+        self.ready_script.push_back(result);
+    }
+
+    /// Queues the result of a future flush, whether requested through
+    /// `poll_flush`, `poll_close` or a full sink in `poll_ready`.
+    pub fn push_flush(&mut self, result: Poll<Result<(), E>>) {
+        self.flush_script.push_back(result);
+    }
+
+    /// Returns every item fed to this sink so far, along with the instant at
+    /// which it was passed to `start_send`.
+    pub fn fed(&self) -> &[(Instant, T)] {
+        &self.fed
+    }
+
+    /// Returns a copy of every item fed to this sink so far.
+    pub fn items(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.fed.iter().map(|(_, item)| item.clone()).collect()
+    }
+
+    /// Returns how many of the fed items have been flushed.
+    pub fn flushed_len(&self) -> usize {
+        self.flushed
+    }
+
+    /// Returns whether `poll_close` has completed successfully.
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    fn next_result(
+        script: &mut VecDeque<Poll<Result<(), E>>>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), E>> {
+        match script.pop_front() {
+            Some(Poll::Pending) => {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+            Some(result) => result,
+            None => Poll::Ready(Ok(())),
+        }
+    }
+
+    fn flush(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), E>> {
+        ready!(Self::next_result(&mut self.flush_script, cx))?;
+        self.flushed = self.fed.len();
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<T, E> Default for MockSink<T, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, E> Sink<T> for MockSink<T, E> {
+    type SinkError = E;
+
+    fn poll_ready(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), E>> {
+        let this = self.get_mut();
+        if !this.ready {
+            ready!(Self::next_result(&mut this.ready_script, cx))?;
+            this.ready = true;
+        }
+        if let Some(capacity) = this.capacity {
+            if this.fed.len() - this.flushed >= capacity {
+                ready!(this.flush(cx))?;
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), E> {
+        let this = self.get_mut();
+        assert!(!this.closed, "`start_send` called on a closed `MockSink`");
+        assert!(this.ready, "`start_send` called without a successful `poll_ready`");
+        this.ready = false;
+        this.fed.push((Instant::now(), item));
+        Ok(())
+    }
+
+    fn poll_flush(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), E>> {
+        self.get_mut().flush(cx)
+    }
+
+    fn poll_close(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), E>> {
+        let this = self.get_mut();
+        ready!(this.flush(cx))?;
+        this.closed = true;
+        Poll::Ready(Ok(()))
+    }
+}
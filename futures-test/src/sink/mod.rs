@@ -0,0 +1,50 @@
+//! Additional combinators and mocks for testing sinks.
+
+use futures_sink::Sink;
+
+mod mock;
+pub use self::mock::MockSink;
+
+pub use crate::interleave_pending::InterleavePending;
+
+/// Additional combinators for testing sinks.
+pub trait SinkTestExt<Item>: Sink<Item> {
+    /// Introduces an extra [`Poll::Pending`](futures_core::task::Poll::Pending)
+    /// in between each call to `poll_ready`, `poll_flush` and `poll_close` on
+    /// the sink.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// use futures::sink::Sink;
+    /// use futures::task::Poll;
+    /// use futures_test::sink::{MockSink, SinkTestExt};
+    /// use futures_test::task::noop_context;
+    /// use pin_utils::pin_mut;
+    ///
+    /// let sink = MockSink::<u32>::new().interleave_pending_sink();
+    /// pin_mut!(sink);
+    ///
+    /// let mut cx = noop_context();
+    ///
+    /// assert_eq!(sink.as_mut().poll_ready(&mut cx), Poll::Pending);
+    /// assert_eq!(sink.as_mut().poll_ready(&mut cx), Poll::Ready(Ok(())));
+    /// assert_eq!(sink.as_mut().start_send(1), Ok(()));
+    ///
+    /// assert_eq!(sink.as_mut().poll_flush(&mut cx), Poll::Pending);
+    /// assert_eq!(sink.as_mut().poll_flush(&mut cx), Poll::Ready(Ok(())));
+    /// assert_eq!(sink.get_ref().flushed_len(), 1);
+    ///
+    /// assert_eq!(sink.as_mut().poll_close(&mut cx), Poll::Pending);
+    /// assert_eq!(sink.as_mut().poll_close(&mut cx), Poll::Ready(Ok(())));
+    /// ```
+    fn interleave_pending_sink(self) -> InterleavePending<Self>
+    where
+        Self: Sized,
+    {
+        InterleavePending::new(self)
+    }
+}
+
+impl<Si, Item> SinkTestExt<Item> for Si where Si: Sink<Item> {}
@@ -35,6 +35,10 @@
 //!   called.
 //! - [`RecordSpawner`](crate::task::RecordSpawner) records the spawned futures.
 //!
+//! Test traces:
+//! - [`Trace`](crate::task::Trace) records when tasks are spawned, polled,
+//!   woken and completed, to be printed when debugging a flaky test.
+//!
 //! For convenience there additionally exist various functions that directly
 //! return waker/spawner references: [`noop_waker_ref`](crate::task::noop_waker_ref),
 //! [`panic_waker_ref`](crate::task::panic_waker_ref), [`noop_spawner_mut`](crate::task::noop_spawner_mut) and [`panic_spawner_mut`](crate::task::panic_spawner_mut).
@@ -56,5 +60,8 @@ pub use self::panic_waker::{panic_waker, panic_waker_ref};
 mod record_spawner;
 pub use self::record_spawner::RecordSpawner;
 
+mod trace;
+pub use self::trace::{DumpOnPanic, Trace, TraceEvent, TraceEventKind, TraceSpawner, Traced};
+
 mod wake_counter;
 pub use self::wake_counter::{AwokenCount, new_count_waker};
@@ -0,0 +1,330 @@
+use futures_core::future::{Future, FutureObj, LocalFutureObj};
+use futures_core::task::{Context, LocalSpawn, Poll, Spawn, SpawnError, Waker};
+use futures_util::task::ArcWake;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// A record of what happened to the tasks of a test, for debugging tests
+/// which fail only some of the time.
+///
+/// Futures are traced by wrapping them with [`Trace::task`], or by spawning
+/// them through a spawner wrapped with [`Trace::spawner`]. Each traced task
+/// gets a number, and every time such a task is spawned, polled, woken or
+/// completes, a [`TraceEvent`] is recorded. The trace can be printed through
+/// its [`Display`](std::fmt::Display) implementation, one event per line,
+/// exported as JSON with [`Trace::to_json`], or printed to stderr
+/// automatically if the test panics with [`Trace::dump_on_panic`].
+///
+/// Clones of a `Trace` share the same record.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await)]
+/// use futures::executor::LocalPool;
+/// use futures::task::SpawnExt;
+/// use futures_test::task::{Trace, TraceEventKind};
+///
+/// let trace = Trace::new();
+/// let _guard = trace.dump_on_panic();
+///
+/// let mut pool = LocalPool::new();
+/// let mut spawner = trace.spawner(pool.spawner());
+/// spawner.spawn(async { }).unwrap();
+/// pool.run();
+///
+/// let kinds: Vec<_> = trace.events().iter().map(|event| event.kind).collect();
+/// assert_eq!(kinds, vec![
+///     TraceEventKind::Spawned,
+///     TraceEventKind::Polled,
+///     TraceEventKind::Completed,
+/// ]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Trace {
+    inner: Arc<Mutex<TraceInner>>,
+}
+
+#[derive(Debug, Default)]
+struct TraceInner {
+    events: Vec<TraceEvent>,
+    tasks: usize,
+}
+
+/// An event recorded in a [`Trace`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TraceEvent {
+    /// The position of this event in the trace, starting from zero.
+    pub seq: usize,
+    /// The number of the task this event happened to, starting from zero in
+    /// the order the tasks were registered with the trace.
+    pub task: usize,
+    /// What happened to the task.
+    pub kind: TraceEventKind,
+}
+
+/// What happened to a task in a [`TraceEvent`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TraceEventKind {
+    /// The task was spawned, or wrapped with [`Trace::task`].
+    Spawned,
+    /// The task is being polled.
+    Polled,
+    /// The waker of the task was woken.
+    Woken,
+    /// A poll of the task returned [`Poll::Ready`].
+    Completed,
+}
+
+impl TraceEventKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            TraceEventKind::Spawned => "spawned",
+            TraceEventKind::Polled => "polled",
+            TraceEventKind::Woken => "woken",
+            TraceEventKind::Completed => "completed",
+        }
+    }
+}
+
+impl Trace {
+    /// Create a new, empty trace.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wrap a future as a new task of this trace.
+    ///
+    /// This is useful for the future passed to an executor directly, such as
+    /// the one given to [`block_on`](futures_executor::block_on).
+    pub fn task<Fut: Future>(&self, future: Fut) -> Traced<Fut> {
+        let task = self.register();
+        self.record(task, TraceEventKind::Spawned);
+        Traced::new(future, self.clone(), task)
+    }
+
+    /// Wrap a spawner so that every future spawned through it becomes a new
+    /// task of this trace.
+    pub fn spawner<Sp>(&self, spawner: Sp) -> TraceSpawner<Sp> {
+        TraceSpawner {
+            spawner,
+            trace: self.clone(),
+        }
+    }
+
+    /// Returns the events recorded so far.
+    pub fn events(&self) -> Vec<TraceEvent> {
+        self.inner.lock().unwrap().events.clone()
+    }
+
+    /// Returns the events recorded so far as a JSON array.
+    ///
+    /// Each event is an object like
+    /// `{"seq":0,"task":0,"event":"spawned"}`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::future;
+    /// use futures_test::future::FutureTestExt;
+    /// use futures_test::task::Trace;
+    ///
+    /// let trace = Trace::new();
+    /// block_on(trace.task(future::ready(()).pending_once()));
+    ///
+    /// assert_eq!(trace.to_string(), "\
+    /// #0 task 0 spawned
+    /// #1 task 0 polled
+    /// #2 task 0 woken
+    /// #3 task 0 polled
+    /// #4 task 0 completed
+    /// ");
+    /// assert!(trace.to_json().starts_with(
+    ///     r#"[{"seq":0,"task":0,"event":"spawned"},{"seq":1,"task":0,"event":"polled"},"#
+    /// ));
+    /// ```
+    pub fn to_json(&self) -> String {
+        let events = self.events();
+        let mut json = String::from("[");
+        for (i, event) in events.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str(&format!(
+                "{{\"seq\":{},\"task\":{},\"event\":\"{}\"}}",
+                event.seq,
+                event.task,
+                event.kind.as_str(),
+            ));
+        }
+        json.push(']');
+        json
+    }
+
+    /// Returns a guard which prints this trace to stderr if it is dropped
+    /// while the thread is panicking, for example because an assertion of
+    /// the test failed.
+    pub fn dump_on_panic(&self) -> DumpOnPanic {
+        DumpOnPanic { trace: self.clone() }
+    }
+
+    fn register(&self) -> usize {
+        let mut inner = self.inner.lock().unwrap();
+        inner.tasks += 1;
+        inner.tasks - 1
+    }
+
+    fn record(&self, task: usize, kind: TraceEventKind) {
+        let mut inner = self.inner.lock().unwrap();
+        let seq = inner.events.len();
+        inner.events.push(TraceEvent { seq, task, kind });
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for event in self.events() {
+            writeln!(f, "#{} task {} {}", event.seq, event.task, event.kind.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+/// Guard for the [`dump_on_panic`](Trace::dump_on_panic) method.
+#[derive(Debug)]
+#[must_use = "the trace is only dumped when the guard is dropped"]
+pub struct DumpOnPanic {
+    trace: Trace,
+}
+
+impl Drop for DumpOnPanic {
+    fn drop(&mut self) {
+        if thread::panicking() {
+            eprintln!("task trace:\n{}", self.trace);
+        }
+    }
+}
+
+/// Future for the [`task`](Trace::task) method, which records the events of
+/// the future it wraps.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Traced<Fut> {
+    future: Fut,
+    trace: Trace,
+    task: usize,
+}
+
+impl<Fut: Unpin> Unpin for Traced<Fut> {}
+
+impl<Fut> Traced<Fut> {
+    unsafe_pinned!(future: Fut);
+    unsafe_unpinned!(trace: Trace);
+
+    fn new(future: Fut, trace: Trace, task: usize) -> Self {
+        Self { future, trace, task }
+    }
+
+    /// Returns the number this task has in its trace.
+    pub fn task(&self) -> usize {
+        self.task
+    }
+}
+
+impl<Fut: Future> Future for Traced<Fut> {
+    type Output = Fut::Output;
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Self::Output> {
+        let task = self.task;
+        let trace = self.as_mut().trace().clone();
+        trace.record(task, TraceEventKind::Polled);
+
+        let waker = ArcWake::into_waker(Arc::new(TracedWaker {
+            trace: trace.clone(),
+            task,
+            waker: cx.waker().clone(),
+        }));
+        let poll = self.as_mut().future().poll(&mut Context::from_waker(&waker));
+        if poll.is_ready() {
+            trace.record(task, TraceEventKind::Completed);
+        }
+        poll
+    }
+}
+
+struct TracedWaker {
+    trace: Trace,
+    task: usize,
+    waker: Waker,
+}
+
+impl ArcWake for TracedWaker {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.trace.record(arc_self.task, TraceEventKind::Woken);
+        arc_self.waker.wake_by_ref();
+    }
+}
+
+/// A spawner which traces the futures spawned through it, created by the
+/// [`spawner`](Trace::spawner) method.
+#[derive(Debug)]
+pub struct TraceSpawner<Sp> {
+    spawner: Sp,
+    trace: Trace,
+}
+
+impl<Sp> TraceSpawner<Sp> {
+    /// Get a shared reference to the inner spawner.
+    pub fn get_ref(&self) -> &Sp {
+        &self.spawner
+    }
+
+    /// Get a mutable reference to the inner spawner.
+    pub fn get_mut(&mut self) -> &mut Sp {
+        &mut self.spawner
+    }
+
+    /// Consumes this spawner, returning the inner spawner.
+    pub fn into_inner(self) -> Sp {
+        self.spawner
+    }
+}
+
+impl<Sp: Spawn> Spawn for TraceSpawner<Sp> {
+    fn spawn_obj(
+        &mut self,
+        future: FutureObj<'static, ()>,
+    ) -> Result<(), SpawnError> {
+        let task = self.trace.register();
+        let future = Traced::new(future, self.trace.clone(), task);
+        self.trace.record(task, TraceEventKind::Spawned);
+        self.spawner.spawn_obj(FutureObj::new(Box::new(future)))
+    }
+
+    fn status(&self) -> Result<(), SpawnError> {
+        self.spawner.status()
+    }
+}
+
+impl<Sp: LocalSpawn> LocalSpawn for TraceSpawner<Sp> {
+    fn spawn_local_obj(
+        &mut self,
+        future: LocalFutureObj<'static, ()>,
+    ) -> Result<(), SpawnError> {
+        let task = self.trace.register();
+        let future = Traced::new(future, self.trace.clone(), task);
+        self.trace.record(task, TraceEventKind::Spawned);
+        self.spawner.spawn_local_obj(LocalFutureObj::new(Box::new(future)))
+    }
+
+    fn status_local(&self) -> Result<(), SpawnError> {
+        self.spawner.status_local()
+    }
+}
@@ -0,0 +1,86 @@
+#![feature(test)]
+
+extern crate test;
+use crate::test::Bencher;
+
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::{self, Future};
+use futures::stream::{self, StreamExt};
+use futures::task::{Context, Poll};
+use std::pin::Pin;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const ITEMS: usize = 50;
+const WINDOW: usize = 2;
+// How long each job takes to complete once it has been started
+const LATENCY: Duration = Duration::from_micros(400);
+// How long the consumer spends on each output
+const PROCESSING: Duration = Duration::from_micros(200);
+
+// Runs each job on a worker thread, completing it after `LATENCY`.
+struct Workers(mpsc::Sender<oneshot::Sender<()>>);
+
+impl Workers {
+    fn new(n: usize) -> Workers {
+        let (tx, rx) = mpsc::channel::<oneshot::Sender<()>>();
+        let rx = Arc::new(Mutex::new(rx));
+        for _ in 0..n {
+            let rx = rx.clone();
+            thread::spawn(move || loop {
+                let job = match rx.lock().unwrap().recv() {
+                    Ok(job) => job,
+                    Err(_) => return,
+                };
+                thread::sleep(LATENCY);
+                let _ = job.send(());
+            });
+        }
+        Workers(tx)
+    }
+
+    // Only submits the job once polled, like most real futures.
+    fn job(&self) -> Job {
+        Job { workers: self.0.clone(), rx: None }
+    }
+}
+
+struct Job {
+    workers: mpsc::Sender<oneshot::Sender<()>>,
+    rx: Option<oneshot::Receiver<()>>,
+}
+
+impl Future for Job {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.rx.is_none() {
+            let (tx, rx) = oneshot::channel();
+            self.workers.send(tx).unwrap();
+            self.rx = Some(rx);
+        }
+        Pin::new(self.rx.as_mut().unwrap()).poll(cx).map(|res| res.unwrap())
+    }
+}
+
+fn spin(duration: Duration) {
+    let start = Instant::now();
+    while start.elapsed() < duration {}
+}
+
+/// A consumer that takes time for each output, which the window should
+/// overlap with the jobs that follow it.
+#[bench]
+fn slow_consumer(b: &mut Bencher) {
+    let workers = Workers::new(WINDOW);
+    b.iter(|| {
+        let st = stream::iter(0..ITEMS).map(|_| workers.job()).buffered(WINDOW);
+        block_on(st.for_each(|()| {
+            spin(PROCESSING);
+            future::ready(())
+        }));
+    });
+}
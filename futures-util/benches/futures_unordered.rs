@@ -41,3 +41,33 @@ fn oneshots(b: &mut Bencher) {
         }))
     });
 }
+
+#[bench]
+fn yielding_futures(b: &mut Bencher) {
+    const NUM: usize = 1_000;
+    const YIELDS: usize = 10;
+
+    b.iter(|| {
+        let mut futs = (0..NUM).map(|_| {
+            let mut remaining = YIELDS;
+            future::poll_fn(move |cx| {
+                if remaining == 0 {
+                    return Poll::Ready(());
+                }
+                remaining -= 1;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            })
+        }).collect::<FuturesUnordered<_>>();
+
+        block_on(future::poll_fn(move |cx| {
+            loop {
+                match futs.poll_next_unpin(cx) {
+                    Poll::Ready(None) => return Poll::Ready(()),
+                    Poll::Ready(Some(())) => {}
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+        }))
+    });
+}
@@ -0,0 +1,33 @@
+#![feature(test)]
+
+extern crate test;
+use crate::test::Bencher;
+
+use futures::channel::oneshot;
+use futures::future::{self, FutureExt};
+use futures_test::task::noop_context;
+
+const FUTURES: usize = 10_000;
+
+// Completes the futures one at a time, polling the `join_all` future after
+// each one, so that every completion is a separate wakeup.
+fn one_at_a_time(n: usize) {
+    let (txs, rxs): (Vec<_>, Vec<_>) = (0..n).map(|_| oneshot::channel::<()>()).unzip();
+    let mut fut = future::join_all(rxs);
+    let mut cx = noop_context();
+    assert!(fut.poll_unpin(&mut cx).is_pending());
+    for tx in txs {
+        tx.send(()).unwrap();
+        let _ = fut.poll_unpin(&mut cx);
+    }
+}
+
+#[bench]
+fn oneshots_completing_one_at_a_time(b: &mut Bencher) {
+    b.iter(|| one_at_a_time(FUTURES));
+}
+
+#[bench]
+fn few_oneshots_completing_one_at_a_time(b: &mut Bencher) {
+    b.iter(|| one_at_a_time(10));
+}
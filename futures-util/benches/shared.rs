@@ -0,0 +1,35 @@
+#![feature(test)]
+
+extern crate test;
+use crate::test::Bencher;
+
+use futures::channel::oneshot;
+use futures::future::FutureExt;
+use futures::task::Context;
+use futures_test::task::new_count_waker;
+
+const CLONES: usize = 100;
+const POLLS: usize = 100;
+
+/// Many clones of a `Shared` which are each polled many times by the same
+/// task before the output is ready.
+#[bench]
+fn poll_heavy(b: &mut Bencher) {
+    let (waker, _) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    b.iter(|| {
+        let (tx, rx) = oneshot::channel::<u32>();
+        let shared = rx.shared();
+        let mut clones: Vec<_> = (0..CLONES).map(|_| shared.clone()).collect();
+        for _ in 0..POLLS {
+            for clone in &mut clones {
+                assert!(clone.poll_unpin(&mut cx).is_pending());
+            }
+        }
+        tx.send(1).unwrap();
+        for clone in &mut clones {
+            assert!(clone.poll_unpin(&mut cx).is_ready());
+        }
+    });
+}
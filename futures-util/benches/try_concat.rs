@@ -0,0 +1,32 @@
+#![feature(test)]
+
+extern crate test;
+use crate::test::Bencher;
+
+use futures::executor::block_on;
+use futures::stream::{self, TryStreamExt};
+
+const CHUNKS: usize = 10_000;
+const CHUNK_LEN: usize = 100;
+
+fn chunks() -> Vec<Result<Vec<u8>, ()>> {
+    (0..CHUNKS).map(|i| Ok(vec![i as u8; CHUNK_LEN])).collect()
+}
+
+#[bench]
+fn small_chunks(b: &mut Bencher) {
+    b.iter(|| {
+        let st = stream::iter(chunks());
+        let bytes = block_on(st.try_concat()).unwrap();
+        assert_eq!(bytes.len(), CHUNKS * CHUNK_LEN);
+    });
+}
+
+#[bench]
+fn small_chunks_with_capacity(b: &mut Bencher) {
+    b.iter(|| {
+        let st = stream::iter(chunks());
+        let bytes = block_on(st.try_concat_with_capacity(CHUNKS * CHUNK_LEN)).unwrap();
+        assert_eq!(bytes.len(), CHUNKS * CHUNK_LEN);
+    });
+}
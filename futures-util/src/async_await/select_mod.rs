@@ -85,6 +85,31 @@ macro_rules! document_select_macro {
         /// # });
         /// ```
         ///
+        /// A branch can be given a guard with `, if <condition>` after the future.
+        /// All guards are evaluated each time `select!` is run, before any of the
+        /// futures are evaluated, and a branch whose guard is `false` is not
+        /// polled for that run of `select!`. Disabled branches count as completed,
+        /// so `complete` runs if every branch is either completed or disabled.
+        ///
+        /// ```
+        /// #![feature(async_await)]
+        /// # futures::executor::block_on(async {
+        /// use futures::stream::{self, StreamExt};
+        /// use futures::select;
+        /// let mut st = stream::iter(vec![1, 2, 3]).fuse();
+        /// let mut total = 0;
+        ///
+        /// loop {
+        ///     select! {
+        ///         // only take items from the stream while the total is small
+        ///         x = st.next(), if total < 3 => total += x.unwrap(),
+        ///         complete => break,
+        ///     };
+        /// }
+        /// assert_eq!(total, 3);
+        /// # });
+        /// ```
+        ///
         /// Note that the futures that have been matched over can still be mutated
         /// from inside the `select!` block's branches. This can be used to implement
         /// more complex behavior such as timer resets or writing into the head of
@@ -165,6 +165,14 @@ impl<St: Stream01> Stream03 for Compat01As03<St> {
 }
 
 /// Converts a futures 0.1 Sink object to a futures 0.3-compatible version
+///
+/// An item passed to `start_send` is held back until the next call to
+/// `poll_ready`, `poll_flush` or `poll_close` hands it to the 0.1 sink. If
+/// the 0.1 sink isn't ready for it, `poll_complete` is called to make room,
+/// just like 0.1 combinators such as `send_all` do. `poll_flush` maps to
+/// `poll_complete`, and `poll_close` sends any held-back item and calls
+/// `poll_complete` before calling `close`, so no buffered data is lost for
+/// 0.1 sinks whose `close` doesn't flush.
 #[derive(Debug)]
 #[must_use = "sinks do nothing unless polled"]
 pub struct Compat01As03Sink<S, SinkItem> {
@@ -200,6 +208,22 @@ impl<S, SinkItem> Compat01As03Sink<S, SinkItem> {
     }
 }
 
+// Starts sending an item to a 0.1 sink. A 0.1 sink applying backpressure
+// relies on its caller to drive `poll_complete` until it has room for the
+// item again, so that is tried once before giving up.
+fn start_send_01<S: Sink01>(
+    sink: &mut S,
+    item: S::SinkItem,
+) -> Result<AsyncSink01<S::SinkItem>, S::SinkError> {
+    match sink.start_send(item)? {
+        AsyncSink01::NotReady(item) => match sink.poll_complete()? {
+            Async01::Ready(()) => sink.start_send(item),
+            Async01::NotReady => Ok(AsyncSink01::NotReady(item)),
+        },
+        AsyncSink01::Ready => Ok(AsyncSink01::Ready),
+    }
+}
+
 impl<S, SinkItem> Stream03 for Compat01As03Sink<S, SinkItem>
 where
     S: Stream01,
@@ -237,15 +261,16 @@ where
         mut self: Pin<&mut Self>,
         cx: &mut Context<'_>,
     ) -> task03::Poll<Result<(), Self::SinkError>> {
-        match self.buffer.take() {
-            Some(item) => match self.in_notify(cx, |f| f.start_send(item))? {
-                AsyncSink01::Ready => task03::Poll::Ready(Ok(())),
-                AsyncSink01::NotReady(i) => {
-                    self.buffer = Some(i);
-                    task03::Poll::Pending
-                }
-            },
-            None => task03::Poll::Ready(Ok(())),
+        let item = match self.buffer.take() {
+            Some(item) => item,
+            None => return task03::Poll::Ready(Ok(())),
+        };
+        match self.in_notify(cx, |f| start_send_01(f, item))? {
+            AsyncSink01::Ready => task03::Poll::Ready(Ok(())),
+            AsyncSink01::NotReady(i) => {
+                self.buffer = Some(i);
+                task03::Poll::Pending
+            }
         }
     }
 
@@ -255,7 +280,7 @@ where
     ) -> task03::Poll<Result<(), Self::SinkError>> {
         let item = self.buffer.take();
         match self.in_notify(cx, |f| match item {
-            Some(i) => match f.start_send(i)? {
+            Some(i) => match start_send_01(f, i)? {
                 AsyncSink01::Ready => f.poll_complete().map(|i| (i, None)),
                 AsyncSink01::NotReady(t) => {
                     Ok((Async01::NotReady, Some(t)))
@@ -281,7 +306,7 @@ where
         let result = self.in_notify(cx, |f| {
             if !close_started {
                 if let Some(item) = item {
-                    if let AsyncSink01::NotReady(item) = f.start_send(item)? {
+                    if let AsyncSink01::NotReady(item) = start_send_01(f, item)? {
                         return Ok((Async01::NotReady, Some(item), false));
                     }
                 }
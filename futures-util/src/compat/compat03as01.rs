@@ -35,6 +35,11 @@ pub struct Compat<T> {
 }
 
 /// Converts a futures 0.3 Sink object to a futures 0.1-compatible version
+///
+/// `start_send` calls `poll_ready` and returns the item back as
+/// `AsyncSink::NotReady` if the 0.3 sink isn't ready for it. `poll_complete`
+/// maps to `poll_flush` and `close` maps to `poll_close`, which flushes the
+/// 0.3 sink before closing it.
 #[derive(Debug)]
 #[must_use = "sinks do nothing unless polled"]
 pub struct CompatSink<T, Item> {
@@ -1,12 +1,25 @@
 use crate::task::AtomicWaker;
 use futures_core::future::Future;
+use futures_core::stream::Stream;
 use futures_core::task::{Context, Poll};
 use pin_utils::unsafe_pinned;
 use core::pin::Pin;
 use core::sync::atomic::{AtomicBool, Ordering};
 use alloc::sync::Arc;
+#[cfg(feature = "std")]
+use alloc::sync::Weak;
+#[cfg(feature = "std")]
+use core::mem;
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::sync::Mutex;
 
-/// A future which can be remotely short-circuited using an `AbortHandle`.
+/// A future or stream which can be remotely short-circuited using an
+/// `AbortHandle` or an `AbortRegistry`.
+///
+/// An aborted future resolves to `Err(Aborted)`, while an aborted stream
+/// terminates.
 #[derive(Debug, Clone)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct Abortable<Fut> {
@@ -16,7 +29,7 @@ pub struct Abortable<Fut> {
 
 impl<Fut: Unpin> Unpin for Abortable<Fut> {}
 
-impl<Fut> Abortable<Fut> where Fut: Future {
+impl<Fut> Abortable<Fut> {
     unsafe_pinned!(future: Fut);
 
     /// Creates a new `Abortable` future using an existing `AbortRegistration`.
@@ -48,8 +61,8 @@ impl<Fut> Abortable<Fut> where Fut: Future {
 }
 
 /// A registration handle for a `Abortable` future.
-/// Values of this type can be acquired from `AbortHandle::new` and are used
-/// in calls to `Abortable::new`.
+/// Values of this type can be acquired from `AbortHandle::new` or
+/// `AbortRegistry::register` and are used in calls to `Abortable::new`.
 #[derive(Debug)]
 pub struct AbortRegistration {
     inner: Arc<AbortInner>,
@@ -81,10 +94,7 @@ impl AbortHandle {
     /// # });
     /// ```
     pub fn new_pair() -> (Self, AbortRegistration) {
-        let inner = Arc::new(AbortInner {
-            waker: AtomicWaker::new(),
-            cancel: AtomicBool::new(false),
-        });
+        let inner = Arc::new(AbortInner::new(false));
 
         (
             AbortHandle {
@@ -105,6 +115,122 @@ struct AbortInner {
     cancel: AtomicBool,
 }
 
+impl AbortInner {
+    fn new(cancel: bool) -> Self {
+        AbortInner {
+            waker: AtomicWaker::new(),
+            cancel: AtomicBool::new(cancel),
+        }
+    }
+
+    fn abort(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+        self.waker.wake();
+    }
+
+    fn is_aborted(&self) -> bool {
+        self.cancel.load(Ordering::Relaxed)
+    }
+}
+
+/// A handle which can abort any number of `Abortable` futures and streams at
+/// once.
+///
+/// Unlike `AbortHandle::new_pair`, which ties one handle to one
+/// registration, `register` can be called any number of times. Calling
+/// `abort` aborts every `Abortable` created from one of these registrations,
+/// and registrations created afterwards start out aborted.
+///
+/// Clones of an `AbortRegistry` share the same set of registrations.
+///
+/// This type is only available when the `std` feature of this library is
+/// activated, and it is activated by default.
+///
+/// Example:
+///
+/// ```
+/// #![feature(async_await)]
+/// # futures::executor::block_on(async {
+/// use futures::future::{empty, Abortable, AbortRegistry, Aborted};
+///
+/// let registry = AbortRegistry::new();
+/// let a = Abortable::new(empty::<()>(), registry.register());
+/// let b = Abortable::new(empty::<()>(), registry.register());
+/// registry.abort();
+/// assert_eq!(a.await, Err(Aborted));
+/// assert_eq!(b.await, Err(Aborted));
+/// # });
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default)]
+pub struct AbortRegistry {
+    inner: Arc<RegistryInner>,
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+struct RegistryInner {
+    // Only written with `entries` locked, so that a registration can't slip
+    // in between aborting and notifying the existing registrations.
+    cancel: AtomicBool,
+    // Registrations are held weakly so that an `Abortable` which completes
+    // normally releases its waker.
+    entries: Mutex<Vec<Weak<AbortInner>>>,
+}
+
+#[cfg(feature = "std")]
+impl AbortRegistry {
+    /// Creates a new `AbortRegistry` without any registrations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `AbortRegistration` tied to this registry.
+    ///
+    /// If `abort` has already been called, the registration is already
+    /// aborted.
+    pub fn register(&self) -> AbortRegistration {
+        let mut entries = self.inner.entries.lock().unwrap();
+        if self.inner.cancel.load(Ordering::Relaxed) {
+            return AbortRegistration { inner: Arc::new(AbortInner::new(true)) };
+        }
+
+        // Drop the entries of registrations that are gone before the vector
+        // has to grow, which keeps its length proportional to the number of
+        // live registrations.
+        if entries.len() == entries.capacity() {
+            entries.retain(|entry| entry.upgrade().is_some());
+        }
+
+        let inner = Arc::new(AbortInner::new(false));
+        entries.push(Arc::downgrade(&inner));
+        AbortRegistration { inner }
+    }
+
+    /// Abort every `Abortable` future and stream registered with this
+    /// registry, as well as any registered afterwards.
+    ///
+    /// As with `AbortHandle::abort`, futures currently being polled on
+    /// another thread keep running until their poll method returns.
+    pub fn abort(&self) {
+        let entries = {
+            let mut entries = self.inner.entries.lock().unwrap();
+            self.inner.cancel.store(true, Ordering::Relaxed);
+            mem::replace(&mut *entries, Vec::new())
+        };
+        for entry in entries {
+            if let Some(inner) = entry.upgrade() {
+                inner.abort();
+            }
+        }
+    }
+
+    /// Returns `true` if `abort` has been called on this registry.
+    pub fn is_aborted(&self) -> bool {
+        self.inner.cancel.load(Ordering::Relaxed)
+    }
+}
+
 /// Creates a new `Abortable` future and a `AbortHandle` which can be used to stop it.
 ///
 /// This function is a convenient (but less flexible) alternative to calling
@@ -126,17 +252,19 @@ pub fn abortable<Fut>(future: Fut) -> (Abortable<Fut>, AbortHandle)
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct Aborted;
 
-impl<Fut> Future for Abortable<Fut> where Fut: Future {
-    type Output = Result<Fut::Output, Aborted>;
-
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+impl<Fut> Abortable<Fut> {
+    fn try_poll<T>(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        poll: impl FnOnce(Pin<&mut Fut>, &mut Context<'_>) -> Poll<T>,
+    ) -> Poll<Result<T, Aborted>> {
         // Check if the future has been aborted
-        if self.inner.cancel.load(Ordering::Relaxed) {
+        if self.inner.is_aborted() {
             return Poll::Ready(Err(Aborted))
         }
 
         // attempt to complete the future
-        if let Poll::Ready(x) = self.as_mut().future().poll(cx) {
+        if let Poll::Ready(x) = poll(self.as_mut().future(), cx) {
             return Poll::Ready(Ok(x))
         }
 
@@ -147,7 +275,7 @@ impl<Fut> Future for Abortable<Fut> where Fut: Future {
         // registration.
         // Checking with `Relaxed` is sufficient because `register` introduces an
         // `AcqRel` barrier.
-        if self.inner.cancel.load(Ordering::Relaxed) {
+        if self.inner.is_aborted() {
             return Poll::Ready(Err(Aborted))
         }
 
@@ -155,6 +283,23 @@ impl<Fut> Future for Abortable<Fut> where Fut: Future {
     }
 }
 
+impl<Fut> Future for Abortable<Fut> where Fut: Future {
+    type Output = Result<Fut::Output, Aborted>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.try_poll(cx, |fut, cx| fut.poll(cx))
+    }
+}
+
+impl<St> Stream for Abortable<St> where St: Stream {
+    type Item = St::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.try_poll(cx, |stream, cx| stream.poll_next(cx))
+            .map(|res| res.unwrap_or(None))
+    }
+}
+
 impl AbortHandle {
     /// Abort the `Abortable` future associated with this handle.
     ///
@@ -163,7 +308,11 @@ impl AbortHandle {
     /// another thread, it will not immediately stop running. Instead, it will
     /// continue to run until its poll method returns.
     pub fn abort(&self) {
-        self.inner.cancel.store(true, Ordering::Relaxed);
-        self.inner.waker.wake();
+        self.inner.abort();
+    }
+
+    /// Returns `true` if `abort` has been called on this handle.
+    pub fn is_aborted(&self) -> bool {
+        self.inner.is_aborted()
     }
 }
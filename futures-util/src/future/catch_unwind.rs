@@ -1,4 +1,4 @@
-use futures_core::future::Future;
+use futures_core::future::{FusedFuture, Future};
 use futures_core::task::{Context, Poll};
 use pin_utils::unsafe_pinned;
 use std::any::Any;
@@ -6,17 +6,21 @@ use std::pin::Pin;
 use std::panic::{catch_unwind, UnwindSafe, AssertUnwindSafe};
 
 /// Future for the [`catch_unwind`](super::FutureExt::catch_unwind) method.
+///
+/// The underlying future is dropped as soon as it completes or panics, and
+/// a panic raised while dropping a future that has already panicked is
+/// swallowed rather than propagated.
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct CatchUnwind<Fut> where Fut: Future {
-    future: Fut,
+    future: Option<Fut>,
 }
 
 impl<Fut> CatchUnwind<Fut> where Fut: Future + UnwindSafe {
-    unsafe_pinned!(future: Fut);
+    unsafe_pinned!(future: Option<Fut>);
 
     pub(super) fn new(future: Fut) -> CatchUnwind<Fut> {
-        CatchUnwind { future }
+        CatchUnwind { future: Some(future) }
     }
 }
 
@@ -25,7 +29,34 @@ impl<Fut> Future for CatchUnwind<Fut>
 {
     type Output = Result<Fut::Output, Box<dyn Any + Send>>;
 
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        catch_unwind(AssertUnwindSafe(|| self.future().poll(cx)))?.map(Ok)
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let res = {
+            let future = self.as_mut().future().as_pin_mut()
+                .expect("CatchUnwind polled after completion");
+            catch_unwind(AssertUnwindSafe(|| future.poll(cx)))
+        };
+
+        match res {
+            Ok(Poll::Pending) => Poll::Pending,
+            Ok(Poll::Ready(output)) => {
+                self.as_mut().future().set(None);
+                Poll::Ready(Ok(output))
+            }
+            Err(e) => {
+                // The future is in an unknown state after panicking, so its
+                // destructor may well panic too.
+                let mut future = self.as_mut().future();
+                let _ = catch_unwind(AssertUnwindSafe(|| future.set(None)));
+                Poll::Ready(Err(e))
+            }
+        }
+    }
+}
+
+impl<Fut> FusedFuture for CatchUnwind<Fut>
+    where Fut: Future + UnwindSafe,
+{
+    fn is_terminated(&self) -> bool {
+        self.future.is_none()
     }
 }
@@ -0,0 +1,70 @@
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`depend_on`](super::FutureExt::depend_on) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct DependOn<Fut, T> {
+    // Declared before the resource, so that a future dropped before it
+    // completes is dropped while the resource is still alive.
+    future: Fut,
+    resource: Option<T>,
+}
+
+impl<Fut: Unpin, T> Unpin for DependOn<Fut, T> {}
+
+impl<Fut: Future, T> DependOn<Fut, T> {
+    unsafe_pinned!(future: Fut);
+    unsafe_unpinned!(resource: Option<T>);
+
+    pub(super) fn new(future: Fut, resource: T) -> DependOn<Fut, T> {
+        DependOn { future, resource: Some(resource) }
+    }
+
+    /// Acquires a reference to the underlying future.
+    pub fn get_ref(&self) -> &Fut {
+        &self.future
+    }
+
+    /// Acquires a mutable reference to the underlying future.
+    pub fn get_mut(&mut self) -> &mut Fut {
+        &mut self.future
+    }
+
+    /// Acquires a pinned mutable reference to the underlying future.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut Fut> {
+        self.future()
+    }
+
+    /// Acquires a reference to the resource, or `None` if the future has
+    /// completed and the resource has been dropped.
+    pub fn get_resource(&self) -> Option<&T> {
+        self.resource.as_ref()
+    }
+
+    /// Consumes this combinator, returning the underlying future and the
+    /// resource, if it hasn't been dropped yet.
+    ///
+    /// The resource is no longer tied to the future afterwards.
+    pub fn into_inner(self) -> (Fut, Option<T>) {
+        (self.future, self.resource)
+    }
+}
+
+impl<Fut: FusedFuture, T> FusedFuture for DependOn<Fut, T> {
+    fn is_terminated(&self) -> bool {
+        self.future.is_terminated()
+    }
+}
+
+impl<Fut: Future, T> Future for DependOn<Fut, T> {
+    type Output = Fut::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Fut::Output> {
+        let output = ready!(self.as_mut().future().poll(cx));
+        *self.as_mut().resource() = None;
+        Poll::Ready(output)
+    }
+}
@@ -3,6 +3,7 @@ use core::pin::Pin;
 use futures_core::future::Future;
 use futures_core::stream::{FusedStream, Stream};
 use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
 use pin_utils::unsafe_pinned;
 
 /// Stream for the [`flatten_stream`](super::FutureExt::flatten_stream) method.
@@ -21,6 +22,18 @@ impl<Fut: Future> FlattenStream<Fut> {
     }
 }
 
+impl<Fut> FlattenStream<Fut>
+    where Fut: Future,
+{
+    fn poll_future(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if let State::Future(f) = self.as_mut().state().get_pin_mut() {
+            let stream = ready!(f.poll(cx));
+            self.as_mut().state().set(State::Stream(stream));
+        }
+        Poll::Ready(())
+    }
+}
+
 impl<Fut> fmt::Debug for FlattenStream<Fut>
     where Fut: Future + fmt::Debug,
           Fut::Output: fmt::Debug,
@@ -87,3 +100,45 @@ impl<Fut> Stream for FlattenStream<Fut>
         }
     }
 }
+
+impl<Fut, Item> Sink<Item> for FlattenStream<Fut>
+    where Fut: Future,
+          Fut::Output: Sink<Item>,
+{
+    type SinkError = <Fut::Output as Sink<Item>>::SinkError;
+
+    fn poll_ready(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        // Sends are held back until the future has resolved to the sink.
+        ready!(self.as_mut().poll_future(cx));
+        match self.as_mut().state().get_pin_mut() {
+            State::Stream(s) => s.poll_ready(cx),
+            State::Future(_) => unreachable!(),
+        }
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::SinkError> {
+        match self.state().get_pin_mut() {
+            State::Stream(s) => s.start_send(item),
+            State::Future(_) => panic!("poll_ready not called first"),
+        }
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        ready!(self.as_mut().poll_future(cx));
+        match self.as_mut().state().get_pin_mut() {
+            State::Stream(s) => s.poll_flush(cx),
+            State::Future(_) => unreachable!(),
+        }
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        ready!(self.as_mut().poll_future(cx));
+        match self.as_mut().state().get_pin_mut() {
+            State::Stream(s) => s.poll_close(cx),
+            State::Future(_) => unreachable!(),
+        }
+    }
+}
@@ -10,6 +10,16 @@ use core::task::{Context, Poll};
 use alloc::boxed::Box;
 use alloc::vec::Vec;
 
+cfg_target_has_atomic! {
+    use crate::stream::{FuturesOrdered, StreamExt};
+
+    // Lists of up to this many futures are polled in place, all of them on
+    // every wakeup, which is cheaper than giving each future its own waker.
+    // Longer lists are driven through `FuturesOrdered`, which only polls the
+    // futures which have been woken.
+    const SMALL: usize = 30;
+}
+
 #[derive(Debug)]
 enum ElemState<F>
 where
@@ -62,7 +72,24 @@ pub struct JoinAll<F>
 where
     F: Future,
 {
-    elems: Pin<Box<[ElemState<F>]>>,
+    kind: JoinAllKind<F>,
+}
+
+enum JoinAllKind<F>
+where
+    F: Future,
+{
+    Small {
+        elems: Pin<Box<[ElemState<F>]>>,
+    },
+    #[cfg_attr(
+        feature = "cfg-target-has-atomic",
+        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+    )]
+    Big {
+        in_progress: FuturesOrdered<F>,
+        outputs: Vec<F::Output>,
+    },
 }
 
 impl<F> fmt::Debug for JoinAll<F>
@@ -71,12 +98,28 @@ where
     F::Output: fmt::Debug,
 {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct("JoinAll")
-            .field("elems", &self.elems)
-            .finish()
+        match &self.kind {
+            JoinAllKind::Small { elems } => {
+                fmt.debug_struct("JoinAll")
+                    .field("elems", elems)
+                    .finish()
+            }
+            #[cfg_attr(
+                feature = "cfg-target-has-atomic",
+                cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+            )]
+            JoinAllKind::Big { in_progress, outputs } => {
+                fmt.debug_struct("JoinAll")
+                    .field("in_progress", in_progress)
+                    .field("outputs", outputs)
+                    .finish()
+            }
+        }
     }
 }
 
+impl<F: Future> Unpin for JoinAll<F> {}
+
 /// Creates a future which represents a collection of the outputs of the futures
 /// given.
 ///
@@ -84,6 +127,12 @@ where
 /// collecting the results into a destination `Vec<T>` in the same order as they
 /// were provided.
 ///
+/// When the iterator says it has only a few futures, all of them which are
+/// still pending are polled whenever the returned future is woken. Longer
+/// lists of futures are driven through
+/// [`FuturesOrdered`](crate::stream::FuturesOrdered) instead, so that each
+/// wakeup only polls the futures which have been woken.
+///
 /// This function is only available when the `std` or `alloc` feature of this
 /// library is activated, and it is activated by default.
 ///
@@ -96,8 +145,8 @@ where
 ///
 ///  * Adding new futures to the set even after it has been started.
 ///
-///  * Only polling the specific futures that have been woken. In cases where
-///    you have a lot of futures this will result in much more efficient polling.
+///  * Getting the outputs of the futures as soon as they complete, rather
+///    than all of them at the end.
 ///
 /// # Examples
 ///
@@ -118,8 +167,24 @@ where
     I: IntoIterator,
     I::Item: Future,
 {
-    let elems: Box<[_]> = i.into_iter().map(ElemState::Pending).collect();
-    JoinAll { elems: elems.into() }
+    let iter = i.into_iter();
+    #[cfg_attr(
+        feature = "cfg-target-has-atomic",
+        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+    )]
+    {
+        let small = match iter.size_hint().1 {
+            Some(max) => max <= SMALL,
+            None => false,
+        };
+        if !small {
+            let in_progress: FuturesOrdered<_> = iter.collect();
+            let outputs = Vec::with_capacity(in_progress.len());
+            return JoinAll { kind: JoinAllKind::Big { in_progress, outputs } };
+        }
+    }
+    let elems: Box<[_]> = iter.map(ElemState::Pending).collect();
+    JoinAll { kind: JoinAllKind::Small { elems: elems.into() } }
 }
 
 impl<F> Future for JoinAll<F>
@@ -129,26 +194,40 @@ where
     type Output = Vec<F::Output>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let mut all_done = true;
+        match &mut self.kind {
+            JoinAllKind::Small { elems } => {
+                let mut all_done = true;
+
+                for mut elem in iter_pin_mut(elems.as_mut()) {
+                    if let Some(pending) = elem.as_mut().pending_pin_mut() {
+                        if let Poll::Ready(output) = pending.poll(cx) {
+                            elem.set(ElemState::Done(Some(output)));
+                        } else {
+                            all_done = false;
+                        }
+                    }
+                }
 
-        for mut elem in iter_pin_mut(self.elems.as_mut()) {
-            if let Some(pending) = elem.as_mut().pending_pin_mut() {
-                if let Poll::Ready(output) = pending.poll(cx) {
-                    elem.set(ElemState::Done(Some(output)));
+                if all_done {
+                    let mut elems = mem::replace(elems, Box::pin([]));
+                    let result = iter_pin_mut(elems.as_mut())
+                        .map(|e| e.take_done().unwrap())
+                        .collect();
+                    Poll::Ready(result)
                 } else {
-                    all_done = false;
+                    Poll::Pending
                 }
             }
-        }
-
-        if all_done {
-            let mut elems = mem::replace(&mut self.elems, Box::pin([]));
-            let result = iter_pin_mut(elems.as_mut())
-                .map(|e| e.take_done().unwrap())
-                .collect();
-            Poll::Ready(result)
-        } else {
-            Poll::Pending
+            #[cfg_attr(
+                feature = "cfg-target-has-atomic",
+                cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+            )]
+            JoinAllKind::Big { in_progress, outputs } => {
+                while let Some(output) = ready!(in_progress.poll_next_unpin(cx)) {
+                    outputs.push(output);
+                }
+                Poll::Ready(mem::replace(outputs, Vec::new()))
+            }
         }
     }
 }
@@ -0,0 +1,44 @@
+use core::marker::PhantomData;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::task::{Context, Poll};
+use pin_utils::unsafe_pinned;
+
+/// Future for the [`map_into`](super::FutureExt::map_into) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct MapInto<Fut, T> {
+    future: Fut,
+    _marker: PhantomData<T>,
+}
+
+impl<Fut: Unpin, T> Unpin for MapInto<Fut, T> {}
+
+impl<Fut, T> MapInto<Fut, T> {
+    unsafe_pinned!(future: Fut);
+
+    pub(super) fn new(future: Fut) -> MapInto<Fut, T> {
+        MapInto {
+            future,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Fut: FusedFuture, T> FusedFuture for MapInto<Fut, T> {
+    fn is_terminated(&self) -> bool { self.future.is_terminated() }
+}
+
+impl<Fut, T> Future for MapInto<Fut, T>
+    where Fut: Future,
+          Fut::Output: Into<T>,
+{
+    type Output = T;
+
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Self::Output> {
+        self.future().poll(cx).map(Into::into)
+    }
+}
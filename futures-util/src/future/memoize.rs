@@ -0,0 +1,175 @@
+use crate::task::{ArcWake, waker_ref};
+use futures_core::future::Future;
+use futures_core::task::{Context, Poll, Waker};
+use slab::Slab;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, TryLockError};
+
+/// Future for the [`memoize`](super::FutureExt::memoize) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Memoize<Fut: Future> {
+    inner: Arc<Inner<Fut>>,
+    waker_key: usize,
+}
+
+struct Inner<Fut: Future> {
+    state: Mutex<State<Fut>>,
+    waiters: Arc<Waiters>,
+}
+
+enum State<Fut: Future> {
+    Future(Fut),
+    Output(Fut::Output),
+}
+
+struct Waiters {
+    wakers: Mutex<Slab<Waker>>,
+}
+
+// The future itself is polled behind the `Arc`, so it won't be moved
+// when `Memoize` is moved.
+impl<Fut: Future> Unpin for Memoize<Fut> {}
+
+impl<Fut: Future> fmt::Debug for Memoize<Fut> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Memoize")
+            .field("waker_key", &self.waker_key)
+            .finish()
+    }
+}
+
+const NULL_WAKER_KEY: usize = usize::max_value();
+
+impl<Fut: Future> Memoize<Fut> {
+    pub(super) fn new(future: Fut) -> Memoize<Fut> {
+        let inner = Inner {
+            state: Mutex::new(State::Future(future)),
+            waiters: Arc::new(Waiters {
+                wakers: Mutex::new(Slab::new()),
+            }),
+        };
+
+        Memoize {
+            inner: Arc::new(inner),
+            waker_key: NULL_WAKER_KEY,
+        }
+    }
+
+    /// Registers the current task as waiting on the output, returning the
+    /// number of handles currently waiting (including this one).
+    fn register(&mut self, waker: &Waker) -> usize {
+        let mut wakers = self.inner.waiters.wakers.lock().unwrap();
+        if self.waker_key == NULL_WAKER_KEY {
+            self.waker_key = wakers.insert(waker.clone());
+        } else {
+            let slot = &mut wakers[self.waker_key];
+            if !slot.will_wake(waker) {
+                *slot = waker.clone();
+            }
+        }
+        wakers.len()
+    }
+
+    fn deregister(&mut self) {
+        if self.waker_key != NULL_WAKER_KEY {
+            self.inner.waiters.wakers.lock().unwrap().remove(self.waker_key);
+            self.waker_key = NULL_WAKER_KEY;
+        }
+    }
+}
+
+impl<Fut> Future for Memoize<Fut>
+where
+    Fut: Future,
+    Fut::Output: Clone,
+{
+    type Output = Fut::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+
+        // Register before taking the lock: a handle that currently holds it
+        // only looks at the waiters after releasing it, so it is guaranteed
+        // to see this one.
+        let waiting = this.register(cx.waker());
+
+        let mut state = match this.inner.state.try_lock() {
+            Ok(state) => state,
+            Err(TryLockError::WouldBlock) => return Poll::Pending,
+            Err(TryLockError::Poisoned(_)) => panic!("inner future panicked during poll"),
+        };
+
+        let future = match &mut *state {
+            State::Future(future) => unsafe { Pin::new_unchecked(future) },
+            State::Output(output) => {
+                let output = output.clone();
+                drop(state);
+                this.deregister();
+                return Poll::Ready(output);
+            }
+        };
+
+        // Fast path: with a single waiter, the inner future gets that
+        // task's waker directly instead of one that wakes every handle.
+        let poll = if waiting == 1 {
+            future.poll(cx)
+        } else {
+            let waker = waker_ref(&this.inner.waiters);
+            future.poll(&mut Context::from_waker(&waker))
+        };
+
+        match poll {
+            Poll::Pending => {
+                drop(state);
+                // Another handle started waiting while the future was only
+                // holding this task's waker. Wake it so it polls the future
+                // again with a waker that reaches everyone.
+                if waiting == 1 && this.inner.waiters.wakers.lock().unwrap().len() > 1 {
+                    ArcWake::wake_by_ref(&this.inner.waiters);
+                }
+                Poll::Pending
+            }
+            Poll::Ready(output) => {
+                *state = State::Output(output.clone());
+                drop(state);
+                this.deregister();
+                ArcWake::wake_by_ref(&this.inner.waiters);
+                Poll::Ready(output)
+            }
+        }
+    }
+}
+
+impl<Fut: Future> Clone for Memoize<Fut> {
+    fn clone(&self) -> Self {
+        Memoize {
+            inner: self.inner.clone(),
+            waker_key: NULL_WAKER_KEY,
+        }
+    }
+}
+
+impl<Fut: Future> Drop for Memoize<Fut> {
+    fn drop(&mut self) {
+        if self.waker_key != NULL_WAKER_KEY {
+            if let Ok(mut wakers) = self.inner.waiters.wakers.lock() {
+                wakers.remove(self.waker_key);
+                // This handle may have been the one driving the future, so
+                // let one of the remaining waiters take over.
+                for (_key, waker) in wakers.iter() {
+                    waker.wake_by_ref();
+                }
+            }
+        }
+    }
+}
+
+impl ArcWake for Waiters {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        let wakers = arc_self.wakers.lock().unwrap();
+        for (_key, waker) in wakers.iter() {
+            waker.wake_by_ref();
+        }
+    }
+}
@@ -30,7 +30,7 @@ mod option;
 pub use self::option::{OptionFuture};
 
 mod poll_fn;
-pub use self::poll_fn::{poll_fn, PollFn};
+pub use self::poll_fn::{poll_fn, poll_fn_local, LocalPollFn, PollFn};
 
 mod ready;
 pub use self::ready::{ready, ok, err, Ready};
@@ -67,12 +67,21 @@ pub use self::into_stream::IntoStream;
 mod map;
 pub use self::map::Map;
 
+mod map_into;
+pub use self::map_into::MapInto;
+
 mod then;
 pub use self::then::Then;
 
 mod inspect;
 pub use self::inspect::Inspect;
 
+mod unless;
+pub use self::unless::Unless;
+
+mod depend_on;
+pub use self::depend_on::DependOn;
+
 mod unit_error;
 pub use self::unit_error::UnitError;
 
@@ -93,6 +102,8 @@ cfg_target_has_atomic! {
     mod abortable;
     #[cfg(feature = "alloc")]
     pub use self::abortable::{abortable, Abortable, AbortHandle, AbortRegistration, Aborted};
+    #[cfg(feature = "std")]
+    pub use self::abortable::AbortRegistry;
 }
 
 #[cfg(feature = "std")]
@@ -105,10 +116,15 @@ mod remote_handle;
 #[cfg(feature = "std")]
 pub use self::remote_handle::{Remote, RemoteHandle};
 
+#[cfg(feature = "std")]
+mod memoize;
+#[cfg(feature = "std")]
+pub use self::memoize::Memoize;
+
 #[cfg(feature = "std")]
 mod shared;
 #[cfg(feature = "std")]
-pub use self::shared::Shared;
+pub use self::shared::{Shared, WeakShared};
 
 impl<T: ?Sized> FutureExt for T where T: Future {}
 
@@ -145,6 +161,55 @@ pub trait FutureExt: Future {
         assert_future::<U, _>(Map::new(self, f))
     }
 
+    /// Map this future's output to a different type using the
+    /// [`Into`](std::convert::Into) trait.
+    ///
+    /// Unlike [`map`](FutureExt::map), the returned future holds no closure,
+    /// so its type can be written out, for example as a field of a struct or
+    /// as the return type of a function which can't use `impl Future`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future::{self, FutureExt, MapInto, Ready};
+    ///
+    /// fn widen(x: u8) -> MapInto<Ready<u8>, u32> {
+    ///     future::ready(x).map_into()
+    /// }
+    ///
+    /// assert_eq!(widen(7).await, 7u32);
+    /// # });
+    /// ```
+    ///
+    /// The nameable type also makes it possible to convert a future before
+    /// boxing it, where a boxed trait object of a particular output is
+    /// expected:
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future::{self, BoxFuture, FutureExt};
+    ///
+    /// fn load(cached: Option<u16>) -> BoxFuture<'static, u64> {
+    ///     match cached {
+    ///         Some(x) => future::ready(x).map_into().boxed(),
+    ///         None => future::ready(0u32).map_into().boxed(),
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(load(Some(3)).await, 3);
+    /// assert_eq!(load(None).await, 0);
+    /// # });
+    /// ```
+    fn map_into<U>(self) -> MapInto<Self, U>
+        where Self: Sized,
+              Self::Output: Into<U>,
+    {
+        assert_future::<U, _>(MapInto::new(self))
+    }
+
     /// Chain on a computation for when a future finished, passing the result of
     /// the future to the provided closure `f`.
     ///
@@ -304,6 +369,13 @@ pub trait FutureExt: Future {
     /// convenient to work with that stream as if stream was available at the
     /// call site.
     ///
+    /// If the stream is also a [`Sink`](futures_sink::Sink), e.g. a connected
+    /// websocket, the returned adapter implements `Sink` as well. Sending into,
+    /// flushing or closing it first drives this future to completion, so items
+    /// sent before the stream is available wait in
+    /// [`poll_ready`](futures_sink::Sink::poll_ready) instead of being lost,
+    /// and a close always reaches the sink.
+    ///
     /// Note that this function consumes this future and returns a wrapped
     /// version of it.
     ///
@@ -379,6 +451,85 @@ pub trait FutureExt: Future {
         assert_future::<Self::Output, _>(Inspect::new(self, f))
     }
 
+    /// Races this future against a `signal` future, resolving with the output
+    /// of whichever completes first.
+    ///
+    /// The returned future resolves to `Either::Left` with this future's
+    /// output, or to `Either::Right` with the signal's output, in which case
+    /// this future is dropped without completing. This covers the common
+    /// "complete, or give up when told to" case without a `select!` block and
+    /// without allocating.
+    ///
+    /// Each time the returned future is polled, the signal is polled before
+    /// this future, so a signal that is ready wins over a future that is
+    /// ready at the same time. Use [`Unless::prefer_future`] to poll this
+    /// future first instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::oneshot;
+    /// use futures::future::{self, Either, FutureExt};
+    ///
+    /// let (cancel_tx, cancel_rx) = oneshot::channel::<()>();
+    /// let work = future::empty::<u32>();
+    ///
+    /// cancel_tx.send(()).unwrap();
+    /// match work.unless(cancel_rx).await {
+    ///     Either::Left(_) => panic!("work never completes"),
+    ///     Either::Right(res) => assert_eq!(res, Ok(())),
+    /// }
+    ///
+    /// // Both are ready, but the future is preferred.
+    /// let res = future::ready(1).unless(future::ready(())).prefer_future().await;
+    /// match res {
+    ///     Either::Left(x) => assert_eq!(x, 1),
+    ///     Either::Right(()) => panic!("the future is polled first"),
+    /// }
+    /// # });
+    /// ```
+    fn unless<S>(self, signal: S) -> Unless<Self, S>
+        where S: Future,
+              Self: Sized,
+    {
+        assert_future::<Either<Self::Output, S::Output>, _>(Unless::new(self, signal))
+    }
+
+    /// Ties the lifetime of `resource` to this future.
+    ///
+    /// The resource, such as a connection guard, a permit or a tracing span,
+    /// is stored next to the future and dropped as soon as the future
+    /// completes, or together with the future if it is dropped before
+    /// completing. In the latter case the future is dropped first, so it can
+    /// still rely on the resource while being dropped. This keeps a permit
+    /// acquired before spawning a future held for exactly as long as the
+    /// future runs, without moving it into an `async` block or closure just
+    /// to keep it alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future::{self, FutureExt};
+    /// use std::sync::Arc;
+    ///
+    /// let permits = Arc::new(());
+    /// let future = future::ready(1).depend_on(permits.clone());
+    /// assert_eq!(Arc::strong_count(&permits), 2);
+    ///
+    /// assert_eq!(future.await, 1);
+    /// assert_eq!(Arc::strong_count(&permits), 1);
+    /// # });
+    /// ```
+    fn depend_on<T>(self, resource: T) -> DependOn<Self, T>
+        where Self: Sized,
+    {
+        assert_future::<Self::Output, _>(DependOn::new(self, resource))
+    }
+
     /// Catches unwinding panics while polling the future.
     ///
     /// In general, panics within a future can propagate all the way out to the
@@ -474,6 +625,45 @@ pub trait FutureExt: Future {
         Shared::new(self)
     }
 
+    /// Create a cloneable handle to this future which caches its output.
+    ///
+    /// The future is only driven while some handle is being polled. Once it
+    /// completes, its output is stored and every handle, including handles
+    /// cloned afterwards, resolves to a clone of it.
+    ///
+    /// Compared to [`shared`](FutureExt::shared), this combinator is geared
+    /// towards caching values such as configuration or metadata that are
+    /// usually awaited by one task at a time: while there is a single waiting
+    /// handle, the underlying future is polled with that task's own waker
+    /// rather than one that has to wake every handle. The output only needs
+    /// to be `Send` (not `Sync`) for the handles to be sent across threads.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future::{self, FutureExt};
+    ///
+    /// let config = future::lazy(|_| String::from("verbose=1")).memoize();
+    /// let handle = config.clone();
+    ///
+    /// assert_eq!(config.await, "verbose=1");
+    /// assert_eq!(handle.clone().await, "verbose=1");
+    /// # });
+    /// ```
+    #[cfg(feature = "std")]
+    fn memoize(self) -> Memoize<Self>
+    where
+        Self: Sized,
+        Self::Output: Clone,
+    {
+        Memoize::new(self)
+    }
+
     /// Turn this future into a future that yields `()` on completion and sends
     /// its output to another future on a separate task.
     ///
@@ -531,6 +721,61 @@ pub trait FutureExt: Future {
     {
         Pin::new(self).poll(cx)
     }
+
+    /// Evaluates and consumes the future, returning the resulting output if
+    /// the future is ready after the first call to `Future::poll`.
+    ///
+    /// If `poll` instead returns `Poll::Pending`, `None` is returned and the
+    /// future is dropped. The future is pinned on the stack for that single
+    /// poll, so it doesn't need to be `Unpin`.
+    ///
+    /// The future is polled with a [no-op waker](crate::task::noop_waker), so
+    /// a future which returns `Poll::Pending` has registered a waker which
+    /// will never wake anything. This method is meant for futures which are
+    /// expected to be ready already, such as a oneshot which should have been
+    /// fulfilled, and not as a way to wait for a future to complete. A panic
+    /// in the future's `poll` is propagated to the caller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::{future::ready, future::FutureExt};
+    ///
+    /// let x = ready("foo");
+    /// assert_eq!(x.now_or_never(), Some("foo"));
+    /// ```
+    ///
+    /// In cases where it is absolutely known that a future should always
+    /// resolve immediately, this can be combined with `expect`:
+    ///
+    /// ```
+    /// use futures::{future::ready, future::FutureExt};
+    ///
+    /// let x = ready("foo");
+    /// assert_eq!(x.now_or_never().expect("Future not ready"), "foo");
+    /// ```
+    ///
+    /// A future which isn't ready yet is dropped:
+    ///
+    /// ```
+    /// use futures::{future::empty, future::FutureExt};
+    ///
+    /// let x = empty::<()>();
+    /// assert_eq!(x.now_or_never(), None);
+    /// ```
+    fn now_or_never(self) -> Option<Self::Output>
+        where Self: Sized
+    {
+        let noop_waker = crate::task::noop_waker();
+        let mut cx = Context::from_waker(&noop_waker);
+
+        let this = self;
+        pin_utils::pin_mut!(this);
+        match this.poll(&mut cx) {
+            Poll::Ready(x) => Some(x),
+            _ => None,
+        }
+    }
 }
 
 // Just a helper function to ensure the futures we're returning all have the
@@ -1,5 +1,6 @@
 //! Definition of the `PollFn` adapter combinator
 
+use core::marker::PhantomData;
 use core::pin::Pin;
 use futures_core::future::Future;
 use futures_core::task::{Context, Poll};
@@ -13,10 +14,24 @@ pub struct PollFn<F> {
 
 impl<F> Unpin for PollFn<F> {}
 
+/// Future for the [`poll_fn_local`] function.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct LocalPollFn<F> {
+    f: F,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl<F> Unpin for LocalPollFn<F> {}
+
 /// Creates a new future wrapping around a function returning [`Poll`].
 ///
 /// Polling the returned future delegates to the wrapped function.
 ///
+/// The returned future is `Send` exactly when the function is `Send`. If the
+/// function captures values which aren't `Send`, use [`poll_fn_local`]
+/// instead to make that explicit in the returned type.
+///
 /// # Examples
 ///
 /// ```
@@ -49,3 +64,54 @@ impl<T, F> Future for PollFn<F>
         (&mut self.f)(cx)
     }
 }
+
+/// Creates a new future wrapping around a function returning [`Poll`], which
+/// is never `Send`.
+///
+/// This is the same as [`poll_fn`], except that the returned future is
+/// neither `Send` nor `Sync`, whether or not the function is. This makes it
+/// suitable for functions capturing thread-local state, like `Rc` or
+/// `RefCell` handles, and keeps the future's auto traits from changing when
+/// the captures of the function change.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await)]
+/// # futures::executor::block_on(async {
+/// use futures::future::poll_fn_local;
+/// use futures::task::{Context, Poll};
+/// use std::rc::Rc;
+///
+/// let counter = Rc::new(5);
+/// let future = poll_fn_local(move |_cx: &mut Context<'_>| Poll::Ready(*counter));
+/// assert_eq!(future.await, 5);
+/// # });
+/// ```
+///
+/// The returned future can't be sent to another thread:
+///
+/// ```compile_fail
+/// use futures::future::poll_fn_local;
+/// use futures::task::{Context, Poll};
+///
+/// fn assert_send<T: Send>(_: T) {}
+///
+/// assert_send(poll_fn_local(|_cx: &mut Context<'_>| Poll::Ready(())));
+/// ```
+pub fn poll_fn_local<T, F>(f: F) -> LocalPollFn<F>
+where
+    F: FnMut(&mut Context<'_>) -> Poll<T>
+{
+    LocalPollFn { f, _not_send: PhantomData }
+}
+
+impl<T, F> Future for LocalPollFn<F>
+    where F: FnMut(&mut Context<'_>) -> Poll<T>,
+{
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        (self.f)(cx)
+    }
+}
@@ -7,7 +7,7 @@ use std::fmt;
 use std::pin::Pin;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering::SeqCst;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, Weak};
 
 /// Future for the [`shared`](super::FutureExt::shared) method.
 #[must_use = "futures do nothing unless you `.await` or poll them"]
@@ -16,6 +16,13 @@ pub struct Shared<Fut: Future> {
     waker_key: usize,
 }
 
+/// A weak reference to a [`Shared`] that can be upgraded much like an `Arc`.
+///
+/// It doesn't keep the inner future or its output alive: both are dropped
+/// once every [`Shared`] handle is gone, after which
+/// [`upgrade`](WeakShared::upgrade) returns `None`.
+pub struct WeakShared<Fut: Future>(Weak<Inner<Fut>>);
+
 struct Inner<Fut: Future> {
     future_or_output: UnsafeCell<FutureOrOutput<Fut>>,
     notifier: Arc<Notifier>,
@@ -39,6 +46,14 @@ impl<Fut: Future> fmt::Debug for Shared<Fut> {
     }
 }
 
+impl<Fut: Future> fmt::Debug for WeakShared<Fut> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple("WeakShared")
+            .field(&self.0)
+            .finish()
+    }
+}
+
 impl<Fut: Future> fmt::Debug for Inner<Fut> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt.debug_struct("Inner").finish()
@@ -107,6 +122,25 @@ where
         None
     }
 
+    /// Gets the number of strong pointers to this allocation, that is the
+    /// number of [`Shared`] handles which haven't returned the output yet.
+    ///
+    /// Returns [`None`] if this [`Shared`] already returned its output from
+    /// [`poll`](Future::poll). As with [`Arc::strong_count`], other clones
+    /// may be created or dropped concurrently, so the count may be out of
+    /// date by the time it is used.
+    pub fn strong_count(&self) -> Option<usize> {
+        self.inner.as_ref().map(Arc::strong_count)
+    }
+
+    /// Creates a new [`WeakShared`] for this [`Shared`].
+    ///
+    /// Returns [`None`] if this [`Shared`] already returned its output from
+    /// [`poll`](Future::poll).
+    pub fn downgrade(&self) -> Option<WeakShared<Fut>> {
+        self.inner.as_ref().map(|inner| WeakShared(Arc::downgrade(inner)))
+    }
+
     /// Registers the current task to receive a wakeup when `Inner` is awoken.
     fn set_waker(&mut self, cx: &mut Context<'_>) {
         // Acquire the lock first before checking COMPLETE to ensure there
@@ -123,16 +157,17 @@ where
             return;
         };
 
+        // Each clone has its own slot, which is freed when the clone is
+        // dropped, so the slab only grows with the number of live clones.
         if self.waker_key == NULL_WAKER_KEY {
             self.waker_key = wakers.insert(Some(cx.waker().clone()));
         } else {
             let waker_slot = &mut wakers[self.waker_key];
-            let needs_replacement = if let Some(_old_waker) = waker_slot {
+            let needs_replacement = if let Some(old_waker) = waker_slot {
                 // If there's still an unwoken waker in the slot, only replace
-                // if the current one wouldn't wake the same task.
-                // TODO: This API is currently not available, so replace always
-                // !waker.will_wake_nonlocal(old_waker)
-                true
+                // it if the current one wouldn't wake the same task, so that
+                // repeated polls from the same task don't clone its waker.
+                !old_waker.will_wake(cx.waker())
             } else {
                 true
             };
@@ -297,6 +332,26 @@ where
     }
 }
 
+impl<Fut: Future> WeakShared<Fut> {
+    /// Attempts to upgrade this [`WeakShared`] into a [`Shared`].
+    ///
+    /// Returns [`None`] if all clones of the [`Shared`] have been dropped or
+    /// have already returned the output. The new [`Shared`] behaves like any
+    /// other clone, and can be polled for the output.
+    pub fn upgrade(&self) -> Option<Shared<Fut>> {
+        Some(Shared {
+            inner: Some(self.0.upgrade()?),
+            waker_key: NULL_WAKER_KEY,
+        })
+    }
+}
+
+impl<Fut: Future> Clone for WeakShared<Fut> {
+    fn clone(&self) -> Self {
+        WeakShared(self.0.clone())
+    }
+}
+
 impl<Fut> Drop for Shared<Fut>
 where
     Fut: Future,
@@ -0,0 +1,72 @@
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::task::{Context, Poll};
+use pin_utils::unsafe_pinned;
+use crate::future::Either;
+
+/// Future for the [`unless`](super::FutureExt::unless) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Unless<Fut, S> {
+    future: Fut,
+    signal: S,
+    future_first: bool,
+}
+
+impl<Fut: Unpin, S: Unpin> Unpin for Unless<Fut, S> {}
+
+impl<Fut: Future, S: Future> Unless<Fut, S> {
+    unsafe_pinned!(future: Fut);
+    unsafe_pinned!(signal: S);
+
+    pub(super) fn new(future: Fut, signal: S) -> Unless<Fut, S> {
+        Unless { future, signal, future_first: false }
+    }
+
+    /// Polls the future before the signal, so that the future's output wins
+    /// if both are ready at the same time.
+    ///
+    /// By default the signal is polled first, so that a signal which is
+    /// already ready always cancels the future.
+    pub fn prefer_future(mut self) -> Self {
+        self.future_first = true;
+        self
+    }
+
+    /// Acquires a reference to the underlying future.
+    pub fn get_ref(&self) -> &Fut {
+        &self.future
+    }
+
+    /// Acquires a mutable reference to the underlying future.
+    pub fn get_mut(&mut self) -> &mut Fut {
+        &mut self.future
+    }
+
+    /// Consumes this combinator, returning the underlying future and the
+    /// signal.
+    pub fn into_inner(self) -> (Fut, S) {
+        (self.future, self.signal)
+    }
+}
+
+impl<Fut: Future, S: Future> Future for Unless<Fut, S> {
+    type Output = Either<Fut::Output, S::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if !self.future_first {
+            if let Poll::Ready(output) = self.as_mut().signal().poll(cx) {
+                return Poll::Ready(Either::Right(output));
+            }
+        }
+        if let Poll::Ready(output) = self.as_mut().future().poll(cx) {
+            return Poll::Ready(Either::Left(output));
+        }
+        if self.future_first {
+            if let Poll::Ready(output) = self.as_mut().signal().poll(cx) {
+                return Poll::Ready(Either::Right(output));
+            }
+        }
+        Poll::Pending
+    }
+}
@@ -0,0 +1,220 @@
+use futures_core::future::Future;
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncBufRead;
+use std::io;
+use std::mem;
+use std::pin::Pin;
+use std::str;
+
+// Where the current line is kept, so that it can be returned, and discarded
+// when the next one is asked for.
+#[derive(Debug, Clone, Copy)]
+enum Line {
+    None,
+    // The first bytes of the reader's buffer, not returned yet because the
+    // reader wasn't ready when the buffer was asked for again
+    Found(usize),
+    // The first bytes of the reader's buffer, which are yet to be consumed
+    Lent(usize),
+    // The spill buffer
+    Spill,
+}
+
+/// Reader for the [`lines_ref`](super::AsyncBufReadExt::lines_ref) method.
+///
+/// Lines are returned as borrows, which stay valid until the next line is
+/// asked for. A line which is entirely in the buffer of the underlying
+/// reader is borrowed from that buffer, without being copied. Only a line
+/// which doesn't fit in the buffer as filled by a single read, because it
+/// starts near the end of the buffer or is longer than it, is copied into a
+/// buffer of this reader, which is reused for all such lines.
+#[derive(Debug)]
+pub struct LinesRef<R> {
+    reader: R,
+    spill: Vec<u8>,
+    line: Line,
+}
+
+impl<R: Unpin> Unpin for LinesRef<R> {}
+
+impl<R: AsyncBufRead> LinesRef<R> {
+    pub(super) fn new(reader: R) -> Self {
+        Self {
+            reader,
+            spill: Vec::new(),
+            line: Line::None,
+        }
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Note that the line returned last is still in the buffer of the
+    /// underlying reader if it was borrowed from it, so care should be taken
+    /// not to consume it through this reference.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Gets a pinned mutable reference to the underlying reader.
+    ///
+    /// Note that the line returned last is still in the buffer of the
+    /// underlying reader if it was borrowed from it, so care should be taken
+    /// not to consume it through this reference.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut R> {
+        unsafe { self.map_unchecked_mut(|s| &mut s.reader) }
+    }
+
+    /// Consumes this reader, returning the underlying reader.
+    ///
+    /// Note that the line returned last is still in the buffer of the
+    /// underlying reader if it was borrowed from it, and that a line which
+    /// has only partially been read so far is lost.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Attempts to read the next line, returning a borrow of it.
+    ///
+    /// This is the poll-based counterpart of [`take_line`](LinesRef::take_line).
+    /// The line doesn't include the newline byte (the 0xA byte) or CRLF (0xD,
+    /// 0xA bytes) at its end, and `None` is returned once the end of the
+    /// reader has been reached. A line which is not valid UTF-8 results in an
+    /// error of kind [`InvalidData`](io::ErrorKind::InvalidData), and is
+    /// skipped by the next call.
+    pub fn poll_next_line_borrowed<'a>(
+        mut self: Pin<&'a mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<Option<&'a str>>> {
+        if ready!(self.as_mut().poll_prepare(cx))? {
+            self.poll_prepared_line(cx).map_ok(Some)
+        } else {
+            Poll::Ready(Ok(None))
+        }
+    }
+
+    /// Reads the next line, returning a borrow of it.
+    ///
+    /// The returned future resolves to the next line, without its newline
+    /// byte or CRLF, or to `None` once the end of the reader has been
+    /// reached. See [`poll_next_line_borrowed`](LinesRef::poll_next_line_borrowed)
+    /// for details.
+    pub fn take_line(&mut self) -> TakeLine<'_, R>
+        where R: Unpin,
+    {
+        TakeLine { lines: Some(self) }
+    }
+
+    // Finds the next line, returning `false` at the end of the reader. The
+    // line is then returned by `poll_prepared_line`.
+    fn poll_prepare(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<bool>> {
+        let Self { reader, spill, line } = unsafe { self.get_unchecked_mut() };
+        let mut reader = unsafe { Pin::new_unchecked(reader) };
+
+        match mem::replace(line, Line::None) {
+            Line::None => {}
+            Line::Found(len) => {
+                *line = Line::Found(len);
+                return Poll::Ready(Ok(true));
+            }
+            Line::Lent(len) => reader.as_mut().consume(len),
+            Line::Spill => spill.clear(),
+        }
+
+        loop {
+            let available = ready!(reader.as_mut().poll_fill_buf(cx))?;
+            if available.is_empty() {
+                // The last line may not end with a newline.
+                if spill.is_empty() {
+                    return Poll::Ready(Ok(false));
+                }
+                *line = Line::Spill;
+                return Poll::Ready(Ok(true));
+            }
+            match memchr::memchr(b'\n', available) {
+                // The whole line is buffered, so it can be borrowed from
+                // the reader.
+                Some(i) if spill.is_empty() => {
+                    *line = Line::Found(i + 1);
+                    return Poll::Ready(Ok(true));
+                }
+                Some(i) => {
+                    spill.extend_from_slice(&available[..=i]);
+                    reader.as_mut().consume(i + 1);
+                    *line = Line::Spill;
+                    return Poll::Ready(Ok(true));
+                }
+                None => {
+                    let len = available.len();
+                    spill.extend_from_slice(available);
+                    reader.as_mut().consume(len);
+                }
+            }
+        }
+    }
+
+    fn poll_prepared_line<'a>(
+        self: Pin<&'a mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<&'a str>> {
+        let Self { reader, spill, line } = unsafe { self.get_unchecked_mut() };
+        let reader = unsafe { Pin::new_unchecked(reader) };
+
+        let mut bytes = match *line {
+            Line::Spill => &spill[..],
+            // Nothing has been consumed since the line was found, so this
+            // returns the same buffer again.
+            Line::Found(len) => {
+                let available = ready!(reader.poll_fill_buf(cx))?;
+                if available.len() < len {
+                    *line = Line::None;
+                    return Poll::Ready(Err(io::Error::new(
+                        io::ErrorKind::Other,
+                        "reader changed its buffer without it being consumed",
+                    )));
+                }
+                *line = Line::Lent(len);
+                &available[..len]
+            }
+            Line::None | Line::Lent(_) => unreachable!(),
+        };
+        if bytes.ends_with(b"\n") {
+            bytes = &bytes[..bytes.len() - 1];
+            if bytes.ends_with(b"\r") {
+                bytes = &bytes[..bytes.len() - 1];
+            }
+        }
+        Poll::Ready(str::from_utf8(bytes).map_err(|_| {
+            io::Error::new(io::ErrorKind::InvalidData, "stream did not contain valid UTF-8")
+        }))
+    }
+}
+
+/// Future for the [`take_line`](LinesRef::take_line) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TakeLine<'a, R> {
+    lines: Option<&'a mut LinesRef<R>>,
+}
+
+impl<R> Unpin for TakeLine<'_, R> {}
+
+impl<'a, R: AsyncBufRead + Unpin> Future for TakeLine<'a, R> {
+    type Output = io::Result<Option<&'a str>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let lines: *mut LinesRef<R> = &mut **self.lines.as_mut()
+            .expect("TakeLine polled after completion");
+        // The line borrows the reader for as long as this future did, which
+        // the borrow checker can't tell is fine when the line is only
+        // returned on some paths. This future gives up its borrow as soon as
+        // a line is returned, so the two borrows are never used together.
+        let res = ready!(Pin::new(unsafe { &mut *lines }).poll_next_line_borrowed(cx));
+        self.lines = None;
+        Poll::Ready(res)
+    }
+}
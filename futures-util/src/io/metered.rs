@@ -0,0 +1,154 @@
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncRead, AsyncWrite, Initializer, IoSlice, IoSliceMut};
+use pin_utils::unsafe_pinned;
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Reader for the [`metered`](super::AsyncReadExt::metered) method.
+///
+/// Counts the bytes read through it and the number of reads that returned
+/// data. The counts can be observed from anywhere, including other tasks,
+/// through a [`ReadMeter`](ReadMeter) obtained from [`meter`](Metered::meter).
+///
+/// Writes, if the underlying object supports them, are passed through
+/// without being counted.
+#[derive(Debug)]
+pub struct Metered<R> {
+    inner: R,
+    meter: ReadMeter,
+}
+
+impl<R: Unpin> Unpin for Metered<R> {}
+
+impl<R: AsyncRead> Metered<R> {
+    unsafe_pinned!(inner: R);
+
+    pub(super) fn new(inner: R) -> Self {
+        Self { inner, meter: ReadMeter::new() }
+    }
+
+    /// Returns a handle to the counters of this reader.
+    pub fn meter(&self) -> ReadMeter {
+        self.meter.clone()
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Bytes read directly from the underlying reader aren't counted.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Gets a pinned mutable reference to the underlying reader.
+    ///
+    /// Bytes read directly from the underlying reader aren't counted.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut R> {
+        self.inner()
+    }
+
+    /// Consumes the `Metered`, returning the wrapped reader.
+    ///
+    /// Existing [`ReadMeter`](ReadMeter)s keep the final counts.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn record(&self, res: Poll<io::Result<usize>>) -> Poll<io::Result<usize>> {
+        if let Poll::Ready(Ok(n)) = res {
+            if n > 0 {
+                self.meter.inner.bytes.fetch_add(n, Ordering::Relaxed);
+                self.meter.inner.reads.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        res
+    }
+}
+
+impl<R: AsyncRead> AsyncRead for Metered<R> {
+    unsafe fn initializer(&self) -> Initializer {
+        self.inner.initializer()
+    }
+
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let res = self.as_mut().inner().poll_read(cx, buf);
+        self.record(res)
+    }
+
+    fn poll_read_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &mut [IoSliceMut<'_>],
+    ) -> Poll<io::Result<usize>> {
+        let res = self.as_mut().inner().poll_read_vectored(cx, bufs);
+        self.record(res)
+    }
+}
+
+impl<R: AsyncRead + AsyncWrite> AsyncWrite for Metered<R> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.inner().poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        self.inner().poll_write_vectored(cx, bufs)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.inner().poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.inner().poll_close(cx)
+    }
+}
+
+/// A handle to the counters of a [`Metered`](Metered) reader.
+///
+/// Handles are cheap to clone and can be sent to other tasks or threads.
+/// Reading the counters never blocks the reader. The counters wrap around
+/// if they overflow a `usize`.
+#[derive(Debug, Clone)]
+pub struct ReadMeter {
+    inner: Arc<Counters>,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    bytes: AtomicUsize,
+    reads: AtomicUsize,
+}
+
+impl ReadMeter {
+    fn new() -> Self {
+        Self { inner: Arc::new(Counters::default()) }
+    }
+
+    /// Returns the total number of bytes read so far.
+    pub fn bytes_read(&self) -> usize {
+        self.inner.bytes.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of reads so far that returned at least one byte.
+    pub fn reads(&self) -> usize {
+        self.inner.reads.load(Ordering::Relaxed)
+    }
+}
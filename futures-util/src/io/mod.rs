@@ -14,6 +14,9 @@ pub use futures_io::{
 };
 
 #[cfg(feature = "io-compat")] use crate::compat::Compat;
+use futures_core::future::Future;
+use futures_core::stream::TryStream;
+use std::time::Duration;
 
 // used by `BufReader` and `BufWriter`
 // https://github.com/rust-lang/rust/blob/master/src/libstd/sys_common/io.rs#L1
@@ -37,6 +40,15 @@ pub use self::flush::Flush;
 mod lines;
 pub use self::lines::Lines;
 
+mod lines_ref;
+pub use self::lines_ref::{LinesRef, TakeLine};
+
+mod metered;
+pub use self::metered::{Metered, ReadMeter};
+
+mod read_timeout;
+pub use self::read_timeout::ReadTimeout;
+
 mod read;
 pub use self::read::Read;
 
@@ -62,7 +74,10 @@ mod seek;
 pub use self::seek::Seek;
 
 mod split;
-pub use self::split::{ReadHalf, WriteHalf};
+pub use self::split::{ReadHalf, SharedReadHalf, SharedWriteHalf, WriteHalf};
+
+mod take_write;
+pub use self::take_write::TakeWrite;
 
 mod window;
 pub use self::window::Window;
@@ -76,6 +91,9 @@ pub use self::write_vectored::WriteVectored;
 mod write_all;
 pub use self::write_all::WriteAll;
 
+mod write_all_from_stream;
+pub use self::write_all_from_stream::WriteAllFromStream;
+
 /// An extension trait which adds utility methods to `AsyncRead` types.
 pub trait AsyncReadExt: AsyncRead {
     /// Creates a future which copies all the bytes from one object to another.
@@ -270,6 +288,114 @@ pub trait AsyncReadExt: AsyncRead {
         split::split(self)
     }
 
+    /// Splits this object into separate `AsyncRead` and `AsyncWrite` handles
+    /// without a lock, for types that can be read from and written to through
+    /// a shared reference.
+    ///
+    /// Types like sockets often implement `AsyncRead` and `AsyncWrite` for
+    /// `&T` as well as for `T`. For those types, the halves returned by
+    /// [`split`](AsyncReadExt::split) needlessly serialize reads and writes
+    /// through a lock, which shows up as contention when a read task and a
+    /// write task take turns on the same connection. The halves returned by
+    /// this method instead share the object through an `Arc` and read or
+    /// write through `&T` directly, so they never wait for each other.
+    ///
+    /// The object is dropped once both halves have been dropped.
+    fn split_shared(self) -> (SharedReadHalf<Self>, SharedWriteHalf<Self>)
+        where for<'a> &'a Self: AsyncRead + AsyncWrite,
+              Self: Sized,
+    {
+        split::split_shared(self)
+    }
+
+    /// Counts the bytes read from this reader and the number of reads that
+    /// returned data.
+    ///
+    /// The counts can be read through a [`ReadMeter`](ReadMeter) handle, which can be
+    /// moved to another task, for example to account for the bandwidth used
+    /// by each connection of a server.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::io::AsyncReadExt;
+    /// use std::io::Cursor;
+    ///
+    /// let mut reader = Cursor::new([1, 2, 3, 4, 5]).metered();
+    /// let meter = reader.meter();
+    ///
+    /// let mut buf = [0; 3];
+    /// reader.read_exact(&mut buf).await?;
+    /// assert_eq!(meter.bytes_read(), 3);
+    ///
+    /// let mut rest = Vec::new();
+    /// reader.read_to_end(&mut rest).await?;
+    /// assert_eq!(meter.bytes_read(), 5);
+    /// assert_eq!(meter.reads(), 2);
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn metered(self) -> Metered<Self>
+        where Self: Sized,
+    {
+        Metered::new(self)
+    }
+
+    /// Fails reads from this reader that take too long, guarding against
+    /// peers that trickle data in slowly.
+    ///
+    /// Two limits can be set, either of which may be `None`:
+    ///
+    /// - `idle` bounds the time the reader may wait for data before a read
+    ///   completes. It is reset whenever a read completes. Exceeding it fails
+    ///   the read with an [`io::ErrorKind::TimedOut`](std::io::ErrorKind::TimedOut) error.
+    /// - `total` bounds the time from the first read that had to wait until
+    ///   the end of the read session, no matter how much data arrives in the
+    ///   meantime. Exceeding it fails the read, and every read after it,
+    ///   with an [`io::ErrorKind::Other`](std::io::ErrorKind::Other) error.
+    ///
+    /// Timers are created by calling `delay` with the timeout, which returns
+    /// a future completing once that much time has passed, so that any
+    /// runtime's timer can be used. Timers are only created once a read has
+    /// to wait for data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future::{self, Ready};
+    /// use futures::io::AsyncReadExt;
+    /// use std::io::Cursor;
+    /// use std::time::Duration;
+    ///
+    /// // With a real runtime, this would be e.g. a call to its `delay_for`.
+    /// fn delay(_timeout: Duration) -> Ready<()> {
+    ///     future::ready(())
+    /// }
+    ///
+    /// let mut reader = Cursor::new([1, 2, 3])
+    ///     .read_timeout(Duration::from_secs(10), Duration::from_secs(60), delay);
+    ///
+    /// let mut buf = Vec::new();
+    /// reader.read_to_end(&mut buf).await?;
+    /// assert_eq!(buf, [1, 2, 3]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn read_timeout<F, D>(
+        self,
+        idle: impl Into<Option<Duration>>,
+        total: impl Into<Option<Duration>>,
+        delay: F,
+    ) -> ReadTimeout<Self, F, D>
+        where Self: Sized,
+              F: FnMut(Duration) -> D,
+              D: Future<Output = ()>,
+    {
+        ReadTimeout::new(self, idle.into(), total.into(), delay)
+    }
+
     /// Wraps an [`AsyncRead`] in a compatibility wrapper that allows it to be
     /// used as a futures 0.1 / tokio-io 0.1 `AsyncRead`. If the wrapped type
     /// implements [`AsyncWrite`] as well, the result will also implement the
@@ -373,6 +499,81 @@ pub trait AsyncWriteExt: AsyncWrite {
         WriteAll::new(self, buf)
     }
 
+    /// Write every chunk yielded by a stream into this object.
+    ///
+    /// Creates a future that drains `stream`, a stream of `io::Result`s of
+    /// byte chunks, into this `AsyncWrite` and flushes it once at the end.
+    /// Chunks which are ready at the same time are submitted together through
+    /// [`poll_write_vectored`](futures_io::AsyncWrite::poll_write_vectored),
+    /// so a stream of many small chunks doesn't result in a write call per
+    /// chunk. This is the writer-side counterpart of
+    /// [`into_async_read`](crate::stream::TryStreamExt::into_async_read).
+    ///
+    /// The returned future completes once the stream has ended, all of its
+    /// data has been written and the writer has been flushed. The first error
+    /// returned by either the stream or the writer is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::io::AsyncWriteExt;
+    /// use futures::stream;
+    ///
+    /// let chunks = stream::iter(vec![Ok(&b"hello "[..]), Ok(&b"world"[..])]);
+    /// let mut writer = Vec::new();
+    ///
+    /// writer.write_all_from_stream(chunks).await?;
+    ///
+    /// assert_eq!(writer, b"hello world");
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn write_all_from_stream<St>(&mut self, stream: St) -> WriteAllFromStream<'_, Self, St>
+        where Self: Unpin,
+              St: TryStream<Error = std::io::Error>,
+              St::Ok: AsRef<[u8]>,
+    {
+        WriteAllFromStream::new(self, stream)
+    }
+
+    /// Creates an `AsyncWrite` adapter which will accept at most `limit` bytes
+    /// from its callers.
+    ///
+    /// Writes are truncated so that no more than `limit` bytes reach the
+    /// underlying writer in total. Once the limit has been reached, every
+    /// further write of a non-empty buffer is rejected by returning `Ok(0)`,
+    /// just like writing into a full in-memory buffer, which causes
+    /// [`write_all`](AsyncWriteExt::write_all) to fail with
+    /// [`ErrorKind::WriteZero`](std::io::ErrorKind::WriteZero). This makes it
+    /// possible to enforce size caps for producers writing into e.g. multipart
+    /// bodies or quota-limited uploads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::io::AsyncWriteExt;
+    /// use std::io::ErrorKind;
+    ///
+    /// let mut writer = Vec::new().take_write(4);
+    ///
+    /// writer.write_all(&[1, 2, 3]).await?;
+    /// assert_eq!(writer.limit(), 1);
+    ///
+    /// let err = writer.write_all(&[4, 5]).await.unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::WriteZero);
+    ///
+    /// assert_eq!(writer.into_inner(), [1, 2, 3, 4]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn take_write(self, limit: u64) -> TakeWrite<Self>
+        where Self: Sized,
+    {
+        TakeWrite::new(self, limit)
+    }
+
     /// Wraps an [`AsyncWrite`] in a compatibility wrapper that allows it to be
     /// used as a futures 0.1 / tokio-io 0.1 `AsyncWrite`.
     /// Requires the `io-compat` feature to enable.
@@ -555,6 +756,48 @@ pub trait AsyncBufReadExt: AsyncBufRead {
     {
         Lines::new(self)
     }
+
+    /// Returns a reader over the lines of this reader, which lends out each
+    /// line instead of allocating a `String` for it.
+    ///
+    /// Lines are read with [`LinesRef::take_line`], or
+    /// [`LinesRef::poll_next_line_borrowed`] in manual `poll` implementations.
+    /// Each line is a `&str` borrowing from the returned reader, which stays
+    /// valid until the next line is read. Like with
+    /// [`lines`](AsyncBufReadExt::lines), it doesn't include the newline byte
+    /// (the 0xA byte) or CRLF (0xD, 0xA bytes) at its end.
+    ///
+    /// A line which is entirely in the buffer of this reader is borrowed from
+    /// that buffer. Only lines spanning more than one fill of the buffer are
+    /// copied, into a buffer which is reused for every such line.
+    ///
+    /// # Errors
+    ///
+    /// Each line has the same error semantics as [`AsyncBufReadExt::read_line`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::io::AsyncBufReadExt;
+    /// use std::io::Cursor;
+    ///
+    /// let cursor = Cursor::new(b"lorem\nipsum\r\ndolor");
+    ///
+    /// let mut lines = cursor.lines_ref();
+    /// let mut lengths = Vec::new();
+    /// while let Some(line) = lines.take_line().await? {
+    ///     lengths.push(line.len());
+    /// }
+    /// assert_eq!(lengths, vec![5, 5, 5]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(()) }).unwrap();
+    /// ```
+    fn lines_ref(self) -> LinesRef<Self>
+        where Self: Sized,
+    {
+        LinesRef::new(self)
+    }
 }
 
 impl<R: AsyncBufRead + ?Sized> AsyncBufReadExt for R {}
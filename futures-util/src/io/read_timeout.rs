@@ -0,0 +1,180 @@
+use futures_core::future::Future;
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncRead, Initializer, IoSliceMut};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::fmt;
+use std::io;
+use std::pin::Pin;
+use std::time::Duration;
+
+/// Reader for the [`read_timeout`](super::AsyncReadExt::read_timeout) method.
+///
+/// Fails reads with an [`io::ErrorKind::TimedOut`] error once the underlying
+/// reader has been waiting for data for longer than the idle timeout, and
+/// with an [`io::ErrorKind::Other`] error once the whole read session has
+/// taken longer than the total timeout.
+pub struct ReadTimeout<R, F, D> {
+    inner: R,
+    delay: F,
+    idle_timeout: Option<Duration>,
+    total_timeout: Option<Duration>,
+    idle: Option<D>,
+    total: Option<D>,
+    // Set once the total timeout has passed, after which every read fails
+    total_expired: bool,
+}
+
+impl<R: Unpin, F, D: Unpin> Unpin for ReadTimeout<R, F, D> {}
+
+impl<R: fmt::Debug, F, D> fmt::Debug for ReadTimeout<R, F, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadTimeout")
+            .field("inner", &self.inner)
+            .field("idle_timeout", &self.idle_timeout)
+            .field("total_timeout", &self.total_timeout)
+            .finish()
+    }
+}
+
+impl<R, F, D> ReadTimeout<R, F, D>
+where
+    R: AsyncRead,
+    F: FnMut(Duration) -> D,
+    D: Future<Output = ()>,
+{
+    unsafe_pinned!(inner: R);
+    unsafe_unpinned!(delay: F);
+    unsafe_pinned!(idle: Option<D>);
+    unsafe_pinned!(total: Option<D>);
+    unsafe_unpinned!(total_expired: bool);
+
+    pub(super) fn new(
+        inner: R,
+        idle_timeout: Option<Duration>,
+        total_timeout: Option<Duration>,
+        delay: F,
+    ) -> Self {
+        Self {
+            inner,
+            delay,
+            idle_timeout,
+            total_timeout,
+            idle: None,
+            total: None,
+            total_expired: false,
+        }
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Reads made directly through the underlying reader don't reset the idle
+    /// timeout.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Gets a pinned mutable reference to the underlying reader.
+    ///
+    /// Reads made directly through the underlying reader don't reset the idle
+    /// timeout.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut R> {
+        self.inner()
+    }
+
+    /// Consumes the `ReadTimeout`, returning the wrapped reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    // Fails the read right away once the total timeout has passed, without
+    // reading from the underlying reader.
+    fn check_total(&self) -> io::Result<()> {
+        if self.total_expired {
+            Err(total_timeout_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn poll_timeouts(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        res: Poll<io::Result<usize>>,
+    ) -> Poll<io::Result<usize>> {
+        if let Poll::Ready(res) = res {
+            // Any completed read counts as activity.
+            self.as_mut().idle().set(None);
+            return Poll::Ready(res);
+        }
+
+        // The timers are only started once the reader has to wait, so that
+        // reads which complete right away don't cost a timer each.
+        if let Some(timeout) = self.total_timeout {
+            if self.total.is_none() {
+                let delay = (self.as_mut().delay())(timeout);
+                self.as_mut().total().set(Some(delay));
+            }
+            if self.as_mut().total().as_pin_mut().unwrap().poll(cx).is_ready() {
+                self.as_mut().total().set(None);
+                self.as_mut().idle().set(None);
+                *self.as_mut().total_expired() = true;
+                return Poll::Ready(Err(total_timeout_error()));
+            }
+        }
+        if let Some(timeout) = self.idle_timeout {
+            if self.idle.is_none() {
+                let delay = (self.as_mut().delay())(timeout);
+                self.as_mut().idle().set(Some(delay));
+            }
+            if self.as_mut().idle().as_pin_mut().unwrap().poll(cx).is_ready() {
+                // A retried read waits for a whole idle timeout again.
+                self.as_mut().idle().set(None);
+                return Poll::Ready(Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "reader was idle for longer than its idle timeout",
+                )));
+            }
+        }
+        Poll::Pending
+    }
+}
+
+fn total_timeout_error() -> io::Error {
+    io::Error::new(io::ErrorKind::Other, "read session exceeded its total timeout")
+}
+
+impl<R, F, D> AsyncRead for ReadTimeout<R, F, D>
+where
+    R: AsyncRead,
+    F: FnMut(Duration) -> D,
+    D: Future<Output = ()>,
+{
+    unsafe fn initializer(&self) -> Initializer {
+        self.inner.initializer()
+    }
+
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        self.check_total()?;
+        let res = self.as_mut().inner().poll_read(cx, buf);
+        self.poll_timeouts(cx, res)
+    }
+
+    fn poll_read_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &mut [IoSliceMut<'_>],
+    ) -> Poll<io::Result<usize>> {
+        self.check_total()?;
+        let res = self.as_mut().inner().poll_read_vectored(cx, bufs);
+        self.poll_timeouts(cx, res)
+    }
+}
@@ -3,6 +3,7 @@ use futures_core::task::{Context, Poll};
 use futures_io::{AsyncRead, AsyncWrite, IoSlice, IoSliceMut};
 use std::io;
 use std::pin::Pin;
+use std::sync::Arc;
 
 /// The readable half of an object returned from `AsyncRead::split`.
 #[derive(Debug)]
@@ -16,6 +17,18 @@ pub struct WriteHalf<T> {
     handle: BiLock<T>,
 }
 
+/// The readable half of an object returned from `AsyncReadExt::split_shared`.
+#[derive(Debug)]
+pub struct SharedReadHalf<T> {
+    inner: Arc<T>,
+}
+
+/// The writable half of an object returned from `AsyncReadExt::split_shared`.
+#[derive(Debug)]
+pub struct SharedWriteHalf<T> {
+    inner: Arc<T>,
+}
+
 fn lock_and_then<T, U, E, F>(
     lock: &BiLock<T>,
     cx: &mut Context<'_>,
@@ -32,6 +45,13 @@ pub(super) fn split<T: AsyncRead + AsyncWrite>(t: T) -> (ReadHalf<T>, WriteHalf<
     (ReadHalf { handle: a }, WriteHalf { handle: b })
 }
 
+pub(super) fn split_shared<T>(t: T) -> (SharedReadHalf<T>, SharedWriteHalf<T>)
+    where for<'a> &'a T: AsyncRead + AsyncWrite,
+{
+    let inner = Arc::new(t);
+    (SharedReadHalf { inner: inner.clone() }, SharedWriteHalf { inner })
+}
+
 impl<R: AsyncRead> AsyncRead for ReadHalf<R> {
     fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8])
         -> Poll<io::Result<usize>>
@@ -67,3 +87,43 @@ impl<W: AsyncWrite> AsyncWrite for WriteHalf<W> {
         lock_and_then(&self.handle, cx, |l, cx| l.poll_close(cx))
     }
 }
+
+impl<T> AsyncRead for SharedReadHalf<T>
+    where for<'a> &'a T: AsyncRead,
+{
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8])
+        -> Poll<io::Result<usize>>
+    {
+        Pin::new(&mut &*self.inner).poll_read(cx, buf)
+    }
+
+    fn poll_read_vectored(self: Pin<&mut Self>, cx: &mut Context<'_>, bufs: &mut [IoSliceMut<'_>])
+        -> Poll<io::Result<usize>>
+    {
+        Pin::new(&mut &*self.inner).poll_read_vectored(cx, bufs)
+    }
+}
+
+impl<T> AsyncWrite for SharedWriteHalf<T>
+    where for<'a> &'a T: AsyncWrite,
+{
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8])
+        -> Poll<io::Result<usize>>
+    {
+        Pin::new(&mut &*self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(self: Pin<&mut Self>, cx: &mut Context<'_>, bufs: &[IoSlice<'_>])
+        -> Poll<io::Result<usize>>
+    {
+        Pin::new(&mut &*self.inner).poll_write_vectored(cx, bufs)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut &*self.inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut &*self.inner).poll_close(cx)
+    }
+}
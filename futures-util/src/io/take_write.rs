@@ -0,0 +1,94 @@
+use futures_core::task::{Context, Poll};
+use futures_io::AsyncWrite;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::cmp;
+use std::io;
+use std::pin::Pin;
+
+/// Writer for the [`take_write`](super::AsyncWriteExt::take_write) method.
+///
+/// Accepts at most `limit` bytes before rejecting further writes.
+#[derive(Debug)]
+pub struct TakeWrite<W> {
+    inner: W,
+    remaining: u64,
+}
+
+impl<W: Unpin> Unpin for TakeWrite<W> {}
+
+impl<W: AsyncWrite> TakeWrite<W> {
+    unsafe_pinned!(inner: W);
+    unsafe_unpinned!(remaining: u64);
+
+    pub(super) fn new(inner: W, limit: u64) -> Self {
+        Self { inner, remaining: limit }
+    }
+
+    /// Returns the number of bytes that can still be written before this
+    /// writer starts rejecting writes.
+    pub fn limit(&self) -> u64 {
+        self.remaining
+    }
+
+    /// Sets the number of bytes that can be written before this writer starts
+    /// rejecting writes.
+    ///
+    /// This resets the amount of bytes accepted so far, so the new limit is
+    /// counted from this point onwards.
+    pub fn set_limit(&mut self, limit: u64) {
+        self.remaining = limit
+    }
+
+    /// Gets a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// Care should be taken to avoid modifying the internal I/O state of the
+    /// underlying writer as doing so may corrupt the internal limit of this
+    /// `TakeWrite`.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Gets a pinned mutable reference to the underlying writer.
+    ///
+    /// Care should be taken to avoid modifying the internal I/O state of the
+    /// underlying writer as doing so may corrupt the internal limit of this
+    /// `TakeWrite`.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut W> {
+        self.inner()
+    }
+
+    /// Consumes the `TakeWrite`, returning the wrapped writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: AsyncWrite> AsyncWrite for TakeWrite<W> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        if self.remaining == 0 {
+            return Poll::Ready(Ok(0));
+        }
+
+        let max = cmp::min(buf.len() as u64, self.remaining) as usize;
+        let n = ready!(self.as_mut().inner().poll_write(cx, &buf[..max]))?;
+        *self.as_mut().remaining() -= n as u64;
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.inner().poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.inner().poll_close(cx)
+    }
+}
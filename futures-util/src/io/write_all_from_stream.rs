@@ -0,0 +1,134 @@
+use crate::stream::{Fuse, StreamExt};
+use crate::try_stream::{IntoStream, TryStreamExt};
+use futures_core::future::Future;
+use futures_core::stream::{Stream, TryStream};
+use futures_core::task::{Context, Poll};
+use futures_io::{AsyncWrite, IoSlice};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use std::collections::VecDeque;
+use std::io;
+use std::pin::Pin;
+
+// The most chunks passed to a single vectored write
+const MAX_CHUNKS: usize = 64;
+
+/// Future for the
+/// [`write_all_from_stream`](super::AsyncWriteExt::write_all_from_stream)
+/// method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WriteAllFromStream<'a, W: ?Sized + Unpin, St: TryStream> {
+    stream: Fuse<IntoStream<St>>,
+    buf: Buffer<'a, W, St::Ok>,
+}
+
+// The writer along with the chunks waiting to be written to it, none of which
+// are pinned.
+#[derive(Debug)]
+struct Buffer<'a, W: ?Sized, T> {
+    writer: &'a mut W,
+    chunks: VecDeque<T>,
+    // Number of bytes of the first chunk which have already been written
+    offset: usize,
+}
+
+impl<W: ?Sized + Unpin, St: TryStream + Unpin> Unpin for WriteAllFromStream<'_, W, St> {}
+
+impl<'a, W, St> WriteAllFromStream<'a, W, St>
+where
+    W: AsyncWrite + ?Sized + Unpin,
+    St: TryStream<Error = io::Error>,
+    St::Ok: AsRef<[u8]>,
+{
+    unsafe_pinned!(stream: Fuse<IntoStream<St>>);
+    unsafe_unpinned!(buf: Buffer<'a, W, St::Ok>);
+
+    pub(super) fn new(writer: &'a mut W, stream: St) -> Self {
+        WriteAllFromStream {
+            stream: stream.into_stream().fuse(),
+            buf: Buffer {
+                writer,
+                chunks: VecDeque::new(),
+                offset: 0,
+            },
+        }
+    }
+
+    // Buffers as many chunks as the stream has ready, up to `MAX_CHUNKS`.
+    fn poll_fill(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> io::Result<()> {
+        while self.buf.chunks.len() < MAX_CHUNKS {
+            match self.as_mut().stream().poll_next(cx) {
+                Poll::Ready(Some(chunk)) => {
+                    let chunk = chunk?;
+                    if !chunk.as_ref().is_empty() {
+                        self.as_mut().buf().chunks.push_back(chunk);
+                    }
+                }
+                Poll::Ready(None) | Poll::Pending => break,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<W, T> Buffer<'_, W, T>
+where
+    W: AsyncWrite + ?Sized + Unpin,
+    T: AsRef<[u8]>,
+{
+    // Writes as much of the buffered chunks as the writer takes at once.
+    fn poll_write(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<usize>> {
+        let mut slices = [IoSlice::new(&[]); MAX_CHUNKS];
+        let mut chunks = self.chunks.iter();
+        let first = chunks.next().unwrap();
+        slices[0] = IoSlice::new(&first.as_ref()[self.offset..]);
+        let mut len = 1;
+        for chunk in chunks {
+            slices[len] = IoSlice::new(chunk.as_ref());
+            len += 1;
+        }
+        Pin::new(&mut *self.writer).poll_write_vectored(cx, &slices[..len])
+    }
+
+    // Removes `n` written bytes from the front of the buffered chunks.
+    fn consume(&mut self, mut n: usize) {
+        while n > 0 {
+            let remaining = self.chunks[0].as_ref().len() - self.offset;
+            if n < remaining {
+                self.offset += n;
+                return;
+            }
+            n -= remaining;
+            self.chunks.pop_front();
+            self.offset = 0;
+        }
+    }
+}
+
+impl<W, St> Future for WriteAllFromStream<'_, W, St>
+where
+    W: AsyncWrite + ?Sized + Unpin,
+    St: TryStream<Error = io::Error>,
+    St::Ok: AsRef<[u8]>,
+{
+    type Output = io::Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        loop {
+            self.as_mut().poll_fill(cx)?;
+
+            if self.buf.chunks.is_empty() {
+                if self.stream.is_done() {
+                    return Pin::new(&mut *self.as_mut().buf().writer).poll_flush(cx);
+                }
+                return Poll::Pending;
+            }
+
+            let n = ready!(self.as_mut().buf().poll_write(cx))?;
+            if n == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()))
+            }
+            self.as_mut().buf().consume(n);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+/// Merges a fixed set of streams with the same item type into a single
+/// stream, without allocating.
+///
+/// This is a statically typed alternative to
+/// [`select_all`](crate::stream::select_all) for a small set of streams
+/// that may have different types: the streams are stored inline, no boxing
+/// or `Vec` is involved. Like [`select`](crate::stream::select), the streams
+/// are polled in a round-robin fashion: after a stream yielded an item, the
+/// next one gets to go first on the next poll. Streams that have ended
+/// aren't polled again, and the merged stream ends once all of them have
+/// ended. The returned stream implements
+/// [`FusedStream`](futures_core::stream::FusedStream).
+///
+/// The macro takes the names of variables holding the streams, which it
+/// moves. The streams need to be [`Unpin`]; pin them with
+/// [`pin_mut!`](pin_utils::pin_mut) or [`Box::pin`](std::boxed::Box::pin)
+/// if they aren't.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await)]
+/// # futures::executor::block_on(async {
+/// use futures::future;
+/// use futures::merge;
+/// use futures::stream::{self, StreamExt};
+///
+/// let a = stream::iter(vec![1, 2, 3]);
+/// let b = stream::once(future::ready(10));
+/// let c = stream::repeat(20).take(2);
+///
+/// let merged: Vec<_> = merge!(a, b, c).collect().await;
+/// assert_eq!(merged, vec![1, 10, 20, 2, 20, 3]);
+/// # });
+/// ```
+#[macro_export]
+macro_rules! merge {
+    ($($stream:ident),+ $(,)?) => { {
+        $(
+            // Move the stream into a local so that it is no longer
+            // accessible by the end user.
+            let mut $stream = $crate::stream::StreamExt::fuse($stream);
+        )+
+        let mut first = 0usize;
+        $crate::stream::StreamExt::fuse($crate::stream::poll_fn(move |cx| {
+            let mut len = 0usize;
+            $( let _ = &$stream; len += 1; )+
+            let mut pending = false;
+            for offset in 0..len {
+                let turn = (first + offset) % len;
+                let mut index = 0usize;
+                $(
+                    if index == turn
+                        && !$crate::stream::Fuse::is_done(&$stream)
+                    {
+                        match $crate::stream::StreamExt::poll_next_unpin(&mut $stream, cx) {
+                            $crate::core_reexport::task::Poll::Ready(Some(item)) => {
+                                first = turn + 1;
+                                return $crate::core_reexport::task::Poll::Ready(Some(item));
+                            }
+                            $crate::core_reexport::task::Poll::Ready(None) => {}
+                            $crate::core_reexport::task::Poll::Pending => pending = true,
+                        }
+                    }
+                    index += 1;
+                )+
+            }
+            if pending {
+                $crate::core_reexport::task::Poll::Pending
+            } else {
+                $crate::core_reexport::task::Poll::Ready(None)
+            }
+        }))
+    } }
+}
@@ -1,6 +1,9 @@
 #[macro_use]
 mod poll;
 
+#[macro_use]
+mod merge;
+
 macro_rules! cfg_target_has_atomic {
     ($($item:item)*) => {$(
         #[cfg_attr(
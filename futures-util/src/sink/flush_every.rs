@@ -0,0 +1,113 @@
+use core::pin::Pin;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Sink for the [`sink_flush_every`](super::SinkExt::sink_flush_every) method.
+#[derive(Debug)]
+#[must_use = "sinks do nothing unless polled"]
+pub struct SinkFlushEvery<Si> {
+    sink: Si,
+    every: usize,
+    // The number of items sent since the last flush.
+    count: usize,
+}
+
+impl<Si: Unpin> Unpin for SinkFlushEvery<Si> {}
+
+impl<Si> SinkFlushEvery<Si> {
+    unsafe_pinned!(sink: Si);
+    unsafe_unpinned!(count: usize);
+
+    pub(super) fn new(sink: Si, every: usize) -> SinkFlushEvery<Si> {
+        assert!(every > 0);
+
+        SinkFlushEvery { sink, every, count: 0 }
+    }
+
+    /// Returns the number of items sent since the last flush.
+    pub fn unflushed(&self) -> usize {
+        self.count
+    }
+
+    /// Get a shared reference to the inner sink.
+    pub fn get_ref(&self) -> &Si {
+        &self.sink
+    }
+
+    /// Get a mutable reference to the inner sink.
+    pub fn get_mut(&mut self) -> &mut Si {
+        &mut self.sink
+    }
+
+    /// Get a pinned mutable reference to the inner sink.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut Si> {
+        self.sink()
+    }
+
+    /// Consumes this combinator, returning the underlying sink.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> Si {
+        self.sink
+    }
+}
+
+impl<Si: Sink<Item>, Item> Sink<Item> for SinkFlushEvery<Si> {
+    type SinkError = Si::SinkError;
+
+    fn poll_ready(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        if self.count >= self.every {
+            ready!(self.as_mut().sink().poll_flush(cx))?;
+            *self.as_mut().count() = 0;
+        }
+        self.as_mut().sink().poll_ready(cx)
+    }
+
+    fn start_send(
+        mut self: Pin<&mut Self>,
+        item: Item,
+    ) -> Result<(), Self::SinkError> {
+        self.as_mut().sink().start_send(item)?;
+        *self.as_mut().count() += 1;
+        Ok(())
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        ready!(self.as_mut().sink().poll_flush(cx))?;
+        *self.as_mut().count() = 0;
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        ready!(self.as_mut().sink().poll_close(cx))?;
+        *self.as_mut().count() = 0;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<S: Stream> Stream for SinkFlushEvery<S> {
+    type Item = S::Item;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<S::Item>> {
+        self.sink().poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.sink.size_hint()
+    }
+}
@@ -0,0 +1,90 @@
+use core::fmt;
+use core::marker::PhantomData;
+use core::pin::Pin;
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+
+/// Sink for the [`from_fn`] function.
+#[must_use = "sinks do nothing unless polled"]
+pub struct FromFn<F, E> {
+    f: F,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<F, E> Unpin for FromFn<F, E> {}
+
+impl<F, E> fmt::Debug for FromFn<F, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FromFn").finish()
+    }
+}
+
+/// Creates a sink which handles each item by calling the given closure.
+///
+/// The closure is called synchronously from `start_send` with each item. An
+/// error returned by the closure is returned from `start_send`. The sink is
+/// always ready to accept another item, and flushing or closing it completes
+/// immediately, which makes this the simplest way to adapt a callback-style
+/// API into a [`Sink`].
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await)]
+/// # futures::executor::block_on(async {
+/// use futures::sink::{self, SinkExt};
+///
+/// let mut received = Vec::new();
+/// let mut sink = sink::from_fn(|item: i32| {
+///     received.push(item);
+///     Ok::<(), ()>(())
+/// });
+///
+/// sink.send(1).await?;
+/// sink.send(2).await?;
+/// drop(sink);
+///
+/// assert_eq!(received, vec![1, 2]);
+/// # Ok::<(), ()>(()) }).unwrap();
+/// ```
+pub fn from_fn<T, E, F>(f: F) -> FromFn<F, E>
+where
+    F: FnMut(T) -> Result<(), E>,
+{
+    FromFn { f, _marker: PhantomData }
+}
+
+impl<T, E, F> Sink<T> for FromFn<F, E>
+where
+    F: FnMut(T) -> Result<(), E>,
+{
+    type SinkError = E;
+
+    fn poll_ready(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(
+        mut self: Pin<&mut Self>,
+        item: T,
+    ) -> Result<(), Self::SinkError> {
+        (self.f)(item)
+    }
+
+    fn poll_flush(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        Poll::Ready(Ok(()))
+    }
+}
@@ -4,7 +4,7 @@
 //! including the `SinkExt` trait which adds methods to `Sink` types.
 
 use futures_core::future::Future;
-use futures_core::stream::Stream;
+use futures_core::stream::{Stream, TryStream};
 use futures_sink::Sink;
 use crate::future::Either;
 
@@ -23,6 +23,12 @@ pub use self::fanout::Fanout;
 mod flush;
 pub use self::flush::Flush;
 
+mod flush_every;
+pub use self::flush_every::SinkFlushEvery;
+
+mod from_fn;
+pub use self::from_fn::{from_fn, FromFn};
+
 mod err_into;
 pub use self::err_into::SinkErrInto;
 
@@ -41,6 +47,9 @@ pub use self::with::With;
 mod with_flat_map;
 pub use self::with_flat_map::WithFlatMap;
 
+mod with_try_flat_map;
+pub use self::with_try_flat_map::WithTryFlatMap;
+
 #[cfg(feature = "alloc")]
 mod buffer;
 #[cfg(feature = "alloc")]
@@ -113,6 +122,62 @@ pub trait SinkExt<Item>: Sink<Item> {
         WithFlatMap::new(self, f)
     }
 
+    /// Composes a fallible expansion *in front of* the sink.
+    ///
+    /// This works like [`with_flat_map`](SinkExt::with_flat_map), except that
+    /// `f` produces a [`TryStream`] whose error type doesn't have to match the
+    /// sink's: the returned sink's error type is the stream's error type, into
+    /// which errors of the underlying sink are converted.
+    ///
+    /// The items of each stream are passed to the underlying sink in order. If
+    /// the stream produces an error, the rest of the stream is dropped and the
+    /// error is returned by the new sink.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::sink::SinkExt;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Error {
+    ///     Send(mpsc::SendError),
+    ///     Parse(String),
+    /// }
+    ///
+    /// impl From<mpsc::SendError> for Error {
+    ///     fn from(e: mpsc::SendError) -> Error {
+    ///         Error::Send(e)
+    ///     }
+    /// }
+    ///
+    /// let (tx, rx) = mpsc::channel(5);
+    ///
+    /// let mut tx = tx.with_try_flat_map(|line: &str| {
+    ///     stream::iter(line.split(',').map(|x| {
+    ///         x.parse::<i32>().map_err(|_| Error::Parse(x.to_string()))
+    ///     }).collect::<Vec<_>>())
+    /// });
+    ///
+    /// tx.send("1,2,3").await.unwrap();
+    /// assert_eq!(tx.send("4,x").await, Err(Error::Parse("x".to_string())));
+    /// drop(tx);
+    /// let received: Vec<i32> = rx.collect().await;
+    /// assert_eq!(received, vec![1, 2, 3, 4]);
+    /// # });
+    /// ```
+    fn with_try_flat_map<U, St, F>(self, f: F) -> WithTryFlatMap<Self, Item, U, St, F>
+        where F: FnMut(U) -> St,
+              St: TryStream<Ok = Item>,
+              St::Error: From<Self::SinkError>,
+              Self: Sized
+    {
+        WithTryFlatMap::new(self, f)
+    }
+
     /*
     fn with_map<U, F>(self, f: F) -> WithMap<Self, U, F>
         where F: FnMut(U) -> Self::SinkItem,
@@ -146,6 +211,47 @@ pub trait SinkExt<Item>: Sink<Item> {
     }
 
 
+    /// Flushes this sink after every `n` items sent into it.
+    ///
+    /// Sending each item with [`send`](SinkExt::send) flushes after every
+    /// single item, while feeding items one after the other may leave them
+    /// unflushed for too long. The returned sink counts the items sent into
+    /// it, and once `n` items have been sent since the last flush, it flushes
+    /// before accepting the next item. Explicit flushes and closing the sink
+    /// reset the count.
+    ///
+    /// Items which haven't been followed by another item are only flushed
+    /// with the next explicit flush, as nothing polls this sink in between.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::sink::{Sink, SinkExt};
+    /// use futures_test::sink::MockSink;
+    /// use futures_test::task::noop_context;
+    /// use std::pin::Pin;
+    ///
+    /// let mut cx = noop_context();
+    /// let mut sink = MockSink::<i32>::new().sink_flush_every(2);
+    ///
+    /// for item in 1..=3 {
+    ///     assert!(Pin::new(&mut sink).poll_ready(&mut cx).is_ready());
+    ///     Pin::new(&mut sink).start_send(item).unwrap();
+    /// }
+    /// // Items 1 and 2 were flushed before item 3 was accepted.
+    /// assert_eq!(sink.get_ref().flushed_len(), 2);
+    /// assert_eq!(sink.unflushed(), 1);
+    /// ```
+    fn sink_flush_every(self, n: usize) -> SinkFlushEvery<Self>
+        where Self: Sized
+    {
+        SinkFlushEvery::new(self, n)
+    }
+
     /// Adds a fixed-size buffer to the current sink.
     ///
     /// The resulting sink will buffer up to `capacity` items when the
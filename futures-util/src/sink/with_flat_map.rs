@@ -114,6 +114,10 @@ where
     ) -> Poll<Option<S::Item>> {
         self.sink().poll_next(cx)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.sink.size_hint()
+    }
 }
 
 impl<Si, Item, U, St, F> Sink<U> for WithFlatMap<Si, Item, U, St, F>
@@ -0,0 +1,180 @@
+use core::marker::PhantomData;
+use core::pin::Pin;
+use futures_core::stream::{Stream, TryStream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Sink for the [`with_try_flat_map`](super::SinkExt::with_try_flat_map)
+/// method.
+#[derive(Debug)]
+#[must_use = "sinks do nothing unless polled"]
+pub struct WithTryFlatMap<Si, Item, U, St, F>
+where
+    Si: Sink<Item>,
+    F: FnMut(U) -> St,
+    St: TryStream<Ok = Item>,
+    St::Error: From<Si::SinkError>,
+{
+    sink: Si,
+    f: F,
+    stream: Option<St>,
+    buffer: Option<Item>,
+    _marker: PhantomData<fn(U)>,
+}
+
+impl<Si, Item, U, St, F> Unpin for WithTryFlatMap<Si, Item, U, St, F>
+where
+    Si: Sink<Item> + Unpin,
+    F: FnMut(U) -> St,
+    St: TryStream<Ok = Item> + Unpin,
+    St::Error: From<Si::SinkError>,
+{}
+
+impl<Si, Item, U, St, F> WithTryFlatMap<Si, Item, U, St, F>
+where
+    Si: Sink<Item>,
+    F: FnMut(U) -> St,
+    St: TryStream<Ok = Item>,
+    St::Error: From<Si::SinkError>,
+{
+    unsafe_pinned!(sink: Si);
+    unsafe_unpinned!(f: F);
+    unsafe_pinned!(stream: Option<St>);
+
+    pub(super) fn new(sink: Si, f: F) -> Self {
+        WithTryFlatMap {
+            sink,
+            f,
+            stream: None,
+            buffer: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get a shared reference to the inner sink.
+    pub fn get_ref(&self) -> &Si {
+        &self.sink
+    }
+
+    /// Get a mutable reference to the inner sink.
+    pub fn get_mut(&mut self) -> &mut Si {
+        &mut self.sink
+    }
+
+    /// Get a pinned mutable reference to the inner sink.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut Si> {
+        self.sink()
+    }
+
+    /// Consumes this combinator, returning the underlying sink.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> Si {
+        self.sink
+    }
+
+    fn try_empty_stream(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), St::Error>> {
+        let WithTryFlatMap { sink, stream, buffer, .. } =
+            unsafe { self.get_unchecked_mut() };
+        let mut sink = unsafe { Pin::new_unchecked(sink) };
+        let mut stream = unsafe { Pin::new_unchecked(stream) };
+
+        if buffer.is_some() {
+            ready!(sink.as_mut().poll_ready(cx))?;
+            let item = buffer.take().unwrap();
+            sink.as_mut().start_send(item)?;
+        }
+        if let Some(mut some_stream) = stream.as_mut().as_pin_mut() {
+            loop {
+                let item = match ready!(some_stream.as_mut().try_poll_next(cx)) {
+                    Some(Ok(item)) => item,
+                    Some(Err(e)) => {
+                        // The rest of the expansion is dropped along with the
+                        // error, so that the next item starts afresh.
+                        stream.set(None);
+                        return Poll::Ready(Err(e));
+                    }
+                    None => break,
+                };
+                match sink.as_mut().poll_ready(cx)? {
+                    Poll::Ready(()) => sink.as_mut().start_send(item)?,
+                    Poll::Pending => {
+                        *buffer = Some(item);
+                        return Poll::Pending;
+                    }
+                };
+            }
+        }
+        stream.set(None);
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<S, Item, U, St, F> Stream for WithTryFlatMap<S, Item, U, St, F>
+where
+    S: Stream + Sink<Item>,
+    F: FnMut(U) -> St,
+    St: TryStream<Ok = Item>,
+    St::Error: From<S::SinkError>,
+{
+    type Item = S::Item;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<S::Item>> {
+        self.sink().poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.sink.size_hint()
+    }
+}
+
+impl<Si, Item, U, St, F> Sink<U> for WithTryFlatMap<Si, Item, U, St, F>
+where
+    Si: Sink<Item>,
+    F: FnMut(U) -> St,
+    St: TryStream<Ok = Item>,
+    St::Error: From<Si::SinkError>,
+{
+    type SinkError = St::Error;
+
+    fn poll_ready(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        self.try_empty_stream(cx)
+    }
+
+    fn start_send(
+        mut self: Pin<&mut Self>,
+        item: U,
+    ) -> Result<(), Self::SinkError> {
+        assert!(self.stream.is_none());
+        let stream = (self.as_mut().f())(item);
+        self.stream().set(Some(stream));
+        Ok(())
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        ready!(self.as_mut().try_empty_stream(cx)?);
+        self.as_mut().sink().poll_flush(cx).map_err(Into::into)
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::SinkError>> {
+        ready!(self.as_mut().try_empty_stream(cx)?);
+        self.as_mut().sink().poll_close(cx).map_err(Into::into)
+    }
+}
@@ -0,0 +1,143 @@
+use crate::stream::Fuse;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use core::pin::Pin;
+use alloc::collections::VecDeque;
+
+/// Stream for the [`armed_prefetch`](super::StreamExt::armed_prefetch) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct ArmedPrefetch<St: Stream> {
+    stream: Fuse<St>,
+    buffer: VecDeque<St::Item>,
+    max: usize,
+}
+
+impl<St: Unpin + Stream> Unpin for ArmedPrefetch<St> {}
+
+impl<St: Stream> ArmedPrefetch<St> {
+    unsafe_pinned!(stream: Fuse<St>);
+    unsafe_unpinned!(buffer: VecDeque<St::Item>);
+
+    pub(super) fn new(stream: St, n: usize) -> ArmedPrefetch<St> {
+        assert!(n > 0);
+
+        ArmedPrefetch {
+            stream: super::Fuse::new(stream),
+            buffer: VecDeque::new(),
+            max: n,
+        }
+    }
+
+    /// Returns the number of items read ahead from the underlying stream
+    /// which haven't been yielded yet.
+    pub fn prefetched(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Reads ahead from the underlying stream without yielding anything.
+    ///
+    /// This returns `Poll::Ready(())` once the buffer is full or the
+    /// underlying stream has ended, and `Poll::Pending` while the underlying
+    /// stream has no more items ready. Polling this alongside the processing
+    /// of an item, for example by joining the two, keeps the underlying
+    /// stream making progress while the consumer is busy.
+    pub fn poll_prefetch(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<()> {
+        while self.buffer.len() < self.max {
+            match ready!(self.as_mut().stream().poll_next(cx)) {
+                Some(item) => self.as_mut().buffer().push_back(item),
+                None => break,
+            }
+        }
+        Poll::Ready(())
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.stream.get_mut()
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut St> {
+        self.stream().get_pin_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream.into_inner()
+    }
+}
+
+impl<St: Stream> Stream for ArmedPrefetch<St> {
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let filled = self.as_mut().poll_prefetch(cx).is_ready();
+        match self.as_mut().buffer().pop_front() {
+            Some(item) => {
+                // Top the buffer back up, so that `n` items are at hand while
+                // this one is being processed.
+                if filled {
+                    let _ = self.as_mut().poll_prefetch(cx);
+                }
+                Poll::Ready(Some(item))
+            }
+            // A buffer that was filled can only be empty if the underlying
+            // stream has ended.
+            None if filled => Poll::Ready(None),
+            None => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.buffer.len();
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower.saturating_add(buffered);
+        let upper = match upper {
+            Some(x) => x.checked_add(buffered),
+            None => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<St: Stream> FusedStream for ArmedPrefetch<St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.buffer.is_empty()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, Item> Sink<Item> for ArmedPrefetch<S>
+where
+    S: Stream + Sink<Item>,
+{
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream, Item);
+}
@@ -84,6 +84,43 @@ where
         self.stream().get_pin_mut()
     }
 
+    /// Returns the maximum number of futures this combinator keeps in flight.
+    pub fn limit(&self) -> usize {
+        self.max
+    }
+
+    /// Returns the number of futures this combinator is currently driving.
+    pub fn in_flight(&self) -> usize {
+        self.in_progress_queue.len()
+    }
+
+    /// Returns `true` if this combinator has reached its
+    /// [`limit`](BufferUnordered::limit) and will not pull more futures from
+    /// the underlying stream until one of the in-flight futures completes.
+    pub fn is_saturated(&self) -> bool {
+        self.in_flight() >= self.max
+    }
+
+    /// Changes the maximum number of futures this combinator keeps in flight.
+    ///
+    /// Raising the limit lets more futures be pulled from the underlying
+    /// stream the next time this combinator is polled. Lowering it does not
+    /// cancel any futures that are already running: they are driven to
+    /// completion as usual, and no new futures are pulled from the underlying
+    /// stream until fewer than `n` of them remain.
+    ///
+    /// Note that this method does not wake the task, so a task that is
+    /// currently waiting on this combinator only observes the new limit
+    /// once it is woken for some other reason.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `n` is zero.
+    pub fn set_limit(&mut self, n: usize) {
+        assert!(n > 0, "limit must be greater than zero");
+        self.max = n;
+    }
+
     /// Consumes this combinator, returning the underlying stream.
     ///
     /// Note that this may discard intermediate state of this combinator, so
@@ -126,6 +163,17 @@ where
             Poll::Pending
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let queue_len = self.in_progress_queue.len();
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower.saturating_add(queue_len);
+        let upper = match upper {
+            Some(x) => x.checked_add(queue_len),
+            None => None,
+        };
+        (lower, upper)
+    }
 }
 
 impl<St> FusedStream for BufferUnordered<St>
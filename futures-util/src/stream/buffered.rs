@@ -79,6 +79,62 @@ where
         self.stream().get_pin_mut()
     }
 
+    /// Returns the maximum number of futures this combinator keeps in flight.
+    pub fn limit(&self) -> usize {
+        self.max
+    }
+
+    /// Returns the number of futures this combinator is currently driving.
+    ///
+    /// This includes futures that have already completed but whose output
+    /// is held back until the outputs of earlier futures have been yielded.
+    pub fn in_flight(&self) -> usize {
+        self.in_progress_queue.len()
+    }
+
+    /// Returns `true` if this combinator has reached its
+    /// [`limit`](Buffered::limit) and will not pull more futures from the
+    /// underlying stream until one of the in-flight futures completes.
+    pub fn is_saturated(&self) -> bool {
+        self.in_flight() >= self.max
+    }
+
+    /// Changes the maximum number of futures this combinator keeps in flight.
+    ///
+    /// Raising the limit lets more futures be pulled from the underlying
+    /// stream the next time this combinator is polled. Lowering it does not
+    /// cancel any futures that are already running: they are driven to
+    /// completion as usual, and no new futures are pulled from the underlying
+    /// stream until fewer than `n` of them remain.
+    ///
+    /// Note that this method does not wake the task, so a task that is
+    /// currently waiting on this combinator only observes the new limit
+    /// once it is woken for some other reason.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `n` is zero.
+    pub fn set_limit(&mut self, n: usize) {
+        assert!(n > 0, "limit must be greater than zero");
+        self.max = n;
+    }
+
+    // Pulls futures from the underlying stream until the limit is reached,
+    // returning whether any were pulled.
+    fn fill_queue(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> bool {
+        let mut pulled = false;
+        while self.in_progress_queue.len() < self.max {
+            match self.as_mut().stream().poll_next(cx) {
+                Poll::Ready(Some(fut)) => {
+                    self.as_mut().in_progress_queue().push_back(fut);
+                    pulled = true;
+                }
+                Poll::Ready(None) | Poll::Pending => break,
+            }
+        }
+        pulled
+    }
+
     /// Consumes this combinator, returning the underlying stream.
     ///
     /// Note that this may discard intermediate state of this combinator, so
@@ -101,16 +157,17 @@ where
     ) -> Poll<Option<Self::Item>> {
         // Try to spawn off as many futures as possible by filling up
         // our in_progress_queue of futures.
-        while self.in_progress_queue.len() < self.max {
-            match self.as_mut().stream().poll_next(cx) {
-                Poll::Ready(Some(fut)) => self.as_mut().in_progress_queue().push(fut),
-                Poll::Ready(None) | Poll::Pending => break,
-            }
-        }
+        self.as_mut().fill_queue(cx);
 
         // Attempt to pull the next value from the in_progress_queue
         let res = self.as_mut().in_progress_queue().poll_next_unpin(cx);
         if let Some(val) = ready!(res) {
+            // Refill the slot this output frees right away, and start the new
+            // futures, so that they run while the caller handles the output
+            // instead of only once it polls again.
+            if self.as_mut().fill_queue(cx) {
+                self.as_mut().in_progress_queue().poll_in_progress(cx);
+            }
             return Poll::Ready(Some(val))
         }
 
@@ -121,6 +178,17 @@ where
             Poll::Pending
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let queue_len = self.in_progress_queue.len();
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower.saturating_add(queue_len);
+        let upper = match upper {
+            Some(x) => x.checked_add(queue_len),
+            None => None,
+        };
+        (lower, upper)
+    }
 }
 
 // Forwarding impl of Sink from the underlying stream
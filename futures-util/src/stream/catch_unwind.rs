@@ -1,4 +1,4 @@
-use futures_core::stream::Stream;
+use futures_core::stream::{FusedStream, Stream};
 use futures_core::task::{Context, Poll};
 use pin_utils::{unsafe_pinned, unsafe_unpinned};
 use std::any::Any;
@@ -6,6 +6,9 @@ use std::pin::Pin;
 use std::panic::{catch_unwind, UnwindSafe, AssertUnwindSafe};
 
 /// Stream for the [`catch_unwind`](super::StreamExt::catch_unwind) method.
+///
+/// Once a panic has been caught, the stream terminates and the underlying
+/// stream is never polled again.
 #[derive(Debug)]
 #[must_use = "streams do nothing unless polled"]
 pub struct CatchUnwind<St: Stream> {
@@ -46,4 +49,21 @@ impl<St: Stream + UnwindSafe> Stream for CatchUnwind<St>
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.caught_unwind {
+            (0, Some(0))
+        } else {
+            // A panic here says nothing about the state of the stream, so
+            // fall back to the most conservative hint instead of unwinding.
+            catch_unwind(AssertUnwindSafe(|| self.stream.size_hint()))
+                .unwrap_or((0, None))
+        }
+    }
+}
+
+impl<St: Stream + FusedStream + UnwindSafe> FusedStream for CatchUnwind<St> {
+    fn is_terminated(&self) -> bool {
+        self.caught_unwind || self.stream.is_terminated()
+    }
 }
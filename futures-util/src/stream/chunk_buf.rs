@@ -0,0 +1,62 @@
+use alloc::vec::Vec;
+use core::mem;
+
+// The chunk being collected by `chunks`, `try_chunks` and `try_ready_chunks`.
+//
+// The buffer for the next chunk is only allocated once its first item
+// arrives, so every yielded chunk costs at most one allocation and no buffer
+// is wasted when the stream ends or fails right after a chunk was yielded. A
+// recycled buffer is used instead of allocating, if there is one.
+#[derive(Debug)]
+pub(crate) struct ChunkBuf<T> {
+    items: Vec<T>,
+    spare: Vec<T>,
+    cap: usize, // https://github.com/rust-lang-nursery/futures-rs/issues/1475
+}
+
+impl<T> ChunkBuf<T> {
+    pub(crate) fn new(cap: usize) -> Self {
+        assert!(cap > 0);
+
+        ChunkBuf {
+            items: Vec::new(),
+            spare: Vec::new(),
+            cap,
+        }
+    }
+
+    pub(crate) fn cap(&self) -> usize {
+        self.cap
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub(crate) fn is_full(&self) -> bool {
+        self.items.len() >= self.cap
+    }
+
+    pub(crate) fn push(&mut self, item: T) {
+        if self.items.capacity() == 0 {
+            self.items = mem::replace(&mut self.spare, Vec::new());
+            self.items.reserve_exact(self.cap);
+        }
+        self.items.push(item);
+    }
+
+    pub(crate) fn take(&mut self) -> Vec<T> {
+        mem::replace(&mut self.items, Vec::new())
+    }
+
+    // Keeps `buf` to be used for the next chunk, dropping any buffer kept
+    // before.
+    pub(crate) fn recycle(&mut self, mut buf: Vec<T>) {
+        buf.clear();
+        self.spare = buf;
+    }
+}
@@ -1,9 +1,8 @@
-use crate::stream::Fuse;
+use crate::stream::{ChunkBuf, Fuse};
 use futures_core::stream::Stream;
 use futures_core::task::{Context, Poll};
 use futures_sink::Sink;
 use pin_utils::{unsafe_pinned, unsafe_unpinned};
-use core::mem;
 use core::pin::Pin;
 use alloc::vec::Vec;
 
@@ -12,29 +11,57 @@ use alloc::vec::Vec;
 #[must_use = "streams do nothing unless polled"]
 pub struct Chunks<St: Stream> {
     stream: Fuse<St>,
-    items: Vec<St::Item>,
-    cap: usize, // https://github.com/rust-lang-nursery/futures-rs/issues/1475
+    items: ChunkBuf<St::Item>,
 }
 
 impl<St: Unpin + Stream> Unpin for Chunks<St> {}
 
 impl<St: Stream> Chunks<St> where St: Stream {
-    unsafe_unpinned!(items:  Vec<St::Item>);
+    unsafe_unpinned!(items: ChunkBuf<St::Item>);
     unsafe_pinned!(stream: Fuse<St>);
 
     pub(super) fn new(stream: St, capacity: usize) -> Chunks<St> {
-        assert!(capacity > 0);
-
         Chunks {
             stream: super::Fuse::new(stream),
-            items: Vec::with_capacity(capacity),
-            cap: capacity,
+            items: ChunkBuf::new(capacity),
         }
     }
 
-    fn take(mut self: Pin<&mut Self>) -> Vec<St::Item> {
-        let cap = self.cap;
-        mem::replace(self.as_mut().items(), Vec::with_capacity(cap))
+    /// Returns the maximum number of items yielded in a single chunk, as
+    /// passed to [`chunks`](super::StreamExt::chunks).
+    pub fn chunk_size(&self) -> usize {
+        self.items.cap()
+    }
+
+    /// Hands a previously yielded chunk back for reuse.
+    ///
+    /// The buffer is cleared and used for the next chunk instead of
+    /// allocating a new one. Only one buffer is kept; recycling another one
+    /// while a buffer is already waiting for reuse drops it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use pin_utils::pin_mut;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let chunks = stream::iter(1..=4).chunks(2);
+    /// pin_mut!(chunks);
+    ///
+    /// let chunk = chunks.next().await.unwrap();
+    /// assert_eq!(chunk, vec![1, 2]);
+    /// let ptr = chunk.as_ptr();
+    /// chunks.as_mut().recycle(chunk);
+    ///
+    /// let chunk = chunks.next().await.unwrap();
+    /// assert_eq!(chunk, vec![3, 4]);
+    /// assert_eq!(chunk.as_ptr(), ptr);
+    /// # });
+    /// ```
+    pub fn recycle(self: Pin<&mut Self>, buf: Vec<St::Item>) {
+        self.items().recycle(buf);
     }
 
     /// Acquires a reference to the underlying stream that this combinator is
@@ -84,8 +111,8 @@ impl<St: Stream> Stream for Chunks<St> {
                 // the full one.
                 Some(item) => {
                     self.as_mut().items().push(item);
-                    if self.items.len() >= self.cap {
-                        return Poll::Ready(Some(self.as_mut().take()))
+                    if self.items.is_full() {
+                        return Poll::Ready(Some(self.as_mut().items().take()))
                     }
                 }
 
@@ -95,8 +122,7 @@ impl<St: Stream> Stream for Chunks<St> {
                     let last = if self.items.is_empty() {
                         None
                     } else {
-                        let full_buf = mem::replace(self.as_mut().items(), Vec::new());
-                        Some(full_buf)
+                        Some(self.as_mut().items().take())
                     };
 
                     return Poll::Ready(last);
@@ -104,6 +130,21 @@ impl<St: Stream> Stream for Chunks<St> {
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every `cap` items make a chunk, and any items left over at the end
+        // make one more.
+        let cap = self.items.cap();
+        let chunks = |items: usize| if items == 0 { 0 } else { (items - 1) / cap + 1 };
+        let buffered = self.items.len();
+        let (lower, upper) = self.stream.size_hint();
+        let lower = chunks(lower.saturating_add(buffered));
+        let upper = match upper {
+            Some(x) => x.checked_add(buffered).map(chunks),
+            None => None,
+        };
+        (lower, upper)
+    }
 }
 
 // Forwarding impl of Sink from the underlying stream
@@ -0,0 +1,181 @@
+use crate::stream::Fuse;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use core::mem;
+use core::pin::Pin;
+use alloc::vec::Vec;
+
+/// Stream for the [`chunks_by_size`](super::StreamExt::chunks_by_size)
+/// method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct ChunksBySize<St: Stream> {
+    stream: Fuse<St>,
+    items: Vec<St::Item>,
+    bytes: usize,
+    // An item which didn't fit into the previous chunk
+    overflow: Option<St::Item>,
+    max_bytes: usize,
+}
+
+impl<St: Unpin + Stream> Unpin for ChunksBySize<St> {}
+
+impl<St> ChunksBySize<St>
+where
+    St: Stream,
+    St::Item: AsRef<[u8]>,
+{
+    unsafe_pinned!(stream: Fuse<St>);
+    unsafe_unpinned!(items: Vec<St::Item>);
+    unsafe_unpinned!(bytes: usize);
+    unsafe_unpinned!(overflow: Option<St::Item>);
+
+    pub(super) fn new(stream: St, max_bytes: usize) -> ChunksBySize<St> {
+        assert!(max_bytes > 0);
+
+        ChunksBySize {
+            stream: super::Fuse::new(stream),
+            items: Vec::new(),
+            bytes: 0,
+            overflow: None,
+            max_bytes,
+        }
+    }
+
+    // Adds `item` to the current chunk, returning whether the chunk is full.
+    fn push(mut self: Pin<&mut Self>, item: St::Item) -> bool {
+        *self.as_mut().bytes() += item.as_ref().len();
+        self.as_mut().items().push(item);
+        self.bytes >= self.max_bytes
+    }
+
+    fn take(mut self: Pin<&mut Self>) -> Vec<St::Item> {
+        *self.as_mut().bytes() = 0;
+        mem::replace(self.as_mut().items(), Vec::new())
+    }
+
+    /// Returns the maximum number of bytes yielded in a single chunk, as
+    /// passed to [`chunks_by_size`](super::StreamExt::chunks_by_size).
+    pub fn max_bytes(&self) -> usize {
+        self.max_bytes
+    }
+
+    /// Returns the number of bytes buffered for the next chunk.
+    pub fn buffered_bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.stream.get_mut()
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut St> {
+        self.stream().get_pin_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream.into_inner()
+    }
+}
+
+impl<St> Stream for ChunksBySize<St>
+where
+    St: Stream,
+    St::Item: AsRef<[u8]>,
+{
+    type Item = Vec<St::Item>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        // Start the chunk with the item that didn't fit into the last one.
+        // If it fills the chunk on its own, it is yielded by itself.
+        if let Some(item) = self.as_mut().overflow().take() {
+            if self.as_mut().push(item) {
+                return Poll::Ready(Some(self.as_mut().take()))
+            }
+        }
+
+        loop {
+            match ready!(self.as_mut().stream().poll_next(cx)) {
+                Some(item) => {
+                    // Hold the item back for the next chunk if it would take
+                    // this one over the limit.
+                    if !self.items.is_empty()
+                        && self.bytes + item.as_ref().len() > self.max_bytes
+                    {
+                        *self.as_mut().overflow() = Some(item);
+                        return Poll::Ready(Some(self.as_mut().take()))
+                    }
+                    if self.as_mut().push(item) {
+                        return Poll::Ready(Some(self.as_mut().take()))
+                    }
+                }
+
+                // Since the underlying stream ran out of values, return what we
+                // have buffered, if we have anything.
+                None => {
+                    let last = if self.items.is_empty() {
+                        None
+                    } else {
+                        Some(self.as_mut().take())
+                    };
+
+                    return Poll::Ready(last);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.items.len() + self.overflow.is_some() as usize;
+        let (lower, upper) = self.stream.size_hint();
+        let lower = if lower > 0 || buffered > 0 { 1 } else { 0 };
+        let upper = upper.and_then(|upper| upper.checked_add(buffered));
+        (lower, upper)
+    }
+}
+
+impl<St> FusedStream for ChunksBySize<St>
+where
+    St: Stream,
+    St::Item: AsRef<[u8]>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.items.is_empty() && self.overflow.is_none()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, Item> Sink<Item> for ChunksBySize<S>
+where
+    S: Stream + Sink<Item>,
+    S::Item: AsRef<[u8]>,
+{
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream, Item);
+}
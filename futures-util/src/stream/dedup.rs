@@ -0,0 +1,112 @@
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`dedup`](super::StreamExt::dedup) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Dedup<St: Stream> {
+    stream: St,
+    last: Option<St::Item>,
+}
+
+impl<St: Unpin + Stream> Unpin for Dedup<St> {}
+
+impl<St> Dedup<St>
+    where St: Stream,
+          St::Item: PartialEq + Clone,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(last: Option<St::Item>);
+
+    pub(super) fn new(stream: St) -> Dedup<St> {
+        Dedup { stream, last: None }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut St> {
+        self.stream()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St> FusedStream for Dedup<St>
+    where St: Stream + FusedStream,
+          St::Item: PartialEq + Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St> Stream for Dedup<St>
+    where St: Stream,
+          St::Item: PartialEq + Clone,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<St::Item>> {
+        loop {
+            let item = match ready!(self.as_mut().stream().poll_next(cx)) {
+                Some(item) => item,
+                None => return Poll::Ready(None),
+            };
+            if self.last.as_ref() != Some(&item) {
+                *self.as_mut().last() = Some(item.clone());
+                return Poll::Ready(Some(item));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        // Once an item has been yielded, all the others may be duplicates of
+        // it.
+        if self.last.is_some() {
+            (0, upper)
+        } else {
+            (lower.min(1), upper)
+        }
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, Item> Sink<Item> for Dedup<S>
+    where S: Stream + Sink<Item>,
+          S::Item: PartialEq + Clone,
+{
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream, Item);
+}
@@ -0,0 +1,119 @@
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`dedup_by_key`](super::StreamExt::dedup_by_key) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct DedupByKey<St, F, K> {
+    stream: St,
+    f: F,
+    last_key: Option<K>,
+}
+
+impl<St: Unpin, F, K> Unpin for DedupByKey<St, F, K> {}
+
+impl<St, F, K> DedupByKey<St, F, K>
+    where St: Stream,
+          F: FnMut(&St::Item) -> K,
+          K: PartialEq,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(last_key: Option<K>);
+
+    pub(super) fn new(stream: St, f: F) -> DedupByKey<St, F, K> {
+        DedupByKey { stream, f, last_key: None }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut St> {
+        self.stream()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St, F, K> FusedStream for DedupByKey<St, F, K>
+    where St: Stream + FusedStream,
+          F: FnMut(&St::Item) -> K,
+          K: PartialEq,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, F, K> Stream for DedupByKey<St, F, K>
+    where St: Stream,
+          F: FnMut(&St::Item) -> K,
+          K: PartialEq,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<St::Item>> {
+        loop {
+            let item = match ready!(self.as_mut().stream().poll_next(cx)) {
+                Some(item) => item,
+                None => return Poll::Ready(None),
+            };
+            let key = (self.as_mut().f())(&item);
+            if self.last_key.as_ref() != Some(&key) {
+                *self.as_mut().last_key() = Some(key);
+                return Poll::Ready(Some(item));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        // Once an item has been yielded, all the others may be duplicates of
+        // it.
+        if self.last_key.is_some() {
+            (0, upper)
+        } else {
+            (lower.min(1), upper)
+        }
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, F, K, Item> Sink<Item> for DedupByKey<S, F, K>
+    where S: Stream + Sink<Item>,
+          F: FnMut(&S::Item) -> K,
+          K: PartialEq,
+{
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream, Item);
+}
@@ -0,0 +1,89 @@
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`find`](super::StreamExt::find) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Find<St, Fut, F>
+    where St: Stream,
+{
+    stream: St,
+    f: F,
+    done: bool,
+    pending_fut: Option<Fut>,
+    pending_item: Option<St::Item>,
+}
+
+impl<St, Fut, F> Unpin for Find<St, Fut, F>
+where St: Stream + Unpin,
+      F: FnMut(&St::Item) -> Fut,
+      Fut: Future<Output = bool> + Unpin,
+{}
+
+impl<St, Fut, F> Find<St, Fut, F>
+where St: Stream,
+      F: FnMut(&St::Item) -> Fut,
+      Fut: Future<Output = bool>,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(done: bool);
+    unsafe_pinned!(pending_fut: Option<Fut>);
+    unsafe_unpinned!(pending_item: Option<St::Item>);
+
+    pub(super) fn new(stream: St, f: F) -> Find<St, Fut, F> {
+        Find {
+            stream,
+            f,
+            done: false,
+            pending_fut: None,
+            pending_item: None,
+        }
+    }
+}
+
+impl<St, Fut, F> FusedFuture for Find<St, Fut, F>
+    where St: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St, Fut, F> Future for Find<St, Fut, F>
+    where St: Stream,
+          F: FnMut(&St::Item) -> Fut,
+          Fut: Future<Output = bool>,
+{
+    type Output = Option<St::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<St::Item>> {
+        assert!(!self.done, "Find polled after completion");
+        loop {
+            if let Some(fut) = self.as_mut().pending_fut().as_pin_mut() {
+                let found = ready!(fut.poll(cx));
+                self.as_mut().pending_fut().set(None);
+                let item = self.as_mut().pending_item().take().unwrap();
+                if found {
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(Some(item));
+                }
+            }
+
+            match ready!(self.as_mut().stream().poll_next(cx)) {
+                Some(item) => {
+                    let fut = (self.as_mut().f())(&item);
+                    self.as_mut().pending_fut().set(Some(fut));
+                    *self.as_mut().pending_item() = Some(item);
+                }
+                None => {
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+}
@@ -94,6 +94,19 @@ impl<St> Stream for Flatten<St>
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Nothing is known about the streams which are still to come, unless
+        // there are none.
+        let (lower, upper) = match &self.next {
+            Some(next) => next.size_hint(),
+            None => (0, Some(0)),
+        };
+        match self.stream.size_hint() {
+            (_, Some(0)) => (lower, upper),
+            _ => (lower, None),
+        }
+    }
 }
 
 // Forwarding impl of Sink from the underlying stream
@@ -32,24 +32,52 @@ where
         Forward {
             sink: Some(sink),
             stream: stream.fuse(),
-                buffered_item: None,
+            buffered_item: None,
         }
     }
 
+    /// Acquires a pinned mutable reference to the sink that this future is
+    /// forwarding into.
+    ///
+    /// Returns `None` once the stream has been fully forwarded and the sink
+    /// has been closed. If forwarding failed, the sink is still available
+    /// through this method.
+    pub fn sink_pin_mut<'a>(self: Pin<&'a mut Self>) -> Option<Pin<&'a mut Si>> {
+        self.sink().as_pin_mut()
+    }
+
+    /// Consumes this future, returning the sink, the stream and the item
+    /// that was pulled from the stream but not yet accepted by the sink.
+    ///
+    /// The sink is `None` once the stream has been fully forwarded and the
+    /// sink has been closed. If the sink reported an error from `poll_ready`,
+    /// the item it was not ready for is returned as the last element rather
+    /// than being dropped. Items the sink accepted through `start_send` are
+    /// owned by the sink and are not returned.
+    ///
+    /// The stream may still yield items that have not been forwarded.
+    pub fn into_parts(self) -> (Option<Si>, St, Option<St::Ok>) {
+        (self.sink, self.stream.into_inner(), self.buffered_item)
+    }
+
     fn try_start_send(
         mut self: Pin<&mut Self>,
         cx: &mut Context<'_>,
         item: St::Ok,
     ) -> Poll<Result<(), Si::SinkError>> {
         debug_assert!(self.buffered_item.is_none());
-        {
+        let res = {
             let mut sink = self.as_mut().sink().as_pin_mut().unwrap();
-            if sink.as_mut().poll_ready(cx)?.is_ready() {
-                return Poll::Ready(sink.start_send(item));
+            match sink.as_mut().poll_ready(cx) {
+                Poll::Ready(Ok(())) => return Poll::Ready(sink.start_send(item)),
+                Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+                Poll::Pending => Poll::Pending,
             }
-        }
+        };
+        // Hold on to the item the sink wasn't ready for, so it can be
+        // recovered through `into_parts` if the sink failed.
         *self.as_mut().buffered_item() = Some(item);
-        Poll::Pending
+        res
     }
 }
 
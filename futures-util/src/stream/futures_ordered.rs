@@ -8,12 +8,14 @@ use core::fmt::{self, Debug};
 use core::iter::FromIterator;
 use core::pin::Pin;
 use alloc::collections::binary_heap::{BinaryHeap, PeekMut};
+use alloc::collections::VecDeque;
 
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 #[derive(Debug)]
 struct OrderWrapper<T> {
     data: T, // A future or a future's output
-    index: usize,
+    // Signed so that `push_front` can go below the index of the current head
+    index: i64,
 }
 
 impl<T> PartialEq for OrderWrapper<T> {
@@ -60,7 +62,10 @@ impl<T> Future for OrderWrapper<T>
 /// This "combinator" is similar to `FuturesUnordered`, but it imposes an order
 /// on top of the set of futures. While futures in the set will race to
 /// completion in parallel, results will only be returned in the order their
-/// originating futures were added to the queue.
+/// originating futures were added to the queue: futures added with
+/// [`push_back`](FuturesOrdered::push_back) are returned after all futures
+/// already in the queue, and futures added with
+/// [`push_front`](FuturesOrdered::push_front) before them.
 ///
 /// Futures are pushed into this queue and their realized values are yielded in
 /// order. This structure is optimized to manage a large number of futures.
@@ -70,7 +75,7 @@ impl<T> Future for OrderWrapper<T>
 ///
 /// When a `FuturesOrdered` is first created, it does not contain any futures.
 /// Calling `poll` in this state will result in `Poll::Ready(None))` to be
-/// returned. Futures are submitted to the queue using `push`; however, the
+/// returned. Futures are submitted to the queue using `push_back`; however, the
 /// future will **not** be polled at this point. `FuturesOrdered` will only
 /// poll managed futures when `FuturesOrdered::poll` is called. As such, it
 /// is important to call `poll` after pushing new futures.
@@ -84,6 +89,12 @@ impl<T> Future for OrderWrapper<T>
 /// return `Poll::Pending` until the first future completes, even if
 /// some of the later futures have already completed.
 ///
+/// By default, every future in the queue is polled when it is woken up, so
+/// when an early future is much slower than later ones, the outputs of the
+/// later ones pile up. [`set_reorder_limit`](FuturesOrdered::set_reorder_limit)
+/// bounds this by holding back futures that are too far behind the oldest
+/// one.
+///
 /// Note that you can create a ready-made `FuturesOrdered` via the
 /// [`collect`](Iterator::collect) method, or you can start with an empty queue
 /// with the `FuturesOrdered::new` constructor.
@@ -94,8 +105,12 @@ impl<T> Future for OrderWrapper<T>
 pub struct FuturesOrdered<T: Future> {
     in_progress_queue: FuturesUnordered<OrderWrapper<T>>,
     queued_outputs: BinaryHeap<OrderWrapper<T::Output>>,
-    next_incoming_index: usize,
-    next_outgoing_index: usize,
+    // Futures pushed to the back while `reorder_limit` was reached, which
+    // aren't polled until earlier outputs have been yielded
+    waiting: VecDeque<OrderWrapper<T>>,
+    reorder_limit: Option<usize>,
+    next_incoming_index: i64,
+    next_outgoing_index: i64,
 }
 
 impl<T: Future> Unpin for FuturesOrdered<T> {}
@@ -109,6 +124,8 @@ impl<Fut: Future> FuturesOrdered<Fut> {
         FuturesOrdered {
             in_progress_queue: FuturesUnordered::new(),
             queued_outputs: BinaryHeap::new(),
+            waiting: VecDeque::new(),
+            reorder_limit: None,
             next_incoming_index: 0,
             next_outgoing_index: 0,
         }
@@ -118,28 +135,114 @@ impl<Fut: Future> FuturesOrdered<Fut> {
     ///
     /// This represents the total number of in-flight futures, both
     /// those currently processing and those that have completed but
-    /// which are waiting for earlier futures to complete.
+    /// which are waiting for earlier futures to complete, as well as
+    /// futures held back by the [reorder limit](FuturesOrdered::set_reorder_limit).
     pub fn len(&self) -> usize {
-        self.in_progress_queue.len() + self.queued_outputs.len()
+        self.in_progress_queue.len() + self.queued_outputs.len() + self.waiting.len()
     }
 
     /// Returns `true` if the queue contains no futures
     pub fn is_empty(&self) -> bool {
-        self.in_progress_queue.is_empty() && self.queued_outputs.is_empty()
+        self.in_progress_queue.is_empty()
+            && self.queued_outputs.is_empty()
+            && self.waiting.is_empty()
+    }
+
+    /// Returns the maximum number of futures that are polled or hold a
+    /// completed output at the same time, if there is one.
+    pub fn reorder_limit(&self) -> Option<usize> {
+        self.reorder_limit
+    }
+
+    /// Limits the number of futures that are polled or hold a completed
+    /// output at the same time.
+    ///
+    /// Once `limit` futures are either still running or done but waiting
+    /// for an earlier future to complete, futures further back in the queue
+    /// aren't polled until the oldest outputs have been yielded. This bounds
+    /// the number of outputs buffered for reordering to less than `limit`,
+    /// at the cost of running fewer futures concurrently. Futures pushed
+    /// with [`push_front`](FuturesOrdered::push_front) are always polled, as
+    /// they are the next ones to be yielded.
+    ///
+    /// Passing `None` removes the limit, which is the default. Lowering the
+    /// limit doesn't stop futures that are already being polled.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `limit` is `Some(0)`.
+    pub fn set_reorder_limit(&mut self, limit: Option<usize>) {
+        assert!(limit != Some(0), "reorder limit must be greater than zero");
+        self.reorder_limit = limit;
+    }
+
+    // Polls the futures in the queue without yielding anything, keeping
+    // their outputs until `poll_next` asks for them. This starts futures that
+    // were pushed after an output was taken without waiting for the next
+    // call to `poll_next`.
+    pub(crate) fn poll_in_progress(&mut self, cx: &mut Context<'_>) {
+        self.admit_waiting();
+        while let Poll::Ready(Some(output)) = self.in_progress_queue.poll_next_unpin(cx) {
+            self.queued_outputs.push(output);
+        }
+    }
+
+    // Starts polling the futures held back by the reorder limit, now that
+    // earlier outputs may have been yielded.
+    fn admit_waiting(&mut self) {
+        while !self.waiting.is_empty() && self.has_capacity() {
+            let wrapped = self.waiting.pop_front().unwrap();
+            self.in_progress_queue.push(wrapped);
+        }
+    }
+
+    fn has_capacity(&self) -> bool {
+        self.reorder_limit
+            .map(|limit| self.in_progress_queue.len() + self.queued_outputs.len() < limit)
+            .unwrap_or(true)
     }
 
     /// Push a future into the queue.
     ///
-    /// This function submits the given future to the internal set for managing.
-    /// This function will not call `poll` on the submitted future. The caller
-    /// must ensure that `FuturesOrdered::poll` is called in order to receive
-    /// task notifications.
+    /// This is the same as [`push_back`](FuturesOrdered::push_back).
+    #[deprecated(note = "use `push_back` instead")]
     pub fn push(&mut self, future: Fut) {
+        self.push_back(future);
+    }
+
+    /// Push a future to the back of the queue.
+    ///
+    /// This function submits the given future to the internal set for managing.
+    /// Its output is yielded after the outputs of all futures already in the
+    /// queue. This function will not call `poll` on the submitted future. The
+    /// caller must ensure that `FuturesOrdered::poll` is called in order to
+    /// receive task notifications.
+    pub fn push_back(&mut self, future: Fut) {
         let wrapped = OrderWrapper {
             data: future,
             index: self.next_incoming_index,
         };
         self.next_incoming_index += 1;
+        if self.waiting.is_empty() && self.has_capacity() {
+            self.in_progress_queue.push(wrapped);
+        } else {
+            self.waiting.push_back(wrapped);
+        }
+    }
+
+    /// Push a future to the front of the queue.
+    ///
+    /// This function submits the given future to the internal set for managing.
+    /// Its output is yielded before the outputs of all futures already in the
+    /// queue, even those which have already completed. This function will not
+    /// call `poll` on the submitted future. The caller must ensure that
+    /// `FuturesOrdered::poll` is called in order to receive task notifications.
+    pub fn push_front(&mut self, future: Fut) {
+        self.next_outgoing_index -= 1;
+        let wrapped = OrderWrapper {
+            data: future,
+            index: self.next_outgoing_index,
+        };
         self.in_progress_queue.push(wrapped);
     }
 }
@@ -159,6 +262,8 @@ impl<Fut: Future> Stream for FuturesOrdered<Fut> {
     ) -> Poll<Option<Self::Item>> {
         let this = &mut *self;
 
+        this.admit_waiting();
+
         // Check to see if we've already received the next value
         if let Some(next_output) = this.queued_outputs.peek_mut() {
             if next_output.index == this.next_outgoing_index {
@@ -181,6 +286,11 @@ impl<Fut: Future> Stream for FuturesOrdered<Fut> {
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
 }
 
 impl<Fut: Future> Debug for FuturesOrdered<Fut> {
@@ -195,6 +305,6 @@ impl<Fut: Future> FromIterator<Fut> for FuturesOrdered<Fut> {
         T: IntoIterator<Item = Fut>,
     {
         let acc = FuturesOrdered::new();
-        iter.into_iter().fold(acc, |mut acc, item| { acc.push(item); acc })
+        iter.into_iter().fold(acc, |mut acc, item| { acc.push_back(item); acc })
     }
 }
@@ -15,6 +15,20 @@ pub struct IterPinMut<'a, Fut> {
 /// Mutable iterator over all futures in the unordered set.
 pub struct IterMut<'a, Fut: Unpin> (pub(super) IterPinMut<'a, Fut>);
 
+#[derive(Debug)]
+/// Immutable iterator over all futures in the unordered set.
+pub struct Iter<'a, Fut> {
+    pub(super) task: *const Task<Fut>,
+    pub(super) len: usize,
+    pub(super) _marker: PhantomData<&'a FuturesUnordered<Fut>>
+}
+
+#[derive(Debug)]
+/// Owned iterator over all futures in the unordered set.
+pub struct IntoIter<Fut: Unpin> {
+    pub(super) inner: FuturesUnordered<Fut>,
+}
+
 impl<'a, Fut> Iterator for IterPinMut<'a, Fut> {
     type Item = Pin<&'a mut Fut>;
 
@@ -51,3 +65,53 @@ impl<'a, Fut: Unpin> Iterator for IterMut<'a, Fut> {
 }
 
 impl<Fut: Unpin> ExactSizeIterator for IterMut<'_, Fut> {}
+
+impl<'a, Fut> Iterator for Iter<'a, Fut> {
+    type Item = &'a Fut;
+
+    fn next(&mut self) -> Option<&'a Fut> {
+        if self.task.is_null() {
+            return None;
+        }
+        unsafe {
+            let future = (*(*self.task).future.get()).as_ref().unwrap();
+            let next = *(*self.task).next_all.get();
+            self.task = next;
+            self.len -= 1;
+            Some(future)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<Fut> ExactSizeIterator for Iter<'_, Fut> {}
+
+impl<Fut: Unpin> Iterator for IntoIter<Fut> {
+    type Item = Fut;
+
+    fn next(&mut self) -> Option<Fut> {
+        let head = self.inner.head_all;
+        if head.is_null() {
+            return None;
+        }
+        unsafe {
+            // Take the future out before releasing the task, so that the
+            // ready to run queue sees an empty task just like it does for
+            // completed futures. Moving the future is fine as it is `Unpin`.
+            let task = self.inner.unlink(head);
+            let future = (*task.future.get()).take().unwrap();
+            self.inner.release_task(task);
+            Some(future)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.len();
+        (len, Some(len))
+    }
+}
+
+impl<Fut: Unpin> ExactSizeIterator for IntoIter<Fut> {}
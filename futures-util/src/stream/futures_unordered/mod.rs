@@ -8,6 +8,7 @@ use futures_core::future::{Future, FutureObj, LocalFutureObj};
 use futures_core::stream::{FusedStream, Stream};
 use futures_core::task::{Context, Poll, Spawn, LocalSpawn, SpawnError};
 use core::cell::UnsafeCell;
+use core::cmp;
 use core::fmt::{self, Debug};
 use core::iter::FromIterator;
 use core::marker::PhantomData;
@@ -21,7 +22,7 @@ use alloc::sync::{Arc, Weak};
 mod abort;
 
 mod iter;
-pub use self::iter::{IterMut, IterPinMut};
+pub use self::iter::{IntoIter, Iter, IterMut, IterPinMut};
 
 mod task;
 use self::task::Task;
@@ -38,6 +39,11 @@ use self::ready_to_run_queue::{ReadyToRunQueue, Dequeue};
 /// without running out of ram.
 const TERMINATED_SENTINEL_LENGTH: usize = usize::max_value();
 
+/// Default minimum number of futures polled by a single call to `poll_next`
+/// before yielding back to the executor, see
+/// [`FuturesUnordered::set_poll_budget`].
+const DEFAULT_POLL_BUDGET: usize = 32;
+
 /// A set of futures which may complete in any order.
 ///
 /// This structure is optimized to manage a large number of futures.
@@ -63,6 +69,7 @@ pub struct FuturesUnordered<Fut> {
     ready_to_run_queue: Arc<ReadyToRunQueue<Fut>>,
     len: usize,
     head_all: *const Task<Fut>,
+    poll_budget: usize,
 }
 
 unsafe impl<Fut: Send> Send for FuturesUnordered<Fut> {}
@@ -137,6 +144,7 @@ impl<Fut: Future> FuturesUnordered<Fut> {
             len: 0,
             head_all: ptr::null_mut(),
             ready_to_run_queue,
+            poll_budget: DEFAULT_POLL_BUDGET,
         }
     }
 }
@@ -160,6 +168,30 @@ impl<Fut> FuturesUnordered<Fut> {
         self.len == 0 || self.len == TERMINATED_SENTINEL_LENGTH
     }
 
+    /// Returns the minimum number of futures polled by a single call to
+    /// [`poll_next`](Stream::poll_next) before it yields back to the executor.
+    pub fn poll_budget(&self) -> usize {
+        self.poll_budget
+    }
+
+    /// Sets the minimum number of futures polled by a single call to
+    /// [`poll_next`](Stream::poll_next) before it yields back to the executor.
+    ///
+    /// Futures that wake themselves up while being polled are scheduled to be
+    /// polled again right away. To keep such futures from starving the rest
+    /// of the task, `poll_next` stops once it has polled as many futures as
+    /// the budget or the number of futures in the set, whichever is larger,
+    /// without any of them completing. It then wakes the current task and
+    /// returns [`Poll::Pending`]. The default budget is 32.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `budget` is zero.
+    pub fn set_poll_budget(&mut self, budget: usize) {
+        assert!(budget > 0, "poll budget must be greater than zero");
+        self.poll_budget = budget;
+    }
+
     /// Push a future into the set.
     ///
     /// This method adds the given future to the set. This method will not
@@ -194,6 +226,17 @@ impl<Fut> FuturesUnordered<Fut> {
         self.ready_to_run_queue.enqueue(ptr);
     }
 
+    /// Returns an iterator over the futures in the set.
+    ///
+    /// Iterating doesn't affect which futures are scheduled to be polled.
+    pub fn iter(&self) -> Iter<'_, Fut> {
+        Iter {
+            task: self.head_all,
+            len: self.len(),
+            _marker: PhantomData
+        }
+    }
+
     /// Returns an iterator that allows modifying each future in the set.
     pub fn iter_mut(&mut self) -> IterMut<'_, Fut> where Fut: Unpin {
         IterMut(Pin::new(self).iter_pin_mut())
@@ -297,6 +340,11 @@ impl<Fut: Future> Stream for FuturesUnordered<Fut> {
         // Ensure `parent` is correctly set.
         self.ready_to_run_queue.waker.register(cx.waker());
 
+        // Limit the number of futures polled in this call, see
+        // `set_poll_budget`.
+        let budget = cmp::max(self.len(), self.poll_budget);
+        let mut polled = 0;
+
         loop {
             // Safety: &mut self guarantees the mutual exclusion `dequeue`
             // expects
@@ -419,6 +467,12 @@ impl<Fut: Future> Stream for FuturesUnordered<Fut> {
                 Poll::Pending => {
                     let task = bomb.task.take().unwrap();
                     bomb.queue.link(task);
+
+                    polled += 1;
+                    if polled == budget {
+                        cx.waker().wake_by_ref();
+                        return Poll::Pending;
+                    }
                     continue
                 }
                 Poll::Ready(output) => {
@@ -427,6 +481,11 @@ impl<Fut: Future> Stream for FuturesUnordered<Fut> {
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
 }
 
 impl<Fut> Debug for FuturesUnordered<Fut> {
@@ -474,6 +533,47 @@ impl<Fut: Future> FromIterator<Fut> for FuturesUnordered<Fut> {
     }
 }
 
+impl<Fut: Future> Extend<Fut> for FuturesUnordered<Fut> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Fut>,
+    {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+/// Yields the futures that haven't completed yet, e.g. to move them to
+/// another set. Futures that are dropped along with a partially consumed
+/// iterator are released just like when dropping the set.
+impl<Fut: Unpin> IntoIterator for FuturesUnordered<Fut> {
+    type Item = Fut;
+    type IntoIter = IntoIter<Fut>;
+
+    fn into_iter(self) -> IntoIter<Fut> {
+        IntoIter { inner: self }
+    }
+}
+
+impl<'a, Fut> IntoIterator for &'a FuturesUnordered<Fut> {
+    type Item = &'a Fut;
+    type IntoIter = Iter<'a, Fut>;
+
+    fn into_iter(self) -> Iter<'a, Fut> {
+        self.iter()
+    }
+}
+
+impl<'a, Fut: Unpin> IntoIterator for &'a mut FuturesUnordered<Fut> {
+    type Item = &'a mut Fut;
+    type IntoIter = IterMut<'a, Fut>;
+
+    fn into_iter(self) -> IterMut<'a, Fut> {
+        self.iter_mut()
+    }
+}
+
 impl<Fut: Future> FusedStream for FuturesUnordered<Fut> {
     fn is_terminated(&self) -> bool {
         self.len == TERMINATED_SENTINEL_LENGTH
@@ -0,0 +1,117 @@
+use crate::stream::{StreamExt, Fuse};
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`interleave`](super::StreamExt::interleave) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Interleave<St1, St2> {
+    stream1: Fuse<St1>,
+    stream2: Fuse<St2>,
+    second_next: bool,
+}
+
+impl<St1: Unpin, St2: Unpin> Unpin for Interleave<St1, St2> {}
+
+impl<St1: Stream, St2: Stream> Interleave<St1, St2> {
+    unsafe_pinned!(stream1: Fuse<St1>);
+    unsafe_pinned!(stream2: Fuse<St2>);
+    unsafe_unpinned!(second_next: bool);
+
+    pub(super) fn new(stream1: St1, stream2: St2) -> Interleave<St1, St2> {
+        Interleave {
+            stream1: stream1.fuse(),
+            stream2: stream2.fuse(),
+            second_next: false,
+        }
+    }
+
+    /// Acquires a reference to the underlying streams that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> (&St1, &St2) {
+        (self.stream1.get_ref(), self.stream2.get_ref())
+    }
+
+    /// Acquires a mutable reference to the underlying streams that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> (&mut St1, &mut St2) {
+        (self.stream1.get_mut(), self.stream2.get_mut())
+    }
+
+    /// Acquires a pinned mutable reference to the underlying streams that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> (Pin<&'a mut St1>, Pin<&'a mut St2>)
+        where St1: Unpin, St2: Unpin,
+    {
+        let Self { stream1, stream2, .. } = self.get_mut();
+        (Pin::new(stream1.get_mut()), Pin::new(stream2.get_mut()))
+    }
+
+    /// Consumes this combinator, returning the underlying streams.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> (St1, St2) {
+        (self.stream1.into_inner(), self.stream2.into_inner())
+    }
+}
+
+impl<St1, St2> FusedStream for Interleave<St1, St2>
+    where St1: Stream, St2: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream1.is_terminated() && self.stream2.is_terminated()
+    }
+}
+
+impl<St1, St2> Stream for Interleave<St1, St2>
+    where St1: Stream,
+          St2: Stream<Item = St1::Item>
+{
+    type Item = St1::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<St1::Item>> {
+        let item = if self.second_next {
+            match ready!(self.as_mut().stream2().poll_next(cx)) {
+                Some(item) => Some(item),
+                // The second stream is done, drain the first one
+                None => ready!(self.as_mut().stream1().poll_next(cx)),
+            }
+        } else {
+            match ready!(self.as_mut().stream1().poll_next(cx)) {
+                Some(item) => Some(item),
+                // The first stream is done, drain the second one
+                None => ready!(self.as_mut().stream2().poll_next(cx)),
+            }
+        };
+
+        // Hand the turn over to the other stream. Once either stream is
+        // done, polling it again just falls through to the other one.
+        if item.is_some() {
+            *self.as_mut().second_next() = !self.second_next;
+        }
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower1, upper1) = self.stream1.size_hint();
+        let (lower2, upper2) = self.stream2.size_hint();
+        let lower = lower1.saturating_add(lower2);
+        let upper = match (upper1, upper2) {
+            (Some(x), Some(y)) => x.checked_add(y),
+            _ => None,
+        };
+        (lower, upper)
+    }
+}
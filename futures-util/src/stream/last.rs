@@ -0,0 +1,49 @@
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`last`](super::StreamExt::last) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Last<St: Stream> {
+    stream: St,
+    last: Option<St::Item>,
+    done: bool,
+}
+
+impl<St: Stream + Unpin> Unpin for Last<St> {}
+
+impl<St: Stream> Last<St> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(last: Option<St::Item>);
+    unsafe_unpinned!(done: bool);
+
+    pub(super) fn new(stream: St) -> Last<St> {
+        Last {
+            stream,
+            last: None,
+            done: false,
+        }
+    }
+}
+
+impl<St: Stream> FusedFuture for Last<St> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St: Stream> Future for Last<St> {
+    type Output = Option<St::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<St::Item>> {
+        assert!(!self.done, "Last polled after completion");
+        while let Some(item) = ready!(self.as_mut().stream().poll_next(cx)) {
+            *self.as_mut().last() = Some(item);
+        }
+        *self.as_mut().done() = true;
+        Poll::Ready(self.as_mut().last().take())
+    }
+}
@@ -0,0 +1,102 @@
+use crate::stream::{Fuse, StreamExt, MAX_ITEMS_PER_POLL};
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`latest`](super::StreamExt::latest) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct Latest<St> {
+    stream: Fuse<St>,
+    // Set once `None` has been yielded, which may happen a poll after the
+    // underlying stream ended, as its last item is yielded first.
+    terminated: bool,
+}
+
+impl<St: Unpin> Unpin for Latest<St> {}
+
+impl<St: Stream> Latest<St> {
+    unsafe_pinned!(stream: Fuse<St>);
+    unsafe_unpinned!(terminated: bool);
+
+    pub(super) fn new(stream: St) -> Latest<St> {
+        Latest { stream: stream.fuse(), terminated: false }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.stream.get_mut()
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut St> {
+        self.stream().get_pin_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    pub fn into_inner(self) -> St {
+        self.stream.into_inner()
+    }
+}
+
+impl<St: Stream> FusedStream for Latest<St> {
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+impl<St: Stream> Stream for Latest<St> {
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let mut latest = None;
+        for _ in 0..MAX_ITEMS_PER_POLL {
+            match self.as_mut().stream().poll_next(cx) {
+                Poll::Ready(Some(item)) => latest = Some(item),
+                Poll::Ready(None) | Poll::Pending => break,
+            }
+        }
+
+        match latest {
+            Some(item) => Poll::Ready(Some(item)),
+            None if self.stream.is_done() => {
+                *self.as_mut().terminated() = true;
+                Poll::Ready(None)
+            }
+            None => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        (if lower > 0 { 1 } else { 0 }, upper)
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, Item> Sink<Item> for Latest<S>
+where S: Stream + Sink<Item>,
+{
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream, Item);
+}
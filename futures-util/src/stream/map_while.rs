@@ -0,0 +1,144 @@
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`map_while`](super::StreamExt::map_while) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct MapWhile<St, Fut, F>
+    where St: Stream,
+          F: FnMut(St::Item) -> Fut,
+          Fut: Future,
+{
+    stream: St,
+    f: F,
+    pending: Option<Fut>,
+    done_mapping: bool,
+}
+
+impl<St, Fut, F> Unpin for MapWhile<St, Fut, F>
+    where St: Stream + Unpin,
+          F: FnMut(St::Item) -> Fut,
+          Fut: Future + Unpin,
+{}
+
+impl<St, Fut, F> MapWhile<St, Fut, F>
+    where St: Stream,
+          F: FnMut(St::Item) -> Fut,
+          Fut: Future,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_pinned!(pending: Option<Fut>);
+    unsafe_unpinned!(done_mapping: bool);
+
+    pub(super) fn new(stream: St, f: F) -> MapWhile<St, Fut, F> {
+        MapWhile { stream, f, pending: None, done_mapping: false }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut St> {
+        self.stream()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St, Fut, F, T> FusedStream for MapWhile<St, Fut, F>
+    where St: Stream,
+          F: FnMut(St::Item) -> Fut,
+          Fut: Future<Output = Option<T>>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done_mapping
+    }
+}
+
+impl<St, Fut, F, T> Stream for MapWhile<St, Fut, F>
+    where St: Stream,
+          F: FnMut(St::Item) -> Fut,
+          Fut: Future<Output = Option<T>>,
+{
+    type Item = T;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<T>> {
+        if self.done_mapping {
+            return Poll::Ready(None);
+        }
+
+        if self.pending.is_none() {
+            let item = match ready!(self.as_mut().stream().poll_next(cx)) {
+                Some(e) => e,
+                None => {
+                    *self.as_mut().done_mapping() = true;
+                    return Poll::Ready(None);
+                }
+            };
+            let fut = (self.as_mut().f())(item);
+            self.as_mut().pending().set(Some(fut));
+        }
+
+        let item = ready!(self.as_mut().pending().as_pin_mut().unwrap().poll(cx));
+        self.as_mut().pending().set(None);
+        if item.is_none() {
+            *self.as_mut().done_mapping() = true;
+        }
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done_mapping {
+            return (0, Some(0));
+        }
+
+        let pending_len = if self.pending.is_some() { 1 } else { 0 };
+        let (_, upper) = self.stream.size_hint();
+        let upper = match upper {
+            Some(x) => x.checked_add(pending_len),
+            None => None,
+        };
+        (0, upper) // can't know a lower bound, due to the closure
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, Fut, F, Item> Sink<Item> for MapWhile<S, Fut, F>
+    where S: Stream + Sink<Item>,
+          F: FnMut(S::Item) -> Fut,
+          Fut: Future,
+{
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream, Item);
+}
@@ -0,0 +1,164 @@
+use crate::stream::{StreamExt, Fuse};
+use core::cmp::Ordering;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`merge_sorted`](super::StreamExt::merge_sorted) method.
+#[must_use = "streams do nothing unless polled"]
+pub struct MergeSorted<St1: Stream, St2, F> {
+    stream1: Fuse<St1>,
+    stream2: Fuse<St2>,
+    head1: Option<St1::Item>,
+    head2: Option<St1::Item>,
+    cmp: F,
+}
+
+impl<St1: Stream + Unpin, St2: Unpin, F> Unpin for MergeSorted<St1, St2, F> {}
+
+impl<St1, St2, F> fmt::Debug for MergeSorted<St1, St2, F>
+where
+    St1: Stream + fmt::Debug,
+    St1::Item: fmt::Debug,
+    St2: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergeSorted")
+            .field("stream1", &self.stream1)
+            .field("stream2", &self.stream2)
+            .field("head1", &self.head1)
+            .field("head2", &self.head2)
+            .finish()
+    }
+}
+
+impl<St1, St2, F> MergeSorted<St1, St2, F>
+where
+    St1: Stream,
+    St2: Stream<Item = St1::Item>,
+    F: FnMut(&St1::Item, &St1::Item) -> Ordering,
+{
+    unsafe_pinned!(stream1: Fuse<St1>);
+    unsafe_pinned!(stream2: Fuse<St2>);
+    unsafe_unpinned!(head1: Option<St1::Item>);
+    unsafe_unpinned!(head2: Option<St1::Item>);
+    unsafe_unpinned!(cmp: F);
+
+    pub(super) fn new(stream1: St1, stream2: St2, cmp: F) -> MergeSorted<St1, St2, F> {
+        MergeSorted {
+            stream1: stream1.fuse(),
+            stream2: stream2.fuse(),
+            head1: None,
+            head2: None,
+            cmp,
+        }
+    }
+
+    /// Acquires a reference to the underlying streams that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> (&St1, &St2) {
+        (self.stream1.get_ref(), self.stream2.get_ref())
+    }
+
+    /// Acquires a mutable reference to the underlying streams that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> (&mut St1, &mut St2) {
+        (self.stream1.get_mut(), self.stream2.get_mut())
+    }
+
+    /// Acquires a pinned mutable reference to the underlying streams that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> (Pin<&'a mut St1>, Pin<&'a mut St2>)
+        where St1: Unpin, St2: Unpin,
+    {
+        let Self { stream1, stream2, .. } = self.get_mut();
+        (Pin::new(stream1.get_mut()), Pin::new(stream2.get_mut()))
+    }
+
+    /// Consumes this combinator, returning the underlying streams.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> (St1, St2) {
+        (self.stream1.into_inner(), self.stream2.into_inner())
+    }
+}
+
+impl<St1, St2, F> FusedStream for MergeSorted<St1, St2, F>
+where
+    St1: Stream,
+    St2: Stream<Item = St1::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream1.is_terminated() && self.stream2.is_terminated()
+            && self.head1.is_none() && self.head2.is_none()
+    }
+}
+
+impl<St1, St2, F> Stream for MergeSorted<St1, St2, F>
+where
+    St1: Stream,
+    St2: Stream<Item = St1::Item>,
+    F: FnMut(&St1::Item, &St1::Item) -> Ordering,
+{
+    type Item = St1::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<St1::Item>> {
+        // An item can only be yielded once the next item of both streams (or
+        // their end) is known, so poll both before deciding.
+        let mut pending = false;
+        if self.head1.is_none() && !self.stream1.is_terminated() {
+            match self.as_mut().stream1().poll_next(cx) {
+                Poll::Ready(item) => *self.as_mut().head1() = item,
+                Poll::Pending => pending = true,
+            }
+        }
+        if self.head2.is_none() && !self.stream2.is_terminated() {
+            match self.as_mut().stream2().poll_next(cx) {
+                Poll::Ready(item) => *self.as_mut().head2() = item,
+                Poll::Pending => pending = true,
+            }
+        }
+        if pending {
+            return Poll::Pending;
+        }
+
+        match (self.as_mut().head1().take(), self.as_mut().head2().take()) {
+            (Some(item1), Some(item2)) => {
+                // On ties, the first stream goes first.
+                if (self.as_mut().cmp())(&item1, &item2) != Ordering::Greater {
+                    *self.as_mut().head2() = Some(item2);
+                    Poll::Ready(Some(item1))
+                } else {
+                    *self.as_mut().head1() = Some(item1);
+                    Poll::Ready(Some(item2))
+                }
+            }
+            (Some(item1), None) => Poll::Ready(Some(item1)),
+            (None, item2) => Poll::Ready(item2),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let heads = self.head1.is_some() as usize + self.head2.is_some() as usize;
+        let (lower1, upper1) = self.stream1.size_hint();
+        let (lower2, upper2) = self.stream2.size_hint();
+        let lower = lower1.saturating_add(lower2).saturating_add(heads);
+        let upper = match (upper1, upper2) {
+            (Some(x), Some(y)) => x.checked_add(y).and_then(|x| x.checked_add(heads)),
+            _ => None,
+        };
+        (lower, upper)
+    }
+}
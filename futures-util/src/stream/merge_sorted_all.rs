@@ -0,0 +1,153 @@
+use crate::stream::{StreamExt, Fuse};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_utils::unsafe_unpinned;
+
+/// Stream for the [`merge_sorted_all`] function.
+#[must_use = "streams do nothing unless polled"]
+pub struct MergeSortedAll<St: Stream, F> {
+    // Each stream along with its next item, once it is known.
+    streams: Vec<(Fuse<St>, Option<St::Item>)>,
+    cmp: F,
+}
+
+impl<St: Stream + Unpin, F> Unpin for MergeSortedAll<St, F> {}
+
+impl<St, F> fmt::Debug for MergeSortedAll<St, F>
+where
+    St: Stream + fmt::Debug,
+    St::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergeSortedAll")
+            .field("streams", &self.streams)
+            .finish()
+    }
+}
+
+/// Merges a collection of streams that are each sorted according to `cmp`
+/// into a single sorted stream.
+///
+/// This is the k-way counterpart of
+/// [`merge_sorted`](super::StreamExt::merge_sorted). An item is only yielded
+/// once the next item of every stream that hasn't ended yet is known, and the
+/// smallest of them is yielded. Items comparing equal are yielded in the order
+/// of the streams they come from. Finding the smallest item takes time linear
+/// in the number of streams.
+///
+/// The streams need to be [`Unpin`]; pin them with
+/// [`Box::pin`](std::boxed::Box::pin) if they aren't.
+///
+/// This function is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await)]
+/// # futures::executor::block_on(async {
+/// use futures::stream::{self, StreamExt};
+///
+/// let segments = vec![
+///     stream::iter(vec![1, 4, 7]),
+///     stream::iter(vec![2, 5, 8]),
+///     stream::iter(vec![3, 6, 9]),
+/// ];
+/// let merged = stream::merge_sorted_all(segments, |a: &i32, b: &i32| a.cmp(b));
+///
+/// assert_eq!(merged.collect::<Vec<_>>().await, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// # });
+/// ```
+pub fn merge_sorted_all<I, F>(streams: I, cmp: F) -> MergeSortedAll<I::Item, F>
+where
+    I: IntoIterator,
+    I::Item: Stream + Unpin,
+    F: FnMut(&<I::Item as Stream>::Item, &<I::Item as Stream>::Item) -> Ordering,
+{
+    MergeSortedAll {
+        streams: streams.into_iter().map(|stream| (stream.fuse(), None)).collect(),
+        cmp,
+    }
+}
+
+impl<St: Stream, F> MergeSortedAll<St, F> {
+    unsafe_unpinned!(streams: Vec<(Fuse<St>, Option<St::Item>)>);
+    unsafe_unpinned!(cmp: F);
+}
+
+impl<St: Stream, F> FusedStream for MergeSortedAll<St, F> {
+    fn is_terminated(&self) -> bool {
+        self.streams.iter().all(|(stream, head)| head.is_none() && stream.is_terminated())
+    }
+}
+
+impl<St, F> Stream for MergeSortedAll<St, F>
+where
+    St: Stream + Unpin,
+    F: FnMut(&St::Item, &St::Item) -> Ordering,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<St::Item>> {
+        let mut pending = false;
+        for (stream, head) in self.as_mut().streams() {
+            if head.is_none() && !stream.is_terminated() {
+                match stream.poll_next_unpin(cx) {
+                    Poll::Ready(item) => *head = item,
+                    Poll::Pending => pending = true,
+                }
+            }
+        }
+        // Streams that have ended can't contribute anything anymore.
+        self.as_mut().streams().retain(|(stream, head)| head.is_some() || !stream.is_terminated());
+        if pending {
+            return Poll::Pending;
+        }
+
+        // The smallest head so far is taken out of its slot while comparing,
+        // and put back as soon as a smaller one turns up.
+        let mut min: Option<(usize, St::Item)> = None;
+        for i in 0..self.streams.len() {
+            let item = match self.as_mut().streams()[i].1.take() {
+                Some(item) => item,
+                None => continue,
+            };
+            min = match min {
+                None => Some((i, item)),
+                Some((j, min_item)) => {
+                    if (self.as_mut().cmp())(&item, &min_item) == Ordering::Less {
+                        self.as_mut().streams()[j].1 = Some(min_item);
+                        Some((i, item))
+                    } else {
+                        self.as_mut().streams()[i].1 = Some(item);
+                        Some((j, min_item))
+                    }
+                }
+            };
+        }
+
+        Poll::Ready(min.map(|(_, item)| item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let mut lower = 0usize;
+        let mut upper = Some(0usize);
+        for (stream, head) in &self.streams {
+            let heads = head.is_some() as usize;
+            let (l, u) = stream.size_hint();
+            lower = lower.saturating_add(l).saturating_add(heads);
+            upper = match (upper, u) {
+                (Some(x), Some(y)) => x.checked_add(y).and_then(|x| x.checked_add(heads)),
+                _ => None,
+            };
+        }
+        (lower, upper)
+    }
+}
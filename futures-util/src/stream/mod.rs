@@ -6,10 +6,14 @@
 use core::pin::Pin;
 use futures_core::future::Future;
 use futures_core::stream::{FusedStream, Stream, TryStream};
+#[cfg(feature = "alloc")]
+use futures_core::stream::{BoxStream, LocalBoxStream, SyncBoxStream};
 use futures_core::task::{Context, Poll};
 use futures_sink::Sink;
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use crate::future::Either;
 
 mod iter;
@@ -18,6 +22,9 @@ pub use self::iter::{iter, Iter};
 mod repeat;
 pub use self::repeat::{repeat, Repeat};
 
+mod repeat_with;
+pub use self::repeat_with::{repeat_with, RepeatWith};
+
 mod chain;
 pub use self::chain::Chain;
 
@@ -27,6 +34,12 @@ pub use self::collect::Collect;
 mod concat;
 pub use self::concat::Concat;
 
+mod dedup;
+pub use self::dedup::Dedup;
+
+mod dedup_by_key;
+pub use self::dedup_by_key::DedupByKey;
+
 mod empty;
 pub use self::empty::{empty, Empty};
 
@@ -51,9 +64,30 @@ pub use self::forward::Forward;
 mod for_each;
 pub use self::for_each::ForEach;
 
+mod any;
+pub use self::any::Any;
+
+mod all;
+pub use self::all::All;
+
+mod find;
+pub use self::find::Find;
+
+mod position;
+pub use self::position::Position;
+
+mod last;
+pub use self::last::Last;
+
+mod nth;
+pub use self::nth::Nth;
+
 mod fuse;
 pub use self::fuse::Fuse;
 
+mod interleave;
+pub use self::interleave::Interleave;
+
 mod into_future;
 pub use self::into_future::StreamFuture;
 
@@ -61,12 +95,24 @@ mod inspect;
 pub(crate) use self::inspect::inspect; // used by `TryStreamExt::{inspect_ok, inspect_err}`
 pub use self::inspect::Inspect;
 
+mod latest;
+pub use self::latest::Latest;
+
 mod map;
 pub use self::map::Map;
 
+mod map_while;
+pub use self::map_while::MapWhile;
+
+mod merge_sorted;
+pub use self::merge_sorted::MergeSorted;
+
 mod next;
 pub use self::next::Next;
 
+mod next_pinned;
+pub use self::next_pinned::NextPinned;
+
 mod select_next_some;
 pub use self::select_next_some::SelectNextSome;
 
@@ -88,11 +134,14 @@ pub use self::skip::Skip;
 mod skip_while;
 pub use self::skip_while::SkipWhile;
 
+mod switch_map;
+pub use self::switch_map::SwitchMap;
+
 mod take;
 pub use self::take::Take;
 
 mod take_while;
-pub use self::take_while::TakeWhile;
+pub use self::take_while::{TakeWhile, TakeWhileInclusive};
 
 mod then;
 pub use self::then::Then;
@@ -100,14 +149,50 @@ pub use self::then::Then;
 mod unfold;
 pub use self::unfold::{unfold, Unfold};
 
+mod unzip;
+pub use self::unzip::Unzip;
+
 mod zip;
 pub use self::zip::Zip;
 
+mod zip_longest;
+pub use self::zip_longest::{EitherOrBoth, ZipLongest};
+
+// The most items an adapter that only keeps the latest item pulls from a
+// stream in a single poll, so that a stream which is always ready can't keep
+// it from ever returning.
+pub(crate) const MAX_ITEMS_PER_POLL: usize = 1024; // used by `latest` and `switch_map`
+
+#[cfg(feature = "alloc")]
+mod chunk_buf;
+#[cfg(feature = "alloc")]
+pub(crate) use self::chunk_buf::ChunkBuf; // used by `TryStreamExt::{try_chunks, try_ready_chunks}`
+
 #[cfg(feature = "alloc")]
 mod chunks;
 #[cfg(feature = "alloc")]
 pub use self::chunks::Chunks;
 
+#[cfg(feature = "alloc")]
+mod next_many;
+#[cfg(feature = "alloc")]
+pub use self::next_many::NextMany;
+
+#[cfg(feature = "alloc")]
+mod chunks_by_size;
+#[cfg(feature = "alloc")]
+pub use self::chunks_by_size::ChunksBySize;
+
+#[cfg(feature = "alloc")]
+mod armed_prefetch;
+#[cfg(feature = "alloc")]
+pub use self::armed_prefetch::ArmedPrefetch;
+
+#[cfg(feature = "alloc")]
+mod merge_sorted_all;
+#[cfg(feature = "alloc")]
+pub use self::merge_sorted_all::{merge_sorted_all, MergeSortedAll};
+
 cfg_target_has_atomic! {
     #[cfg(feature = "alloc")]
     mod buffer_unordered;
@@ -124,6 +209,16 @@ cfg_target_has_atomic! {
     #[cfg(feature = "alloc")]
     pub use self::for_each_concurrent::ForEachConcurrent;
 
+    #[cfg(feature = "alloc")]
+    mod then_concurrent;
+    #[cfg(feature = "alloc")]
+    pub use self::then_concurrent::ThenConcurrent;
+
+    #[cfg(feature = "alloc")]
+    mod tap_each_concurrent;
+    #[cfg(feature = "alloc")]
+    pub use self::tap_each_concurrent::TapEachConcurrent;
+
     #[cfg(feature = "alloc")]
     mod futures_ordered;
     #[cfg(feature = "alloc")]
@@ -144,6 +239,8 @@ cfg_target_has_atomic! {
     mod select_all;
     #[cfg(feature = "alloc")]
     pub use self::select_all::{select_all, SelectAll};
+    #[cfg(feature = "std")]
+    pub use self::select_all::OnEmpty;
 }
 
 #[cfg(feature = "std")]
@@ -151,6 +248,11 @@ mod catch_unwind;
 #[cfg(feature = "std")]
 pub use self::catch_unwind::CatchUnwind;
 
+#[cfg(feature = "std")]
+mod shared;
+#[cfg(feature = "std")]
+pub use self::shared::Shared;
+
 impl<T: ?Sized> StreamExt for T where T: Stream {}
 
 /// An extension trait for `Stream`s that provides a variety of convenient
@@ -163,7 +265,8 @@ pub trait StreamExt: Stream {
     /// [`!Unpin`](Unpin) stream, you'll first have to pin the stream. This can
     /// be done by boxing the stream using [`Box::pin`] or
     /// pinning it to the stack using the `pin_mut!` macro from the `pin_utils`
-    /// crate.
+    /// crate. A stream which is already pinned can also use
+    /// [`next_pinned`](StreamExt::next_pinned) instead.
     ///
     /// # Examples
     ///
@@ -186,6 +289,81 @@ pub trait StreamExt: Stream {
         Next::new(self)
     }
 
+    /// Creates a future that resolves to the next item in a pinned stream.
+    ///
+    /// This is like [`next`](StreamExt::next), but it borrows an already
+    /// pinned stream instead of requiring the stream to be [`Unpin`]. This is
+    /// useful for [`!Unpin`](Unpin) streams such as those stored in a pinned
+    /// field of a struct, or pinned to the stack with `pin_mut!`, without
+    /// having to box them. Use [`Pin::as_mut`] to call this repeatedly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    /// use pin_utils::pin_mut;
+    ///
+    /// // `then` with an `async` closure produces a `!Unpin` stream.
+    /// let stream = stream::iter(1..=2).then(|x| async move { x * 10 });
+    /// pin_mut!(stream);
+    ///
+    /// assert_eq!(stream.as_mut().next_pinned().await, Some(10));
+    /// assert_eq!(stream.as_mut().next_pinned().await, Some(20));
+    /// assert_eq!(stream.as_mut().next_pinned().await, None);
+    /// # });
+    /// ```
+    fn next_pinned(self: Pin<&mut Self>) -> NextPinned<'_, Self> {
+        NextPinned::new(self)
+    }
+
+    /// Creates a future that resolves once this stream has yielded at least
+    /// one item, after appending all the items it has ready, up to `limit`,
+    /// to `buf`.
+    ///
+    /// The future resolves to the number of items appended. It only
+    /// resolves to `0` once the stream has ended: while the stream has no
+    /// items ready it stays pending. Pulling all the ready items at once
+    /// saves creating and polling a future per item, for example when
+    /// draining a channel into a write buffer. See
+    /// [`poll_next_batch`](StreamExt::poll_next_batch) for the underlying
+    /// poll method, and for why streams which can't be polled after they
+    /// have ended need to be [fused](StreamExt::fuse).
+    ///
+    /// Note that this function consumes the stream passed into it only by
+    /// reference.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `limit` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut stream = stream::iter(1..=5);
+    /// let mut buf = Vec::new();
+    ///
+    /// assert_eq!(stream.next_many(&mut buf, 3).await, 3);
+    /// assert_eq!(stream.next_many(&mut buf, 3).await, 2);
+    /// assert_eq!(stream.next_many(&mut buf, 3).await, 0);
+    /// assert_eq!(buf, vec![1, 2, 3, 4, 5]);
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn next_many<'a>(&'a mut self, buf: &'a mut Vec<Self::Item>, limit: usize) -> NextMany<'a, Self>
+        where Self: Unpin,
+    {
+        NextMany::new(self, buf, limit)
+    }
+
     /// Converts this stream into a future of `(next_item, tail_of_stream)`.
     /// If the stream terminates, then the next item is [`None`].
     ///
@@ -368,6 +546,95 @@ pub trait StreamExt: Stream {
         FilterMap::new(self, f)
     }
 
+    /// Maps the values of this stream through the provided asynchronous
+    /// closure until it first resolves to [`None`].
+    ///
+    /// This is the asynchronous counterpart of
+    /// [`Iterator::map_while`](core::iter::Iterator::map_while). As long as
+    /// the future returned by `f` resolves to [`Some(item)`](Some), the
+    /// returned stream yields `item`. The first time it resolves to [`None`]
+    /// the returned stream ends, and the underlying stream is not polled
+    /// again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec!["1", "2", "three", "4"]);
+    /// let numbers = stream.map_while(|s| future::ready(s.parse::<u32>().ok()));
+    ///
+    /// assert_eq!(vec![1, 2], numbers.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn map_while<Fut, T, F>(self, f: F) -> MapWhile<Self, Fut, F>
+        where F: FnMut(Self::Item) -> Fut,
+              Fut: Future<Output = Option<T>>,
+              Self: Sized,
+    {
+        MapWhile::new(self, f)
+    }
+
+    /// Removes consecutive repeated items from this stream.
+    ///
+    /// An item is dropped if it is equal to the item yielded right before it,
+    /// so the first item always passes through. Only the last yielded item
+    /// is kept around for the comparison, which is why the items need to be
+    /// `Clone`. Use [`dedup_by_key`](StreamExt::dedup_by_key) to compare a
+    /// key derived from the items instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![1, 1, 2, 3, 3, 3, 1]);
+    /// let stream = stream.dedup();
+    ///
+    /// assert_eq!(vec![1, 2, 3, 1], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn dedup(self) -> Dedup<Self>
+        where Self::Item: PartialEq + Clone,
+              Self: Sized,
+    {
+        Dedup::new(self)
+    }
+
+    /// Removes consecutive items from this stream that map to the same key.
+    ///
+    /// The closure `f` is called with each item to compute its key. An item
+    /// is dropped if its key is equal to the key of the item yielded right
+    /// before it, so the first item always passes through. Only the last key
+    /// is kept around for the comparison, so the items themselves do not need
+    /// to be `Clone`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![10, 20, 21, 30, 11]);
+    /// let stream = stream.dedup_by_key(|x| x / 10);
+    ///
+    /// assert_eq!(vec![10, 20, 30, 11], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn dedup_by_key<K, F>(self, f: F) -> DedupByKey<Self, F, K>
+        where F: FnMut(&Self::Item) -> K,
+              K: PartialEq,
+              Self: Sized,
+    {
+        DedupByKey::new(self, f)
+    }
+
     /// Computes from this stream's items new items of a different type using
     /// an asynchronous closure.
     ///
@@ -433,6 +700,43 @@ pub trait StreamExt: Stream {
         Collect::new(self)
     }
 
+    /// Converts a stream of pairs into a future, which resolves to a pair of
+    /// collections.
+    ///
+    /// `unzip` produces a future which resolves to two collections: one built
+    /// from the left elements of the pairs, and one from the right elements.
+    /// Both collections are extended as items arrive, so no pairs are
+    /// buffered in between.
+    ///
+    /// The returned future will be resolved when the stream terminates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    /// use std::collections::HashMap;
+    ///
+    /// let stream = stream::iter(vec![(1, 'a'), (2, 'b'), (3, 'c')]);
+    /// let (numbers, letters): (Vec<_>, String) = stream.unzip().await;
+    /// assert_eq!(numbers, vec![1, 2, 3]);
+    /// assert_eq!(letters, "abc");
+    ///
+    /// let stream = stream::iter(vec![(("one", 1), true), (("two", 2), false)]);
+    /// let (map, flags): (HashMap<_, _>, Vec<_>) = stream.unzip().await;
+    /// assert_eq!(map["two"], 2);
+    /// assert_eq!(flags, vec![true, false]);
+    /// # });
+    /// ```
+    fn unzip<A, B, FromA, FromB>(self) -> Unzip<Self, FromA, FromB>
+        where FromA: Default + Extend<A>,
+              FromB: Default + Extend<B>,
+              Self: Sized + Stream<Item = (A, B)>,
+    {
+        Unzip::new(self)
+    }
+
     /// Concatenate all items of a stream into a single extendable
     /// destination, returning a future representing the end result.
     ///
@@ -545,6 +849,87 @@ pub trait StreamExt: Stream {
         Flatten::new(self)
     }
 
+    /// Skips over stale items when the consumer falls behind, so that only the
+    /// most recent item of this stream is yielded.
+    ///
+    /// Every time the returned stream is polled, it pulls all the items this
+    /// stream has ready, discards all but the last one and yields that. If
+    /// this stream has no items ready, the returned stream is pending. This
+    /// suits streams of state updates, such as telemetry or UI state, where
+    /// intermediate values aren't worth queueing up for a slow consumer.
+    ///
+    /// To keep a stream which is always ready from stalling the task, at
+    /// most 1024 items are pulled in a single poll.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::stream::StreamExt;
+    ///
+    /// let (tx, rx) = mpsc::unbounded();
+    /// let mut stream = rx.latest();
+    ///
+    /// tx.unbounded_send(1).unwrap();
+    /// tx.unbounded_send(2).unwrap();
+    /// tx.unbounded_send(3).unwrap();
+    /// assert_eq!(stream.next().await, Some(3));
+    ///
+    /// tx.unbounded_send(4).unwrap();
+    /// drop(tx);
+    /// assert_eq!(stream.next().await, Some(4));
+    /// assert_eq!(stream.next().await, None);
+    /// # });
+    /// ```
+    fn latest(self) -> Latest<Self>
+        where Self: Sized
+    {
+        Latest::new(self)
+    }
+
+    /// Maps each item of this stream to a stream, and yields the items of
+    /// the most recent of those streams.
+    ///
+    /// Whenever this stream yields an item, the inner stream created for the
+    /// previous item is dropped immediately and replaced by the stream that
+    /// `f` returns for the new item, even if the previous stream had more
+    /// items ready. This is useful when only the latest request matters,
+    /// such as a search query which is superseded as the user types.
+    ///
+    /// This stream is checked for new items every time the returned stream
+    /// is polled, before the current inner stream. The returned stream ends
+    /// once this stream and the last inner stream have both ended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::mpsc;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let (tx, rx) = mpsc::unbounded();
+    /// let mut stream = rx.switch_map(|n| stream::iter(vec![n; 3]));
+    ///
+    /// tx.unbounded_send(1).unwrap();
+    /// assert_eq!(stream.next().await, Some(1));
+    ///
+    /// // The stream for `1` is dropped as soon as `2` arrives.
+    /// tx.unbounded_send(2).unwrap();
+    /// drop(tx);
+    /// assert_eq!(stream.collect::<Vec<_>>().await, vec![2, 2, 2]);
+    /// # });
+    /// ```
+    fn switch_map<U, F>(self, f: F) -> SwitchMap<Self, U, F>
+        where F: FnMut(Self::Item) -> U,
+              U: Stream,
+              Self: Sized
+    {
+        SwitchMap::new(self, f)
+    }
+
     /// Skip elements on this stream while the provided asynchronous predicate
     /// resolves to `true`.
     ///
@@ -606,6 +991,40 @@ pub trait StreamExt: Stream {
         TakeWhile::new(self, f)
     }
 
+    /// Take elements from this stream while the provided asynchronous predicate
+    /// resolves to `true`, and also take the first element for which it
+    /// resolves to `false`.
+    ///
+    /// This is like [`take_while`](StreamExt::take_while), except that the
+    /// element which ends the returned stream is yielded as its last element
+    /// rather than dropped. This suits framing where the terminating element
+    /// belongs to the frame, such as "everything up to and including the end
+    /// marker". Once that element has been yielded, the underlying stream is
+    /// not polled again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec!["a", "b", "END", "c"]);
+    ///
+    /// let stream = stream.take_while_inclusive(|x| future::ready(*x != "END"));
+    ///
+    /// assert_eq!(vec!["a", "b", "END"], stream.collect::<Vec<_>>().await);
+    /// # });
+    /// ```
+    fn take_while_inclusive<Fut, F>(self, f: F) -> TakeWhileInclusive<Self, Fut, F>
+        where F: FnMut(&Self::Item) -> Fut,
+              Fut: Future<Output = bool>,
+              Self: Sized
+    {
+        TakeWhileInclusive::new(self, f)
+    }
+
     /// Runs this stream to completion, executing the provided asynchronous
     /// closure for each element on the stream.
     ///
@@ -648,42 +1067,231 @@ pub trait StreamExt: Stream {
         ForEach::new(self, f)
     }
 
-    /// Runs this stream to completion, executing the provided asynchronous
-    /// closure for each element on the stream concurrently as elements become
-    /// available.
+    /// Execute a predicate over the items of this stream, resolving to `true`
+    /// as soon as the predicate holds for any item.
     ///
-    /// This is similar to [`StreamExt::for_each`], but the futures
-    /// produced by the closure are run concurrently (but not in parallel--
-    /// this combinator does not introduce any threads).
+    /// The predicate produces a future for each item, which is polled to
+    /// completion before the stream is polled again. Once a predicate future
+    /// resolves to `true`, the returned future resolves to `true` without
+    /// polling the stream any further. If the stream ends first, which also
+    /// covers an empty stream, it resolves to `false`, just like
+    /// [`Iterator::any`].
     ///
-    /// The closure provided will be called for each item this stream produces,
-    /// yielding a future. That future will then be executed to completion
-    /// concurrently with the other futures produced by the closure.
+    /// # Examples
     ///
-    /// The first argument is an optional limit on the number of concurrent
-    /// futures. If this limit is not `None`, no more than `limit` futures
-    /// will be run concurrently. The `limit` argument is of type
-    /// `Into<Option<usize>>`, and so can be provided as either `None`,
-    /// `Some(10)`, or just `10`. Note: a limit of zero is interpreted as
-    /// no limit at all, and will have the same result as passing in `None`.
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
     ///
-    /// This method is only available when the `std` or `alloc` feature of this
-    /// library is activated, and it is activated by default.
+    /// let numbers = vec![1, 3, 6, 7];
+    /// let even = stream::iter(numbers.clone()).any(|x| future::ready(x % 2 == 0));
+    /// assert_eq!(even.await, numbers.iter().any(|x| x % 2 == 0));
+    ///
+    /// let empty = stream::empty::<i32>().any(|_| future::ready(true));
+    /// assert_eq!(empty.await, false);
+    /// # });
+    /// ```
+    fn any<Fut, F>(self, f: F) -> Any<Self, Fut, F>
+        where F: FnMut(Self::Item) -> Fut,
+              Fut: Future<Output = bool>,
+              Self: Sized
+    {
+        Any::new(self, f)
+    }
+
+    /// Execute a predicate over the items of this stream, resolving to `false`
+    /// as soon as the predicate fails for any item.
+    ///
+    /// The predicate produces a future for each item, which is polled to
+    /// completion before the stream is polled again. Once a predicate future
+    /// resolves to `false`, the returned future resolves to `false` without
+    /// polling the stream any further. If the stream ends first, which also
+    /// covers an empty stream, it resolves to `true`, just like
+    /// [`Iterator::all`].
     ///
     /// # Examples
     ///
     /// ```
     /// #![feature(async_await)]
     /// # futures::executor::block_on(async {
-    /// use futures::channel::oneshot;
+    /// use futures::future;
     /// use futures::stream::{self, StreamExt};
     ///
-    /// let (tx1, rx1) = oneshot::channel();
-    /// let (tx2, rx2) = oneshot::channel();
-    /// let (tx3, rx3) = oneshot::channel();
+    /// let numbers = vec![2, 4, 5, 8];
+    /// let even = stream::iter(numbers.clone()).all(|x| future::ready(x % 2 == 0));
+    /// assert_eq!(even.await, numbers.iter().all(|x| x % 2 == 0));
     ///
-    /// let fut = stream::iter(vec![rx1, rx2, rx3]).for_each_concurrent(
-    ///     /* limit */ 2,
+    /// let empty = stream::empty::<i32>().all(|_| future::ready(false));
+    /// assert_eq!(empty.await, true);
+    /// # });
+    /// ```
+    fn all<Fut, F>(self, f: F) -> All<Self, Fut, F>
+        where F: FnMut(Self::Item) -> Fut,
+              Fut: Future<Output = bool>,
+              Self: Sized
+    {
+        All::new(self, f)
+    }
+
+    /// Searches this stream for the first item satisfying a predicate.
+    ///
+    /// The predicate is given a reference to each item and produces a future,
+    /// which is polled to completion before the stream is polled again. The
+    /// returned future resolves to the first item for which the predicate
+    /// resolves to `true`, without polling the stream any further, or to
+    /// `None` if the stream ends without a match, just like
+    /// [`Iterator::find`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![1, 4, 6, 7]);
+    /// let even = stream.find(|x| future::ready(x % 2 == 0));
+    /// assert_eq!(even.await, Some(4));
+    ///
+    /// let stream = stream::iter(vec![1, 3]);
+    /// let even = stream.find(|x| future::ready(x % 2 == 0));
+    /// assert_eq!(even.await, None);
+    /// # });
+    /// ```
+    fn find<Fut, F>(self, f: F) -> Find<Self, Fut, F>
+        where F: FnMut(&Self::Item) -> Fut,
+              Fut: Future<Output = bool>,
+              Self: Sized
+    {
+        Find::new(self, f)
+    }
+
+    /// Searches this stream for the first item satisfying a predicate,
+    /// returning its index.
+    ///
+    /// The predicate produces a future for each item, which is polled to
+    /// completion before the stream is polled again. The returned future
+    /// resolves to the index of the first item for which the predicate
+    /// resolves to `true`, without polling the stream any further, or to
+    /// `None` if the stream ends without a match, just like
+    /// [`Iterator::position`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![1, 4, 6, 7]);
+    /// let even = stream.position(|x| future::ready(x % 2 == 0));
+    /// assert_eq!(even.await, Some(1));
+    ///
+    /// let stream = stream::iter(vec![1, 3]);
+    /// let even = stream.position(|x| future::ready(x % 2 == 0));
+    /// assert_eq!(even.await, None);
+    /// # });
+    /// ```
+    fn position<Fut, F>(self, f: F) -> Position<Self, Fut, F>
+        where F: FnMut(Self::Item) -> Fut,
+              Fut: Future<Output = bool>,
+              Self: Sized
+    {
+        Position::new(self, f)
+    }
+
+    /// Runs this stream to completion, returning its last item.
+    ///
+    /// The returned future resolves to the last item the stream yielded, or
+    /// to `None` if the stream ended without yielding any, just like
+    /// [`Iterator::last`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec![1, 2, 3]);
+    /// assert_eq!(stream.last().await, Some(3));
+    ///
+    /// let stream = stream::iter(Vec::<i32>::new());
+    /// assert_eq!(stream.last().await, None);
+    /// # });
+    /// ```
+    fn last(self) -> Last<Self>
+        where Self: Sized
+    {
+        Last::new(self)
+    }
+
+    /// Returns the `n`th item of this stream, counting from zero.
+    ///
+    /// The returned future skips `n` items and resolves to the next one,
+    /// without polling the stream any further, or to `None` if the stream
+    /// ends first, just like [`Iterator::nth`]. Use
+    /// [`by_ref`](StreamExt::by_ref) to keep using the stream afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut stream = stream::iter(vec![1, 2, 3, 4]);
+    /// assert_eq!(stream.by_ref().nth(1).await, Some(2));
+    /// assert_eq!(stream.next().await, Some(3));
+    /// assert_eq!(stream.nth(1).await, None);
+    /// # });
+    /// ```
+    fn nth(self, n: usize) -> Nth<Self>
+        where Self: Sized
+    {
+        Nth::new(self, n)
+    }
+
+    /// Runs this stream to completion, executing the provided asynchronous
+    /// closure for each element on the stream concurrently as elements become
+    /// available.
+    ///
+    /// This is similar to [`StreamExt::for_each`], but the futures
+    /// produced by the closure are run concurrently (but not in parallel--
+    /// this combinator does not introduce any threads).
+    ///
+    /// The closure provided will be called for each item this stream produces,
+    /// yielding a future. That future will then be executed to completion
+    /// concurrently with the other futures produced by the closure.
+    ///
+    /// The first argument is an optional limit on the number of concurrent
+    /// futures. If this limit is not `None`, no more than `limit` futures
+    /// will be run concurrently. The `limit` argument is of type
+    /// `Into<Option<usize>>`, and so can be provided as either `None`,
+    /// `Some(10)`, or just `10`. Note: a limit of zero is interpreted as
+    /// no limit at all, and will have the same result as passing in `None`.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::oneshot;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let (tx1, rx1) = oneshot::channel();
+    /// let (tx2, rx2) = oneshot::channel();
+    /// let (tx3, rx3) = oneshot::channel();
+    ///
+    /// let fut = stream::iter(vec![rx1, rx2, rx3]).for_each_concurrent(
+    ///     /* limit */ 2,
     ///     async move |rx| {
     ///         rx.await.unwrap();
     ///     }
@@ -711,6 +1319,131 @@ pub trait StreamExt: Stream {
         ForEachConcurrent::new(self, limit.into(), f)
     }
 
+    /// Computes from this stream's items new items of a different type using
+    /// an asynchronous closure, running the resulting futures concurrently.
+    ///
+    /// This is similar to [`StreamExt::then`], but instead of waiting for
+    /// each future to complete before pulling the next item, up to `limit`
+    /// futures are run concurrently (but not in parallel-- this combinator
+    /// does not introduce any threads). The returned stream yields the
+    /// outputs of the futures in the order in which they complete.
+    ///
+    /// The first argument is an optional limit on the number of concurrent
+    /// futures. If this limit is not `None`, no more than `limit` futures
+    /// will be run concurrently. The `limit` argument is of type
+    /// `Into<Option<usize>>`, and so can be provided as either `None`,
+    /// `Some(10)`, or just `10`. Note: a limit of zero is interpreted as
+    /// no limit at all, and will have the same result as passing in `None`.
+    ///
+    /// The returned stream ends once this stream has ended and all of the
+    /// futures produced by the closure have completed.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::channel::oneshot;
+    /// use futures::future::FutureExt;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let (tx1, rx1) = oneshot::channel();
+    /// let (tx2, rx2) = oneshot::channel();
+    ///
+    /// let mut stream = stream::iter(vec![rx1, rx2]).then_concurrent(
+    ///     /* limit */ None,
+    ///     |rx| rx.map(|res| res.unwrap() * 2),
+    /// );
+    ///
+    /// tx2.send(2).unwrap();
+    /// assert_eq!(stream.next().await, Some(4));
+    /// assert_eq!(stream.in_flight(), 1);
+    ///
+    /// tx1.send(1).unwrap();
+    /// assert_eq!(stream.next().await, Some(2));
+    /// assert_eq!(stream.next().await, None);
+    /// # })
+    /// ```
+    #[cfg_attr(
+        feature = "cfg-target-has-atomic",
+        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+    )]
+    #[cfg(feature = "alloc")]
+    fn then_concurrent<Fut, F>(
+        self,
+        limit: impl Into<Option<usize>>,
+        f: F,
+    ) -> ThenConcurrent<Self, Fut, F>
+        where F: FnMut(Self::Item) -> Fut,
+              Fut: Future,
+              Self: Sized,
+    {
+        ThenConcurrent::new(self, limit.into(), f)
+    }
+
+    /// Runs an asynchronous side effect for each item of this stream,
+    /// concurrently, and passes the items through unchanged.
+    ///
+    /// The closure is called with a reference to each item and returns a
+    /// future which is run alongside the futures for other items. Each item
+    /// is yielded by the returned stream once its future has completed, and
+    /// items are always yielded in the order this stream produced them. This
+    /// makes it possible to add asynchronous logging or auditing to the
+    /// middle of a pipeline, unlike
+    /// [`for_each_concurrent`](StreamExt::for_each_concurrent) which
+    /// consumes the stream.
+    ///
+    /// The first argument is an optional limit on the number of items held
+    /// at once, including items whose future has completed but which wait
+    /// for an earlier item to be yielded. The `limit` argument is of type
+    /// `Into<Option<usize>>`, and so can be provided as either `None`,
+    /// `Some(10)`, or just `10`. Note: a limit of zero is interpreted as
+    /// no limit at all, and will have the same result as passing in `None`.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut seen = Vec::new();
+    /// let stream = stream::iter(1..=3).tap_each_concurrent(
+    ///     /* limit */ 2,
+    ///     |x| {
+    ///         seen.push(*x);
+    ///         future::ready(())
+    ///     },
+    /// );
+    ///
+    /// assert_eq!(stream.collect::<Vec<_>>().await, vec![1, 2, 3]);
+    /// assert_eq!(seen, vec![1, 2, 3]);
+    /// # })
+    /// ```
+    #[cfg_attr(
+        feature = "cfg-target-has-atomic",
+        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+    )]
+    #[cfg(feature = "alloc")]
+    fn tap_each_concurrent<Fut, F>(
+        self,
+        limit: impl Into<Option<usize>>,
+        f: F,
+    ) -> TapEachConcurrent<Self, Fut, F>
+        where F: FnMut(&Self::Item) -> Fut,
+              Fut: Future<Output = ()>,
+              Self: Sized,
+    {
+        TapEachConcurrent::new(self, limit.into(), f)
+    }
+
     /// Creates a new stream of at most `n` items of the underlying stream.
     ///
     /// Once `n` items have been yielded from this stream then it will always
@@ -882,13 +1615,118 @@ pub trait StreamExt: Stream {
         CatchUnwind::new(self)
     }
 
+    /// Create a cloneable handle to this stream so that multiple consumers
+    /// can read every item from it.
+    ///
+    /// Each handle yields clones of the items the stream produces after the
+    /// handle was created, so this stream's item type must implement
+    /// [`Clone`]. Only a single item is buffered: the stream isn't polled for
+    /// its next item until every live handle has taken the current one, so
+    /// the slowest consumer applies backpressure to the others. Dropping a
+    /// handle never holds up the remaining ones, and handles created after
+    /// the stream has terminated yield `None` right away.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let shared1 = stream::iter(1..=3).share();
+    /// let shared2 = shared1.clone();
+    ///
+    /// let (items1, items2) = future::join(
+    ///     shared1.collect::<Vec<_>>(),
+    ///     shared2.collect::<Vec<_>>(),
+    /// ).await;
+    /// assert_eq!(items1, vec![1, 2, 3]);
+    /// assert_eq!(items2, vec![1, 2, 3]);
+    /// # });
+    /// ```
+    #[cfg(feature = "std")]
+    fn share(self) -> Shared<Self>
+        where Self: Sized,
+              Self::Item: Clone,
+    {
+        Shared::new(self)
+    }
+
     /// Wrap the stream in a Box, pinning it.
     ///
+    /// The returned [`BoxStream`](futures_core::stream::BoxStream) is a trait
+    /// object, which makes it convenient to return streams of different types
+    /// from the same function. The lifetime is generic, so streams that
+    /// borrow data can be boxed too.
+    ///
     /// This method is only available when the `std` or `alloc` feature of this
     /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, BoxStream, StreamExt};
+    ///
+    /// fn numbers<'a>(v: &'a [i32], reverse: bool) -> BoxStream<'a, &'a i32> {
+    ///     if reverse {
+    ///         stream::iter(v.iter().rev()).boxed()
+    ///     } else {
+    ///         stream::iter(v).boxed()
+    ///     }
+    /// }
+    ///
+    /// let v = vec![1, 2, 3];
+    /// assert_eq!(numbers(&v, true).collect::<Vec<&i32>>().await, vec![&3, &2, &1]);
+    /// # });
+    /// ```
     #[cfg(feature = "alloc")]
-    fn boxed(self) -> Pin<Box<Self>>
-        where Self: Sized
+    fn boxed<'a>(self) -> BoxStream<'a, Self::Item>
+        where Self: Sized + Send + 'a
+    {
+        Box::pin(self)
+    }
+
+    /// Wrap the stream in a Box, pinning it.
+    ///
+    /// Similar to `boxed`, but without the `Send` requirement.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    #[cfg(feature = "alloc")]
+    fn boxed_local<'a>(self) -> LocalBoxStream<'a, Self::Item>
+        where Self: Sized + 'a
+    {
+        Box::pin(self)
+    }
+
+    /// Wrap the stream in a Box, pinning it.
+    ///
+    /// Similar to `boxed`, but the returned
+    /// [`SyncBoxStream`](futures_core::stream::SyncBoxStream) is also `Sync`.
+    /// Like every boxed stream, it forwards the
+    /// [`size_hint`](futures_core::stream::Stream::size_hint) of the stream
+    /// it wraps.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::stream::{self, StreamExt, SyncBoxStream};
+    ///
+    /// let stream: SyncBoxStream<'_, i32> = stream::iter(1..=3).map(|x| x * 2).boxed_sync();
+    /// assert_eq!(stream.size_hint(), (3, Some(3)));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn boxed_sync<'a>(self) -> SyncBoxStream<'a, Self::Item>
+        where Self: Sized + Send + Sync + 'a
     {
         Box::pin(self)
     }
@@ -901,6 +1739,14 @@ pub trait StreamExt: Stream {
     /// buffered at any point in time, and less than `n` may also be buffered
     /// depending on the state of each future.
     ///
+    /// The window is refilled from this stream every time the returned stream
+    /// is polled, whether or not the oldest future has completed. When an
+    /// output is yielded, the slot it frees is refilled and the new future is
+    /// polled before the output is returned, so that the future runs while
+    /// the output is being handled. Outputs of futures which complete before
+    /// earlier ones are kept until it is their turn, and count towards `n`
+    /// until then.
+    ///
     /// The returned stream will be a stream of each future's output.
     ///
     /// This method is only available when the `std` or `alloc` feature of this
@@ -971,6 +1817,12 @@ pub trait StreamExt: Stream {
     /// returns that pair. If either stream ends then the zipped stream will
     /// also end.
     ///
+    /// Both streams are dropped as soon as either of them ends, so resources
+    /// held by the other stream, such as a channel receiver, are released
+    /// right away instead of when the zipped stream is dropped. An item which
+    /// the other stream has already produced at that point can't be paired up
+    /// anymore and is discarded.
+    ///
     /// # Examples
     ///
     /// ```
@@ -995,6 +1847,44 @@ pub trait StreamExt: Stream {
         Zip::new(self, other)
     }
 
+    /// An adapter for zipping two streams together until both of them end.
+    ///
+    /// Unlike [`zip`](StreamExt::zip), which ends as soon as either stream
+    /// ends, the zipped stream keeps going until both streams have ended.
+    /// Each item is an [`EitherOrBoth`](crate::stream::EitherOrBoth): `Both`
+    /// while both streams produce items, followed by `Left` or `Right` for the
+    /// remaining items of the longer stream.
+    ///
+    /// At most one item of each stream is buffered while waiting for the
+    /// other stream to produce an item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, EitherOrBoth, StreamExt};
+    ///
+    /// let stream1 = stream::iter(1..=3);
+    /// let stream2 = stream::iter(vec!['a']);
+    ///
+    /// let vec = stream1.zip_longest(stream2)
+    ///                  .collect::<Vec<_>>()
+    ///                  .await;
+    /// assert_eq!(vec![
+    ///     EitherOrBoth::Both(1, 'a'),
+    ///     EitherOrBoth::Left(2),
+    ///     EitherOrBoth::Left(3),
+    /// ], vec);
+    /// # });
+    /// ```
+    fn zip_longest<St>(self, other: St) -> ZipLongest<Self, St>
+        where St: Stream,
+              Self: Sized,
+    {
+        ZipLongest::new(self, other)
+    }
+
     /// Adapter for chaining two stream.
     ///
     /// The resulting stream emits elements from the first stream, and when
@@ -1026,6 +1916,71 @@ pub trait StreamExt: Stream {
         Chain::new(self, other)
     }
 
+    /// Adapter for interleaving two streams element by element.
+    ///
+    /// The resulting stream alternates strictly between the two streams,
+    /// starting with this one: it yields one item from this stream, then one
+    /// from `other`, and so on. Unlike [`select`](crate::stream::select),
+    /// which yields whichever item is ready first, this waits for the stream
+    /// whose turn it is, so the order of the output does not depend on
+    /// timing. Once either stream ends, the remaining items of the other
+    /// stream are yielded.
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream1 = stream::iter(vec![1, 3, 5, 7]);
+    /// let stream2 = stream::iter(vec![2, 4]);
+    ///
+    /// let stream = stream1.interleave(stream2);
+    ///
+    /// let result: Vec<_> = stream.collect().await;
+    /// assert_eq!(result, vec![1, 2, 3, 4, 5, 7]);
+    /// # });
+    /// ```
+    fn interleave<St>(self, other: St) -> Interleave<Self, St>
+        where St: Stream<Item = Self::Item>,
+              Self: Sized
+    {
+        Interleave::new(self, other)
+    }
+
+    /// Merges this stream with another one, assuming that both of them are
+    /// sorted according to `cmp`, into a single sorted stream.
+    ///
+    /// An item is only yielded once the next item of both streams (or their
+    /// end) is known, and the smaller of the two is yielded. Items comparing
+    /// equal are taken from this stream first. The returned stream ends once
+    /// both streams have ended.
+    ///
+    /// If either stream isn't sorted, the output isn't sorted either, but no
+    /// item is lost. To merge more than two streams, see
+    /// [`merge_sorted_all`](crate::stream::merge_sorted_all).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream1 = stream::iter(vec![1, 3, 5, 7]);
+    /// let stream2 = stream::iter(vec![2, 3, 4]);
+    /// let merged = stream1.merge_sorted(stream2, |a, b| a.cmp(b));
+    ///
+    /// assert_eq!(merged.collect::<Vec<_>>().await, vec![1, 2, 3, 3, 4, 5, 7]);
+    /// # });
+    /// ```
+    fn merge_sorted<St, F>(self, other: St, cmp: F) -> MergeSorted<Self, St, F>
+        where St: Stream<Item = Self::Item>,
+              F: FnMut(&Self::Item, &Self::Item) -> core::cmp::Ordering,
+              Self: Sized
+    {
+        MergeSorted::new(self, other, cmp)
+    }
+
     /// Creates a new stream which exposes a `peek` method.
     ///
     /// Calling `peek` returns a reference to the next item in the stream.
@@ -1060,6 +2015,93 @@ pub trait StreamExt: Stream {
         Chunks::new(self, capacity)
     }
 
+    /// An adaptor for chunking up byte buffers of the stream inside a vector,
+    /// bounded by their total length rather than their number.
+    ///
+    /// This combinator pulls items from this stream and buffers them into a
+    /// local vector for as long as the summed lengths of the buffered items
+    /// stay at most `max_bytes`. An item which would take the chunk over the
+    /// limit starts the next chunk instead, so that chunks can be used as
+    /// frames of a transport with a maximum frame size. An item which is
+    /// longer than `max_bytes` on its own is yielded as a chunk by itself,
+    /// as items are never split. Empty items are buffered like any other
+    /// item and yielded as part of the chunk they arrived in.
+    ///
+    /// When the underlying stream ends, the partial chunk buffered so far is
+    /// yielded, if there is one.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `max_bytes` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let stream = stream::iter(vec!["ab", "cd", "e", "fghijk", "", "l"]);
+    /// let chunks: Vec<_> = stream.chunks_by_size(5).collect().await;
+    /// assert_eq!(chunks, vec![
+    ///     vec!["ab", "cd", "e"],
+    ///     vec!["fghijk"],
+    ///     vec!["", "l"],
+    /// ]);
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn chunks_by_size(self, max_bytes: usize) -> ChunksBySize<Self>
+        where Self: Sized,
+              Self::Item: AsRef<[u8]>,
+    {
+        ChunksBySize::new(self, max_bytes)
+    }
+
+    /// An adaptor which reads up to `n` items ahead of the consumer.
+    ///
+    /// Whenever the returned stream is polled, it first pulls items from this
+    /// stream until `n` of them are buffered or this stream has none ready,
+    /// and after yielding an item it tops the buffer back up. Items produced
+    /// while the consumer is busy processing an earlier one are thereby
+    /// picked up in one go on the next poll, instead of one at a time.
+    ///
+    /// No task is spawned for this, so this stream is only read from when
+    /// the returned stream is polled. To keep reading ahead while an item is
+    /// being processed, poll [`ArmedPrefetch::poll_prefetch`] alongside the
+    /// processing.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// let mut stream = stream::iter(1..=5).armed_prefetch(2);
+    ///
+    /// assert_eq!(stream.next().await, Some(1));
+    /// assert_eq!(stream.prefetched(), 2);
+    /// assert_eq!(stream.collect::<Vec<_>>().await, vec![2, 3, 4, 5]);
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn armed_prefetch(self, n: usize) -> ArmedPrefetch<Self>
+        where Self: Sized
+    {
+        ArmedPrefetch::new(self, n)
+    }
+
     /// A future that completes after the given stream has been fully processed
     /// into the sink and the sink has been flushed and closed.
     ///
@@ -1070,6 +2112,10 @@ pub trait StreamExt: Stream {
     /// sink will be output by this future.  Pass the sink by `Pin<&mut S>`
     /// (for example, via `forward(&mut sink)` inside an `async` fn/block) in
     /// order to preserve access to the Sink.
+    ///
+    /// If forwarding fails, the sink, the rest of the stream and the item the
+    /// sink was not ready for can be recovered with
+    /// [`Forward::into_parts`](crate::stream::Forward::into_parts).
     fn forward<S>(self, sink: S) -> Forward<Self, S>
     where
         S: Sink<<Self as TryStream>::Ok>,
@@ -1145,6 +2191,51 @@ pub trait StreamExt: Stream {
         Pin::new(self).poll_next(cx)
     }
 
+    /// Pulls the items this stream has ready, up to `limit`, and appends them
+    /// to `buf`, for [`Unpin`] stream types.
+    ///
+    /// Returns `Poll::Ready` with the number of items appended if there was
+    /// at least one, or `Poll::Ready(0)` if the stream has ended. If the
+    /// stream has no items ready, the current task is scheduled to be woken
+    /// up like with [`Stream::poll_next`], and `Poll::Pending` is returned.
+    ///
+    /// If the stream ends after some items have been appended, those items
+    /// are returned and the end of the stream is reported by the next call,
+    /// which polls the stream again. Streams which don't support being
+    /// polled after they have ended should be [fused](StreamExt::fuse).
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `limit` is zero, as `Poll::Ready(0)` would
+    /// then be indistinguishable from the end of the stream.
+    #[cfg(feature = "alloc")]
+    fn poll_next_batch(
+        &mut self,
+        cx: &mut Context<'_>,
+        buf: &mut Vec<Self::Item>,
+        limit: usize,
+    ) -> Poll<usize>
+    where Self: Unpin
+    {
+        assert!(limit > 0, "limit must be greater than zero");
+        let mut count = 0;
+        while count < limit {
+            match self.poll_next_unpin(cx) {
+                Poll::Ready(Some(item)) => {
+                    buf.push(item);
+                    count += 1;
+                }
+                Poll::Ready(None) => break,
+                Poll::Pending if count == 0 => return Poll::Pending,
+                Poll::Pending => break,
+            }
+        }
+        Poll::Ready(count)
+    }
+
     /// Returns a [`Future`] that resolves when the next item in this stream is
     /// ready.
     ///
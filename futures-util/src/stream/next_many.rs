@@ -0,0 +1,42 @@
+use crate::stream::StreamExt;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use alloc::vec::Vec;
+
+/// Future for the [`next_many`](super::StreamExt::next_many) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct NextMany<'a, St: ?Sized + Stream> {
+    stream: &'a mut St,
+    buf: &'a mut Vec<St::Item>,
+    limit: usize,
+}
+
+impl<St: ?Sized + Stream + Unpin> Unpin for NextMany<'_, St> {}
+
+impl<'a, St: ?Sized + Stream + Unpin> NextMany<'a, St> {
+    pub(super) fn new(stream: &'a mut St, buf: &'a mut Vec<St::Item>, limit: usize) -> Self {
+        assert!(limit > 0, "limit must be greater than zero");
+        NextMany { stream, buf, limit }
+    }
+}
+
+impl<St: ?Sized + Stream + FusedStream> FusedFuture for NextMany<'_, St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St: ?Sized + Stream + Unpin> Future for NextMany<'_, St> {
+    type Output = usize;
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Self::Output> {
+        let this = &mut *self;
+        this.stream.poll_next_batch(cx, this.buf, this.limit)
+    }
+}
@@ -0,0 +1,36 @@
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+
+/// Future for the [`next_pinned`](super::StreamExt::next_pinned) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct NextPinned<'a, St: ?Sized> {
+    stream: Pin<&'a mut St>,
+}
+
+impl<St: ?Sized> Unpin for NextPinned<'_, St> {}
+
+impl<'a, St: ?Sized + Stream> NextPinned<'a, St> {
+    pub(super) fn new(stream: Pin<&'a mut St>) -> Self {
+        NextPinned { stream }
+    }
+}
+
+impl<St: ?Sized + FusedStream> FusedFuture for NextPinned<'_, St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St: ?Sized + Stream> Future for NextPinned<'_, St> {
+    type Output = Option<St::Item>;
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Self::Output> {
+        self.stream.as_mut().poll_next(cx)
+    }
+}
@@ -0,0 +1,52 @@
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`nth`](super::StreamExt::nth) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Nth<St> {
+    stream: St,
+    remaining: usize,
+    done: bool,
+}
+
+impl<St: Unpin> Unpin for Nth<St> {}
+
+impl<St: Stream> Nth<St> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(remaining: usize);
+    unsafe_unpinned!(done: bool);
+
+    pub(super) fn new(stream: St, n: usize) -> Nth<St> {
+        Nth {
+            stream,
+            remaining: n,
+            done: false,
+        }
+    }
+}
+
+impl<St> FusedFuture for Nth<St> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St: Stream> Future for Nth<St> {
+    type Output = Option<St::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<St::Item>> {
+        assert!(!self.done, "Nth polled after completion");
+        loop {
+            let item = ready!(self.as_mut().stream().poll_next(cx));
+            if item.is_none() || self.remaining == 0 {
+                *self.as_mut().done() = true;
+                return Poll::Ready(item);
+            }
+            *self.as_mut().remaining() -= 1;
+        }
+    }
+}
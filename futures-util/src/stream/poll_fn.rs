@@ -1,11 +1,11 @@
 //! Definition of the `PollFn` combinator
 
+use core::fmt;
 use core::pin::Pin;
 use futures_core::stream::Stream;
 use futures_core::task::{Context, Poll};
 
 /// Stream for the [`poll_fn`] function.
-#[derive(Debug)]
 #[must_use = "streams do nothing unless polled"]
 pub struct PollFn<F> {
     f: F,
@@ -13,23 +13,64 @@ pub struct PollFn<F> {
 
 impl<F> Unpin for PollFn<F> {}
 
+impl<F> fmt::Debug for PollFn<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PollFn").finish()
+    }
+}
+
 /// Creates a new stream wrapping a function returning `Poll<Option<T>>`.
 ///
-/// Polling the returned stream calls the wrapped function.
+/// Polling the returned stream calls the wrapped function. Returning
+/// `Poll::Ready(None)` from the function terminates the stream. The returned
+/// stream is not fused: if it is polled again after it has terminated, the
+/// function is called again, and it is up to the function to decide what to
+/// return in that case. Use [`fuse`](super::StreamExt::fuse) if you need
+/// [`FusedStream`](futures_core::stream::FusedStream) semantics.
 ///
 /// # Examples
 ///
+/// A counter stream:
+///
 /// ```
+/// use futures::executor::block_on_stream;
 /// use futures::stream::poll_fn;
 /// use futures::task::Poll;
 ///
-/// let mut counter = 1usize;
+/// let mut counter = 0;
 ///
-/// let read_stream = poll_fn(move |_| -> Poll<Option<String>> {
-///     if counter == 0 { return Poll::Ready(None); }
-///     counter -= 1;
-///     Poll::Ready(Some("Hello, World!".to_owned()))
+/// let stream = poll_fn(move |_| -> Poll<Option<usize>> {
+///     if counter == 3 { return Poll::Ready(None); }
+///     counter += 1;
+///     Poll::Ready(Some(counter))
 /// });
+///
+/// assert_eq!(block_on_stream(stream).collect::<Vec<_>>(), vec![1, 2, 3]);
+/// ```
+///
+/// A stream alternating between `Pending` and `Ready`:
+///
+/// ```
+/// use futures::stream::{poll_fn, StreamExt};
+/// use futures::task::{noop_waker_ref, Context, Poll};
+///
+/// let mut ready = false;
+///
+/// let mut stream = poll_fn(move |cx: &mut Context<'_>| {
+///     ready = !ready;
+///     if ready {
+///         Poll::Ready(Some(()))
+///     } else {
+///         // Make sure the task is polled again
+///         cx.waker().wake_by_ref();
+///         Poll::Pending
+///     }
+/// });
+///
+/// let mut cx = Context::from_waker(noop_waker_ref());
+/// assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(())));
+/// assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+/// assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(())));
 /// ```
 pub fn poll_fn<T, F>(f: F) -> PollFn<F>
 where
@@ -0,0 +1,57 @@
+use core::pin::Pin;
+use futures_core::stream::Stream;
+use futures_core::task::{Context, Poll};
+
+/// Stream for the [`repeat_with`] function.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct RepeatWith<F> {
+    f: F,
+}
+
+impl<F> Unpin for RepeatWith<F> {}
+
+/// Create a stream which produces items by calling the given closure
+/// repeatedly.
+///
+/// This is the lazy counterpart of [`repeat`](super::repeat): the item type
+/// does not need to be `Clone`, and the closure is called once for every
+/// item the stream yields.
+///
+/// The stream never terminates. Note that you likely want to avoid
+/// usage of `collect` or such on the returned stream as it will exhaust
+/// available memory as it tries to just fill up all RAM.
+///
+/// ```
+/// #![feature(async_await)]
+/// # futures::executor::block_on(async {
+/// use futures::stream::{self, StreamExt};
+///
+/// let mut curr = 1;
+/// let stream = stream::repeat_with(|| {
+///     let tmp = curr;
+///     curr *= 2;
+///     tmp
+/// });
+/// assert_eq!(vec![1, 2, 4, 8], stream.take(4).collect::<Vec<i32>>().await);
+/// # });
+/// ```
+pub fn repeat_with<A, F>(f: F) -> RepeatWith<F>
+    where F: FnMut() -> A
+{
+    RepeatWith { f }
+}
+
+impl<A, F> Stream for RepeatWith<F>
+    where F: FnMut() -> A
+{
+    type Item = A;
+
+    fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<A>> {
+        Poll::Ready(Some((self.f)()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::max_value(), None)
+    }
+}
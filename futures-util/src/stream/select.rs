@@ -99,6 +99,17 @@ impl<St1, St2> Stream for Select<St1, St2>
             poll_inner(flag, stream2, stream1, cx)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower1, upper1) = self.stream1.size_hint();
+        let (lower2, upper2) = self.stream2.size_hint();
+        let lower = lower1.saturating_add(lower2);
+        let upper = match (upper1, upper2) {
+            (Some(x), Some(y)) => x.checked_add(y),
+            _ => None,
+        };
+        (lower, upper)
+    }
 }
 
 fn poll_inner<St1, St2>(
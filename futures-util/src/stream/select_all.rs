@@ -5,7 +5,10 @@ use core::iter::FromIterator;
 use core::pin::Pin;
 
 use futures_core::{Poll, Stream, FusedStream};
+use futures_core::future::Future;
 use futures_core::task::Context;
+#[cfg(feature = "std")]
+use futures_channel::oneshot;
 
 use crate::stream::{StreamExt, StreamFuture, FuturesUnordered};
 
@@ -17,6 +20,12 @@ use crate::stream::{StreamExt, StreamFuture, FuturesUnordered};
 /// Streams are pushed into this set and their realized values are
 /// yielded as they become ready. Streams will only be polled when they
 /// generate notifications. This allows to coordinate a large number of streams.
+/// A stream which has just yielded an item is queued behind the other ready
+/// streams, so a stream that is always ready can't starve the rest of the set.
+/// Beyond that, the order in which items of concurrently ready streams are
+/// yielded is unspecified.
+/// A stream is dropped as soon as it terminates, releasing any resources it
+/// holds while the rest of the set keeps running.
 ///
 /// Note that you can create a ready-made `SelectAll` via the
 /// `select_all` function in the `stream` module, or you can start with an
@@ -24,6 +33,8 @@ use crate::stream::{StreamExt, StreamFuture, FuturesUnordered};
 #[must_use = "streams do nothing unless polled"]
 pub struct SelectAll<St> {
     inner: FuturesUnordered<StreamFuture<St>>,
+    #[cfg(feature = "std")]
+    empty_signals: Vec<oneshot::Sender<()>>,
 }
 
 impl<St: Debug> Debug for SelectAll<St> {
@@ -38,7 +49,11 @@ impl<St: Stream + Unpin> SelectAll<St> {
     /// The returned `SelectAll` does not contain any streams and, in this
     /// state, `SelectAll::poll` will return `Poll::Ready(None)`.
     pub fn new() -> SelectAll<St> {
-        SelectAll { inner: FuturesUnordered::new() }
+        SelectAll {
+            inner: FuturesUnordered::new(),
+            #[cfg(feature = "std")]
+            empty_signals: Vec::new(),
+        }
     }
 
     /// Returns the number of streams contained in the set.
@@ -62,6 +77,39 @@ impl<St: Stream + Unpin> SelectAll<St> {
     pub fn push(&mut self, stream: St) {
         self.inner.push(stream.into_future());
     }
+
+    /// Returns a future which resolves once this set no longer contains any
+    /// streams.
+    ///
+    /// The future resolves when the last stream in the set terminates, or
+    /// immediately if the set is already empty. It also resolves if the
+    /// `SelectAll` is dropped. The future does not need to be polled from the
+    /// task driving the `SelectAll`, so it can be used to shut down e.g. a
+    /// connection manager once its last subscriber has left.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    #[cfg(feature = "std")]
+    pub fn on_empty(&mut self) -> OnEmpty {
+        let (tx, rx) = oneshot::channel();
+        if self.is_empty() {
+            let _ = tx.send(());
+        } else {
+            self.empty_signals.retain(|tx| !tx.is_canceled());
+            self.empty_signals.push(tx);
+        }
+        OnEmpty { rx }
+    }
+
+    #[cfg(feature = "std")]
+    fn notify_empty(&mut self) {
+        for tx in self.empty_signals.drain(..) {
+            let _ = tx.send(());
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn notify_empty(&mut self) {}
 }
 
 impl<St: Stream + Unpin> Default for SelectAll<St> {
@@ -84,6 +132,9 @@ impl<St: Stream + Unpin> Stream for SelectAll<St> {
                     return Poll::Ready(Some(item));
                 }
                 Some((None, _)) => {
+                    if self.is_empty() {
+                        self.notify_empty();
+                    }
                     // `FuturesUnordered` thinks it isn't terminated
                     // because it yielded a Some.
                     // We do not return, but poll `FuturesUnordered`
@@ -101,15 +152,38 @@ impl<St: Stream + Unpin> FusedStream for SelectAll<St> {
     }
 }
 
+/// Future for the [`on_empty`](SelectAll::on_empty) method.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct OnEmpty {
+    rx: oneshot::Receiver<()>,
+}
+
+#[cfg(feature = "std")]
+impl Future for OnEmpty {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        // A canceled receiver means the `SelectAll` was dropped, which also
+        // leaves no streams behind.
+        let _ = ready!(Pin::new(&mut self.rx).poll(cx));
+        Poll::Ready(())
+    }
+}
+
 /// Convert a list of streams into a `Stream` of results from the streams.
 ///
 /// This essentially takes a list of streams (e.g. a vector, an iterator, etc.)
 /// and bundles them together into a single stream.
 /// The stream will yield items as they become available on the underlying
-/// streams internally, in the order they become available.
+/// streams internally, in the order they become available. Streams which
+/// are ready at the same time take turns, but the order of their items is
+/// otherwise unspecified.
 ///
 /// Note that the returned set can also be used to dynamically push more
-/// futures into the set as they become available.
+/// futures into the set as they become available. An empty list of streams
+/// results in an empty set, which yields `None` until streams are pushed.
 ///
 /// This function is only available when the `std` or `alloc` feature of this
 /// library is activated, and it is activated by default.
@@ -0,0 +1,218 @@
+use crate::task::{ArcWake, waker_ref};
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll, Waker};
+use slab::Slab;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+/// Stream for the [`share`](super::StreamExt::share) method.
+#[must_use = "streams do nothing unless polled"]
+pub struct Shared<St: Stream> {
+    inner: Arc<Inner<St>>,
+    // Index of the next item this handle yields
+    next: u64,
+    waker_key: usize,
+}
+
+struct Inner<St: Stream> {
+    state: Mutex<State<St>>,
+    notifier: Arc<Notifier>,
+}
+
+struct State<St: Stream> {
+    // `None` once the stream has terminated
+    stream: Option<Pin<Box<St>>>,
+    // The latest item, until every handle has taken it
+    current: Option<St::Item>,
+    // Number of items produced by the stream so far
+    produced: u64,
+    // Number of handles which still have to take `current`
+    pending: usize,
+    // Number of live handles
+    handles: usize,
+}
+
+struct Notifier {
+    wakers: Mutex<Slab<Option<Waker>>>,
+}
+
+const NULL_WAKER_KEY: usize = usize::max_value();
+
+impl<St: Stream> fmt::Debug for Shared<St> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Shared")
+            .field("next", &self.next)
+            .field("waker_key", &self.waker_key)
+            .finish()
+    }
+}
+
+impl<St: Stream> Shared<St> {
+    pub(super) fn new(stream: St) -> Shared<St> {
+        let inner = Inner {
+            state: Mutex::new(State {
+                stream: Some(Box::pin(stream)),
+                current: None,
+                produced: 0,
+                pending: 0,
+                handles: 1,
+            }),
+            notifier: Arc::new(Notifier {
+                wakers: Mutex::new(Slab::new()),
+            }),
+        };
+
+        Shared {
+            inner: Arc::new(inner),
+            next: 0,
+            waker_key: NULL_WAKER_KEY,
+        }
+    }
+
+    /// Registers the current task to receive a wakeup when a new item is
+    /// available or the current one has been taken by every handle.
+    fn set_waker(&mut self, cx: &mut Context<'_>) {
+        let mut wakers = self.inner.notifier.wakers.lock().unwrap();
+        if self.waker_key == NULL_WAKER_KEY {
+            self.waker_key = wakers.insert(Some(cx.waker().clone()));
+        } else {
+            wakers[self.waker_key] = Some(cx.waker().clone());
+        }
+    }
+}
+
+impl Notifier {
+    fn wake_all(&self) {
+        let mut wakers = self.wakers.lock().unwrap();
+        for (_key, opt_waker) in wakers.iter_mut() {
+            if let Some(waker) = opt_waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+impl ArcWake for Notifier {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.wake_all();
+    }
+}
+
+impl<St> FusedStream for Shared<St>
+    where St: Stream,
+          St::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.state.lock().unwrap().stream.is_none()
+    }
+}
+
+impl<St> Stream for Shared<St>
+    where St: Stream,
+          St::Item: Clone,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<St::Item>> {
+        let this = &mut *self;
+        this.set_waker(cx);
+
+        let mut state = this.inner.state.lock().unwrap();
+
+        if state.current.is_some() {
+            if this.next + 1 != state.produced {
+                // This handle already took the current item, wait for the
+                // other handles to take it as well.
+                return Poll::Pending;
+            }
+
+            this.next += 1;
+            state.pending -= 1;
+            let item = if state.pending == 0 {
+                let item = state.current.take().unwrap();
+                drop(state);
+                // Let the handles waiting on us advance the stream.
+                this.inner.notifier.wake_all();
+                item
+            } else {
+                state.current.clone().unwrap()
+            };
+            return Poll::Ready(Some(item));
+        }
+
+        let stream = match state.stream.as_mut() {
+            Some(stream) => stream,
+            None => return Poll::Ready(None),
+        };
+
+        // Poll the stream with a waker which wakes all handles, so that the
+        // stream makes progress even if this handle is dropped.
+        let waker = waker_ref(&this.inner.notifier);
+        let mut notifier_cx = Context::from_waker(&waker);
+
+        match stream.as_mut().poll_next(&mut notifier_cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(None) => {
+                state.stream = None;
+                drop(state);
+                this.inner.notifier.wake_all();
+                Poll::Ready(None)
+            }
+            Poll::Ready(Some(item)) => {
+                state.produced += 1;
+                this.next = state.produced;
+                if state.handles > 1 {
+                    state.current = Some(item.clone());
+                    state.pending = state.handles - 1;
+                    drop(state);
+                    this.inner.notifier.wake_all();
+                }
+                Poll::Ready(Some(item))
+            }
+        }
+    }
+}
+
+/// The new handle yields the items produced after it was created.
+impl<St: Stream> Clone for Shared<St> {
+    fn clone(&self) -> Self {
+        let mut state = self.inner.state.lock().unwrap();
+        state.handles += 1;
+        Shared {
+            inner: self.inner.clone(),
+            next: state.produced,
+            waker_key: NULL_WAKER_KEY,
+        }
+    }
+}
+
+impl<St: Stream> Drop for Shared<St> {
+    fn drop(&mut self) {
+        let mut wake = false;
+        if let Ok(mut state) = self.inner.state.lock() {
+            state.handles -= 1;
+            // Don't hold up the other handles with an item we'll never take.
+            if state.current.is_some() && self.next + 1 == state.produced {
+                state.pending -= 1;
+                if state.pending == 0 {
+                    state.current = None;
+                    wake = true;
+                }
+            }
+        }
+
+        if let Ok(mut wakers) = self.inner.notifier.wakers.lock() {
+            if self.waker_key != NULL_WAKER_KEY {
+                wakers.remove(self.waker_key);
+            }
+        }
+
+        if wake {
+            self.inner.notifier.wake_all();
+        }
+    }
+}
@@ -80,6 +80,16 @@ impl<St: Stream> Stream for Skip<St> {
 
         self.as_mut().stream().poll_next(cx)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+
+        // The results fit, as they are at most the hints of the stream
+        let lower = (lower as u64).saturating_sub(self.remaining) as usize;
+        let upper = upper.map(|x| (x as u64).saturating_sub(self.remaining) as usize);
+
+        (lower, upper)
+    }
 }
 
 // Forwarding impl of Sink from the underlying stream
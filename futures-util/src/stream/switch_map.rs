@@ -0,0 +1,137 @@
+use crate::stream::{Fuse, StreamExt, MAX_ITEMS_PER_POLL};
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`switch_map`](super::StreamExt::switch_map) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct SwitchMap<St, U, F> {
+    stream: Fuse<St>,
+    f: F,
+    inner: Option<U>,
+}
+
+impl<St, U, F> Unpin for SwitchMap<St, U, F>
+where St: Unpin,
+      U: Unpin,
+{}
+
+impl<St, U, F> SwitchMap<St, U, F>
+where St: Stream,
+      F: FnMut(St::Item) -> U,
+      U: Stream,
+{
+    unsafe_pinned!(stream: Fuse<St>);
+    unsafe_unpinned!(f: F);
+    unsafe_pinned!(inner: Option<U>);
+
+    pub(super) fn new(stream: St, f: F) -> SwitchMap<St, U, F> {
+        SwitchMap { stream: stream.fuse(), f, inner: None }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.stream.get_mut()
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut St> {
+        self.stream().get_pin_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream.into_inner()
+    }
+}
+
+impl<St, U, F> FusedStream for SwitchMap<St, U, F>
+where St: Stream,
+      F: FnMut(St::Item) -> U,
+      U: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_none() && self.stream.is_terminated()
+    }
+}
+
+impl<St, U, F> Stream for SwitchMap<St, U, F>
+where St: Stream,
+      F: FnMut(St::Item) -> U,
+      U: Stream,
+{
+    type Item = U::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        // Check the outer stream first, so that a new item replaces the
+        // current inner stream before any of its items are yielded. Only the
+        // last of several ready items needs an inner stream, but the closure
+        // is still called for each of them.
+        let mut drained = false;
+        for _ in 0..MAX_ITEMS_PER_POLL {
+            match self.as_mut().stream().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    let next = (self.as_mut().f())(item);
+                    self.as_mut().inner().set(Some(next));
+                }
+                Poll::Ready(None) | Poll::Pending => {
+                    drained = true;
+                    break;
+                }
+            }
+        }
+
+        if let Some(inner) = self.as_mut().inner().as_pin_mut() {
+            match inner.poll_next(cx) {
+                Poll::Ready(Some(item)) => return Poll::Ready(Some(item)),
+                Poll::Ready(None) => self.as_mut().inner().set(None),
+                Poll::Pending => {}
+            }
+        }
+
+        if self.inner.is_none() && self.stream.is_done() {
+            Poll::Ready(None)
+        } else {
+            if !drained {
+                // The outer stream may still have items ready, and nothing
+                // else is going to wake the task to pull them.
+                cx.waker().wake_by_ref();
+            }
+            Poll::Pending
+        }
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, U, F, Item> Sink<Item> for SwitchMap<S, U, F>
+where S: Stream + Sink<Item>,
+      F: FnMut(S::Item) -> U,
+      U: Stream,
+{
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream, Item);
+}
@@ -1,3 +1,5 @@
+use core::cmp;
+use core::convert::TryFrom;
 use core::pin::Pin;
 use futures_core::stream::Stream;
 use futures_core::task::{Context, Poll};
@@ -78,6 +80,24 @@ impl<St> Stream for Take<St>
             Poll::Ready(next)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.remaining == 0 {
+            return (0, Some(0));
+        }
+
+        let (lower, upper) = self.stream.size_hint();
+
+        let lower = cmp::min(lower as u64, self.remaining) as usize;
+
+        let upper = match (upper, usize::try_from(self.remaining).ok()) {
+            (Some(x), Some(remaining)) => Some(cmp::min(x, remaining)),
+            (None, remaining) => remaining,
+            (upper, None) => upper,
+        };
+
+        (lower, upper)
+    }
 }
 
 // Forwarding impl of Sink from the underlying stream
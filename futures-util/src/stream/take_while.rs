@@ -1,6 +1,7 @@
+use core::fmt;
 use core::pin::Pin;
 use futures_core::future::Future;
-use futures_core::stream::Stream;
+use futures_core::stream::{FusedStream, Stream};
 use futures_core::task::{Context, Poll};
 use futures_sink::Sink;
 use pin_utils::{unsafe_pinned, unsafe_unpinned};
@@ -14,6 +15,8 @@ pub struct TakeWhile<St: Stream , Fut, F> {
     pending_fut: Option<Fut>,
     pending_item: Option<St::Item>,
     done_taking: bool,
+    // Whether the first item failing the predicate is yielded too
+    inclusive: bool,
 }
 
 impl<St: Unpin + Stream, Fut: Unpin, F> Unpin for TakeWhile<St, Fut, F> {}
@@ -36,9 +39,14 @@ impl<St, Fut, F> TakeWhile<St, Fut, F>
             pending_fut: None,
             pending_item: None,
             done_taking: false,
+            inclusive: false,
         }
     }
 
+    fn new_inclusive(stream: St, f: F) -> TakeWhile<St, Fut, F> {
+        TakeWhile { inclusive: true, ..TakeWhile::new(stream, f) }
+    }
+
     /// Acquires a reference to the underlying stream that this combinator is
     /// pulling from.
     pub fn get_ref(&self) -> &St {
@@ -90,7 +98,10 @@ impl<St, Fut, F> Stream for TakeWhile<St, Fut, F>
         if self.pending_item.is_none() {
             let item = match ready!(self.as_mut().stream().poll_next(cx)) {
                 Some(e) => e,
-                None => return Poll::Ready(None),
+                None => {
+                    *self.as_mut().done_taking() = true;
+                    return Poll::Ready(None);
+                }
             };
             let fut = (self.as_mut().f())(&item);
             self.as_mut().pending_fut().set(Some(fut));
@@ -105,8 +116,36 @@ impl<St, Fut, F> Stream for TakeWhile<St, Fut, F>
             Poll::Ready(Some(item))
         } else {
             *self.as_mut().done_taking() = true;
-            Poll::Ready(None)
+            if self.inclusive {
+                Poll::Ready(Some(item))
+            } else {
+                Poll::Ready(None)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done_taking {
+            return (0, Some(0));
         }
+
+        let pending_len = if self.pending_item.is_some() { 1 } else { 0 };
+        let (_, upper) = self.stream.size_hint();
+        let upper = match upper {
+            Some(x) => x.checked_add(pending_len),
+            None => None,
+        };
+        (0, upper) // can't know a lower bound, due to the predicate
+    }
+}
+
+impl<St, Fut, F> FusedStream for TakeWhile<St, Fut, F>
+    where St: Stream,
+          F: FnMut(&St::Item) -> Fut,
+          Fut: Future<Output = bool>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done_taking
     }
 }
 
@@ -120,3 +159,109 @@ impl<S, Fut, F, Item> Sink<Item> for TakeWhile<S, Fut, F>
 
     delegate_sink!(stream, Item);
 }
+
+/// Stream for the
+/// [`take_while_inclusive`](super::StreamExt::take_while_inclusive) method.
+#[must_use = "streams do nothing unless polled"]
+pub struct TakeWhileInclusive<St: Stream, Fut, F> {
+    inner: TakeWhile<St, Fut, F>,
+}
+
+impl<St, Fut, F> fmt::Debug for TakeWhileInclusive<St, Fut, F>
+    where St: Stream + fmt::Debug,
+          St::Item: fmt::Debug,
+          Fut: fmt::Debug,
+          F: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TakeWhileInclusive")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<St: Unpin + Stream, Fut: Unpin, F> Unpin for TakeWhileInclusive<St, Fut, F> {}
+
+impl<St, Fut, F> TakeWhileInclusive<St, Fut, F>
+    where St: Stream,
+          F: FnMut(&St::Item) -> Fut,
+          Fut: Future<Output = bool>,
+{
+    unsafe_pinned!(inner: TakeWhile<St, Fut, F>);
+
+    pub(super) fn new(stream: St, f: F) -> TakeWhileInclusive<St, Fut, F> {
+        TakeWhileInclusive { inner: TakeWhile::new_inclusive(stream, f) }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.inner.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.inner.get_mut()
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut St> {
+        self.inner().get_pin_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.inner.into_inner()
+    }
+}
+
+impl<St, Fut, F> Stream for TakeWhileInclusive<St, Fut, F>
+    where St: Stream,
+          F: FnMut(&St::Item) -> Fut,
+          Fut: Future<Output = bool>,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<St::Item>> {
+        self.inner().poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<St, Fut, F> FusedStream for TakeWhileInclusive<St, Fut, F>
+    where St: Stream,
+          F: FnMut(&St::Item) -> Fut,
+          Fut: Future<Output = bool>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, Fut, F, Item> Sink<Item> for TakeWhileInclusive<S, Fut, F>
+    where S: Stream + Sink<Item>,
+          F: FnMut(&S::Item) -> Fut,
+          Fut: Future<Output = bool>,
+{
+    type SinkError = S::SinkError;
+
+    delegate_sink!(inner, Item);
+}
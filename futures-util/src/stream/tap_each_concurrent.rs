@@ -0,0 +1,173 @@
+use crate::stream::{Fuse, FuturesOrdered, StreamExt};
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the
+/// [`tap_each_concurrent`](super::StreamExt::tap_each_concurrent) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct TapEachConcurrent<St, Fut, F>
+where St: Stream,
+      Fut: Future<Output = ()>,
+{
+    stream: Fuse<St>,
+    f: F,
+    futures: FuturesOrdered<Tap<Fut, St::Item>>,
+    limit: Option<NonZeroUsize>,
+}
+
+impl<St, Fut, F> Unpin for TapEachConcurrent<St, Fut, F>
+where St: Stream + Unpin,
+      Fut: Future<Output = ()>,
+{}
+
+impl<St, Fut, F> TapEachConcurrent<St, Fut, F>
+where St: Stream,
+      F: FnMut(&St::Item) -> Fut,
+      Fut: Future<Output = ()>,
+{
+    unsafe_pinned!(stream: Fuse<St>);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(futures: FuturesOrdered<Tap<Fut, St::Item>>);
+
+    pub(super) fn new(stream: St, limit: Option<usize>, f: F) -> TapEachConcurrent<St, Fut, F> {
+        TapEachConcurrent {
+            stream: super::Fuse::new(stream),
+            f,
+            futures: FuturesOrdered::new(),
+            // Note: `limit` = 0 gets ignored.
+            limit: limit.and_then(NonZeroUsize::new),
+        }
+    }
+
+    /// Returns the number of items this combinator is currently holding,
+    /// either because their side effect is still running or because an
+    /// earlier item hasn't been yielded yet.
+    pub fn in_flight(&self) -> usize {
+        self.futures.len()
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.stream.get_mut()
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut St> {
+        self.stream().get_pin_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream.into_inner()
+    }
+
+    fn has_capacity(&self) -> bool {
+        self.limit.map(|limit| self.futures.len() < limit.get()).unwrap_or(true)
+    }
+}
+
+impl<St, Fut, F> Stream for TapEachConcurrent<St, Fut, F>
+where St: Stream,
+      F: FnMut(&St::Item) -> Fut,
+      Fut: Future<Output = ()>,
+{
+    type Item = St::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        // First up, try to start as many side effects as the limit allows.
+        while self.has_capacity() {
+            match self.as_mut().stream().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    let future = (self.as_mut().f())(&item);
+                    self.as_mut().futures().push_back(Tap { future, item: Some(item) });
+                }
+                Poll::Ready(None) | Poll::Pending => break,
+            }
+        }
+
+        // Attempt to pull the next item whose side effect has completed
+        match self.as_mut().futures().poll_next_unpin(cx) {
+            x @ Poll::Pending | x @ Poll::Ready(Some(_)) => return x,
+            Poll::Ready(None) => {}
+        }
+
+        // If more values are still coming from the stream, we're not done yet
+        if self.stream.is_done() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<St, Fut, F> FusedStream for TapEachConcurrent<St, Fut, F>
+where St: Stream,
+      F: FnMut(&St::Item) -> Fut,
+      Fut: Future<Output = ()>,
+{
+    fn is_terminated(&self) -> bool {
+        self.futures.is_empty() && self.stream.is_terminated()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, Fut, F, Item> Sink<Item> for TapEachConcurrent<S, Fut, F>
+where S: Stream + Sink<Item>,
+      F: FnMut(&S::Item) -> Fut,
+      Fut: Future<Output = ()>,
+{
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream, Item);
+}
+
+// Runs the side effect for `item`, then hands the item back.
+#[derive(Debug)]
+struct Tap<Fut, T> {
+    future: Fut,
+    item: Option<T>,
+}
+
+impl<Fut: Unpin, T> Unpin for Tap<Fut, T> {}
+
+impl<Fut, T> Tap<Fut, T> {
+    unsafe_pinned!(future: Fut);
+    unsafe_unpinned!(item: Option<T>);
+}
+
+impl<Fut, T> Future for Tap<Fut, T>
+where Fut: Future<Output = ()>,
+{
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        ready!(self.as_mut().future().poll(cx));
+        Poll::Ready(self.item().take().expect("Tap polled after completion"))
+    }
+}
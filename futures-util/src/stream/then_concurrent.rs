@@ -0,0 +1,143 @@
+use crate::stream::{Fuse, FuturesUnordered, StreamExt};
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use futures_core::future::Future;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`then_concurrent`](super::StreamExt::then_concurrent)
+/// method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct ThenConcurrent<St, Fut, F> {
+    stream: Fuse<St>,
+    f: F,
+    futures: FuturesUnordered<Fut>,
+    limit: Option<NonZeroUsize>,
+}
+
+impl<St, Fut, F> Unpin for ThenConcurrent<St, Fut, F>
+where St: Unpin,
+      Fut: Unpin,
+{}
+
+impl<St, Fut, F> ThenConcurrent<St, Fut, F>
+where St: Stream,
+      F: FnMut(St::Item) -> Fut,
+      Fut: Future,
+{
+    unsafe_pinned!(stream: Fuse<St>);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(futures: FuturesUnordered<Fut>);
+
+    pub(super) fn new(stream: St, limit: Option<usize>, f: F) -> ThenConcurrent<St, Fut, F> {
+        ThenConcurrent {
+            stream: super::Fuse::new(stream),
+            f,
+            futures: FuturesUnordered::new(),
+            // Note: `limit` = 0 gets ignored.
+            limit: limit.and_then(NonZeroUsize::new),
+        }
+    }
+
+    /// Returns the number of futures this combinator is currently driving.
+    pub fn in_flight(&self) -> usize {
+        self.futures.len()
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.stream.get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.stream.get_mut()
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut St> {
+        self.stream().get_pin_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream.into_inner()
+    }
+
+    fn has_capacity(&self) -> bool {
+        self.limit.map(|limit| self.futures.len() < limit.get()).unwrap_or(true)
+    }
+}
+
+impl<St, Fut, F> Stream for ThenConcurrent<St, Fut, F>
+where St: Stream,
+      F: FnMut(St::Item) -> Fut,
+      Fut: Future,
+{
+    type Item = Fut::Output;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        // First up, try to spawn off as many futures as the limit allows.
+        while self.has_capacity() {
+            match self.as_mut().stream().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    let fut = (self.as_mut().f())(item);
+                    self.as_mut().futures().push(fut);
+                }
+                Poll::Ready(None) | Poll::Pending => break,
+            }
+        }
+
+        // Attempt to pull the next value from the in-flight futures
+        match self.as_mut().futures().poll_next_unpin(cx) {
+            x @ Poll::Pending | x @ Poll::Ready(Some(_)) => return x,
+            Poll::Ready(None) => {}
+        }
+
+        // If more values are still coming from the stream, we're not done yet
+        if self.stream.is_done() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<St, Fut, F> FusedStream for ThenConcurrent<St, Fut, F>
+where St: Stream,
+      F: FnMut(St::Item) -> Fut,
+      Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.futures.is_terminated() && self.stream.is_terminated()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, Fut, F, Item> Sink<Item> for ThenConcurrent<S, Fut, F>
+where S: Stream + Sink<Item>,
+      F: FnMut(S::Item) -> Fut,
+      Fut: Future,
+{
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream, Item);
+}
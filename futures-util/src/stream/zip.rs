@@ -1,4 +1,4 @@
-use crate::stream::{StreamExt, Fuse};
+use core::cmp;
 use core::pin::Pin;
 use futures_core::stream::{FusedStream, Stream};
 use futures_core::task::{Context, Poll};
@@ -8,30 +8,29 @@ use pin_utils::{unsafe_pinned, unsafe_unpinned};
 #[derive(Debug)]
 #[must_use = "streams do nothing unless polled"]
 pub struct Zip<St1: Stream, St2: Stream> {
-    stream1: Fuse<St1>,
-    stream2: Fuse<St2>,
+    // Both streams are dropped as soon as either of them ends
+    stream1: Option<St1>,
+    stream2: Option<St2>,
     queued1: Option<St1::Item>,
     queued2: Option<St2::Item>,
 }
 
 impl<St1, St2> Unpin for Zip<St1, St2>
 where
-    St1: Stream,
-    Fuse<St1>: Unpin,
-    St2: Stream,
-    Fuse<St2>: Unpin,
+    St1: Stream + Unpin,
+    St2: Stream + Unpin,
 {}
 
 impl<St1: Stream, St2: Stream> Zip<St1, St2> {
-    unsafe_pinned!(stream1: Fuse<St1>);
-    unsafe_pinned!(stream2: Fuse<St2>);
+    unsafe_pinned!(stream1: Option<St1>);
+    unsafe_pinned!(stream2: Option<St2>);
     unsafe_unpinned!(queued1: Option<St1::Item>);
     unsafe_unpinned!(queued2: Option<St2::Item>);
 
     pub(super) fn new(stream1: St1, stream2: St2) -> Zip<St1, St2> {
         Zip {
-            stream1: stream1.fuse(),
-            stream2: stream2.fuse(),
+            stream1: Some(stream1),
+            stream2: Some(stream2),
             queued1: None,
             queued2: None,
         }
@@ -39,8 +38,16 @@ impl<St1: Stream, St2: Stream> Zip<St1, St2> {
 
     /// Acquires a reference to the underlying streams that this combinator is
     /// pulling from.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the zipped stream has ended, as both underlying streams are
+    /// dropped at that point.
     pub fn get_ref(&self) -> (&St1, &St2) {
-        (self.stream1.get_ref(), self.stream2.get_ref())
+        match (&self.stream1, &self.stream2) {
+            (Some(stream1), Some(stream2)) => (stream1, stream2),
+            _ => panic!("Zip accessed after termination"),
+        }
     }
 
     /// Acquires a mutable reference to the underlying streams that this
@@ -48,8 +55,16 @@ impl<St1: Stream, St2: Stream> Zip<St1, St2> {
     ///
     /// Note that care must be taken to avoid tampering with the state of the
     /// stream which may otherwise confuse this combinator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the zipped stream has ended, as both underlying streams are
+    /// dropped at that point.
     pub fn get_mut(&mut self) -> (&mut St1, &mut St2) {
-        (self.stream1.get_mut(), self.stream2.get_mut())
+        match (&mut self.stream1, &mut self.stream2) {
+            (Some(stream1), Some(stream2)) => (stream1, stream2),
+            _ => panic!("Zip accessed after termination"),
+        }
     }
 
     /// Acquires a pinned mutable reference to the underlying streams that this
@@ -57,19 +72,41 @@ impl<St1: Stream, St2: Stream> Zip<St1, St2> {
     ///
     /// Note that care must be taken to avoid tampering with the state of the
     /// stream which may otherwise confuse this combinator.
-    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> (Pin<&'a mut St1>, Pin<&'a mut St2>)
-        where St1: Unpin, St2: Unpin,
-    {
-        let Self { stream1, stream2, .. } = self.get_mut();
-        (Pin::new(stream1.get_mut()), Pin::new(stream2.get_mut()))
+    ///
+    /// # Panics
+    ///
+    /// Panics if the zipped stream has ended, as both underlying streams are
+    /// dropped at that point.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> (Pin<&'a mut St1>, Pin<&'a mut St2>) {
+        unsafe {
+            let (stream1, stream2) = Pin::get_unchecked_mut(self).get_mut();
+            (Pin::new_unchecked(stream1), Pin::new_unchecked(stream2))
+        }
     }
 
     /// Consumes this combinator, returning the underlying streams.
     ///
     /// Note that this may discard intermediate state of this combinator, so
     /// care should be taken to avoid losing resources when this is called.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the zipped stream has ended, as both underlying streams are
+    /// dropped at that point.
     pub fn into_inner(self) -> (St1, St2) {
-        (self.stream1.into_inner(), self.stream2.into_inner())
+        match (self.stream1, self.stream2) {
+            (Some(stream1), Some(stream2)) => (stream1, stream2),
+            _ => panic!("Zip accessed after termination"),
+        }
+    }
+
+    // Drops both streams along with any item buffered from the other stream,
+    // which can never be paired up anymore.
+    fn terminate(mut self: Pin<&mut Self>) {
+        self.as_mut().stream1().set(None);
+        self.as_mut().stream2().set(None);
+        *self.as_mut().queued1() = None;
+        *self.as_mut().queued2() = None;
     }
 }
 
@@ -77,7 +114,7 @@ impl<St1, St2> FusedStream for Zip<St1, St2>
     where St1: Stream, St2: Stream,
 {
     fn is_terminated(&self) -> bool {
-        self.stream1.is_terminated() && self.stream2.is_terminated()
+        self.stream1.is_none()
     }
 }
 
@@ -90,16 +127,28 @@ impl<St1, St2> Stream for Zip<St1, St2>
         mut self: Pin<&mut Self>,
         cx: &mut Context<'_>,
     ) -> Poll<Option<Self::Item>> {
+        if self.stream1.is_none() {
+            return Poll::Ready(None);
+        }
+
         if self.queued1.is_none() {
-            match self.as_mut().stream1().poll_next(cx) {
+            match self.as_mut().stream1().as_pin_mut().unwrap().poll_next(cx) {
                 Poll::Ready(Some(item1)) => *self.as_mut().queued1() = Some(item1),
-                Poll::Ready(None) | Poll::Pending => {}
+                Poll::Ready(None) => {
+                    self.terminate();
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => {}
             }
         }
         if self.queued2.is_none() {
-            match self.as_mut().stream2().poll_next(cx) {
+            match self.as_mut().stream2().as_pin_mut().unwrap().poll_next(cx) {
                 Poll::Ready(Some(item2)) => *self.as_mut().queued2() = Some(item2),
-                Poll::Ready(None) | Poll::Pending => {}
+                Poll::Ready(None) => {
+                    self.terminate();
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => {}
             }
         }
 
@@ -107,10 +156,37 @@ impl<St1, St2> Stream for Zip<St1, St2>
             let pair = (self.as_mut().queued1().take().unwrap(),
                         self.as_mut().queued2().take().unwrap());
             Poll::Ready(Some(pair))
-        } else if self.stream1.is_done() || self.stream2.is_done() {
-            Poll::Ready(None)
         } else {
             Poll::Pending
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (stream1, stream2) = match (&self.stream1, &self.stream2) {
+            (Some(stream1), Some(stream2)) => (stream1, stream2),
+            _ => return (0, Some(0)),
+        };
+
+        let queued1_len = if self.queued1.is_some() { 1 } else { 0 };
+        let queued2_len = if self.queued2.is_some() { 1 } else { 0 };
+        let (stream1_lower, stream1_upper) = stream1.size_hint();
+        let (stream2_lower, stream2_upper) = stream2.size_hint();
+
+        let stream1_lower = stream1_lower.saturating_add(queued1_len);
+        let stream2_lower = stream2_lower.saturating_add(queued2_len);
+
+        let lower = cmp::min(stream1_lower, stream2_lower);
+
+        let stream1_upper = stream1_upper.and_then(|x| x.checked_add(queued1_len));
+        let stream2_upper = stream2_upper.and_then(|y| y.checked_add(queued2_len));
+
+        let upper = match (stream1_upper, stream2_upper) {
+            (Some(x), Some(y)) => Some(cmp::min(x, y)),
+            (Some(x), None) => Some(x),
+            (None, Some(y)) => Some(y),
+            (None, None) => None,
+        };
+
+        (lower, upper)
+    }
 }
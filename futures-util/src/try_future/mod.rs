@@ -4,7 +4,7 @@
 //! including the `FutureExt` trait which adds methods to `Future` types.
 
 use core::pin::Pin;
-use futures_core::future::TryFuture;
+use futures_core::future::{Future, TryFuture};
 use futures_core::stream::TryStream;
 use futures_core::task::{Context, Poll};
 use futures_sink::Sink;
@@ -30,6 +30,13 @@ mod select_ok;
 #[cfg(feature = "alloc")]
 pub use self::select_ok::{select_ok, SelectOk};
 
+#[cfg(feature = "alloc")]
+mod try_select_all;
+#[cfg(feature = "alloc")]
+pub use self::try_select_all::{
+    try_select_all, try_select_all_ok, TrySelectAll, TrySelectAllOk, TrySelectAllError,
+};
+
 // Combinators
 mod and_then;
 pub use self::and_then::AndThen;
@@ -55,12 +62,21 @@ pub use self::map_err::MapErr;
 mod map_ok;
 pub use self::map_ok::MapOk;
 
+mod ok_into;
+pub use self::ok_into::OkInto;
+
+mod ok_into_stream;
+pub use self::ok_into_stream::OkIntoStream;
+
 mod or_else;
 pub use self::or_else::OrElse;
 
 mod try_flatten_stream;
 pub use self::try_flatten_stream::TryFlattenStream;
 
+mod timeout_with_default;
+pub use self::timeout_with_default::{TimeoutWith, TimeoutWithDefault};
+
 mod unwrap_or_else;
 pub use self::unwrap_or_else::UnwrapOrElse;
 
@@ -239,6 +255,43 @@ pub trait TryFutureExt: TryFuture {
         ErrInto::new(self)
     }
 
+    /// Maps this future's [`Ok`](TryFuture::Ok) to a new type using the
+    /// [`Into`](std::convert::Into) trait.
+    ///
+    /// This is the counterpart of [`err_into`](TryFutureExt::err_into) for
+    /// the success value, leaving the error untouched. Unlike
+    /// [`map_ok`](TryFutureExt::map_ok), the returned future holds no
+    /// closure, so its type can be written out.
+    ///
+    /// Note that this method consumes the future it is called on and returns a
+    /// wrapped version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future::{self, BoxFuture, FutureExt, TryFutureExt};
+    ///
+    /// fn fetch(small: bool) -> BoxFuture<'static, Result<u64, String>> {
+    ///     if small {
+    ///         future::ok::<u8, String>(1).ok_into().boxed()
+    ///     } else {
+    ///         future::ok::<u32, String>(70_000).ok_into().boxed()
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(fetch(true).await, Ok(1));
+    /// assert_eq!(fetch(false).await, Ok(70_000));
+    /// # });
+    /// ```
+    fn ok_into<T>(self) -> OkInto<Self, T>
+        where Self: Sized,
+              Self::Ok: Into<T>
+    {
+        OkInto::new(self)
+    }
+
     /// Executes another future after this one resolves successfully. The
     /// success value is passed to a closure to create this subsequent future.
     ///
@@ -348,6 +401,11 @@ pub trait TryFutureExt: TryFuture {
     /// let future = future::ok::<_, ()>(1);
     /// let new_future = future.inspect_ok(|&x| println!("about to resolve: {}", x));
     /// assert_eq!(new_future.await, Ok(1));
+    ///
+    /// // The closure isn't called for an error.
+    /// let future = future::err::<i32, _>(2);
+    /// let new_future = future.inspect_ok(|_| panic!("not called"));
+    /// assert_eq!(new_future.await, Err(2));
     /// # });
     /// ```
     fn inspect_ok<F>(self, f: F) -> InspectOk<Self, F>
@@ -374,6 +432,11 @@ pub trait TryFutureExt: TryFuture {
     /// let future = future::err::<(), _>(1);
     /// let new_future = future.inspect_err(|&x| println!("about to error: {}", x));
     /// assert_eq!(new_future.await, Err(1));
+    ///
+    /// // The closure isn't called for a success.
+    /// let future = future::ok::<_, i32>(2);
+    /// let new_future = future.inspect_err(|_| panic!("not called"));
+    /// assert_eq!(new_future.await, Ok(2));
     /// # });
     /// ```
     fn inspect_err<F>(self, f: F) -> InspectErr<Self, F>
@@ -416,6 +479,36 @@ pub trait TryFutureExt: TryFuture {
         TryFlattenStream::new(self)
     }
 
+    /// Converts this future into a single element stream.
+    ///
+    /// The returned stream yields the result of this future, either the
+    /// success value wrapped in [`Ok`] or the error wrapped in [`Err`], and
+    /// then terminates. This is useful when an API expects a [`TryStream`] but
+    /// the value is produced by a single request future.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future::{self, TryFutureExt};
+    /// use futures::stream::TryStreamExt;
+    ///
+    /// let future = future::ok::<_, ()>(17);
+    /// let list = future.ok_into_stream().try_collect::<Vec<_>>().await;
+    /// assert_eq!(list, Ok(vec![17]));
+    ///
+    /// let future = future::err::<i32, _>(());
+    /// let list = future.ok_into_stream().try_collect::<Vec<_>>().await;
+    /// assert_eq!(list, Err(()));
+    /// # });
+    /// ```
+    fn ok_into_stream(self) -> OkIntoStream<Self>
+        where Self: Sized
+    {
+        OkIntoStream::new(self)
+    }
+
     /// Unwraps this future's ouput, producing a future with this future's
     /// [`Ok`](TryFuture::Ok) type as its
     /// [`Output`](std::future::Future::Output) type.
@@ -446,6 +539,77 @@ pub trait TryFutureExt: TryFuture {
         UnwrapOrElse::new(self, f)
     }
 
+    /// Resolves to `default` if `deadline` completes before this future.
+    ///
+    /// This is meant for serving a fallback value, such as a stale cached
+    /// one, when a fresh result takes too long. The deadline is any future,
+    /// usually a timer, and its output is ignored. When it completes first,
+    /// the returned future succeeds with `default`, and this future isn't
+    /// polled anymore. It is dropped along with the returned future.
+    /// Otherwise the returned future resolves to this future's result,
+    /// including its errors.
+    ///
+    /// This future is polled before the deadline, so a result which is ready
+    /// at the same time as the deadline still wins. Use
+    /// [`timeout_with`](TryFutureExt::timeout_with) to compute the fallback
+    /// value only when it's needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// use futures::channel::oneshot;
+    /// use futures::future::{self, TryFutureExt};
+    ///
+    /// # futures::executor::block_on(async {
+    /// let (_tx, slow) = oneshot::channel::<&str>();
+    /// let deadline = future::ready(());
+    /// assert_eq!(slow.timeout_with_default(deadline, "stale").await, Ok("stale"));
+    ///
+    /// let fast = future::ok::<_, ()>("fresh");
+    /// let deadline = future::ready(());
+    /// assert_eq!(fast.timeout_with_default(deadline, "stale").await, Ok("fresh"));
+    /// # });
+    /// ```
+    fn timeout_with_default<D>(
+        self,
+        deadline: D,
+        default: Self::Ok,
+    ) -> TimeoutWithDefault<Self, D>
+        where Self: Sized,
+              D: Future,
+    {
+        TimeoutWithDefault::new(self, deadline, default)
+    }
+
+    /// Resolves to the result of `f` if `deadline` completes before this
+    /// future.
+    ///
+    /// This is like [`timeout_with_default`](TryFutureExt::timeout_with_default),
+    /// except that the fallback value is only computed, by calling `f`, once
+    /// the deadline has completed first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// use futures::future::{self, TryFutureExt};
+    ///
+    /// # futures::executor::block_on(async {
+    /// let cache = vec![1, 2, 3];
+    /// let slow = future::empty::<Result<Vec<i32>, ()>>();
+    /// let deadline = future::ready(());
+    /// assert_eq!(slow.timeout_with(deadline, || cache.clone()).await, Ok(vec![1, 2, 3]));
+    /// # });
+    /// ```
+    fn timeout_with<D, F>(self, deadline: D, f: F) -> TimeoutWith<Self, D, F>
+        where Self: Sized,
+              D: Future,
+              F: FnOnce() -> Self::Ok,
+    {
+        TimeoutWith::new(self, deadline, f)
+    }
+
     /// Wraps a [`TryFuture`] into a future compatable with libraries using
     /// futures 0.1 future definitons. Requires the `compat` feature to enable.
     #[cfg(feature = "compat")]
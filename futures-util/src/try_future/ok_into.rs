@@ -0,0 +1,45 @@
+use core::marker::PhantomData;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future, TryFuture};
+use futures_core::task::{Context, Poll};
+use pin_utils::unsafe_pinned;
+
+/// Future for the [`ok_into`](super::TryFutureExt::ok_into) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct OkInto<Fut, T> {
+    future: Fut,
+    _marker: PhantomData<T>,
+}
+
+impl<Fut: Unpin, T> Unpin for OkInto<Fut, T> {}
+
+impl<Fut, T> OkInto<Fut, T> {
+    unsafe_pinned!(future: Fut);
+
+    pub(super) fn new(future: Fut) -> OkInto<Fut, T> {
+        OkInto {
+            future,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Fut: FusedFuture, T> FusedFuture for OkInto<Fut, T> {
+    fn is_terminated(&self) -> bool { self.future.is_terminated() }
+}
+
+impl<Fut, T> Future for OkInto<Fut, T>
+    where Fut: TryFuture,
+          Fut::Ok: Into<T>,
+{
+    type Output = Result<T, Fut::Error>;
+
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Self::Output> {
+        self.future().try_poll(cx)
+            .map(|res| res.map(Into::into))
+    }
+}
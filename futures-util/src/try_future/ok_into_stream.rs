@@ -0,0 +1,54 @@
+use core::pin::Pin;
+use futures_core::future::TryFuture;
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::task::{Context, Poll};
+use pin_utils::unsafe_pinned;
+
+/// Stream for the [`ok_into_stream`](super::TryFutureExt::ok_into_stream)
+/// method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct OkIntoStream<Fut> {
+    future: Option<Fut>,
+}
+
+impl<Fut> OkIntoStream<Fut> {
+    unsafe_pinned!(future: Option<Fut>);
+
+    pub(super) fn new(future: Fut) -> OkIntoStream<Fut> {
+        OkIntoStream {
+            future: Some(future),
+        }
+    }
+}
+
+impl<Fut: TryFuture> FusedStream for OkIntoStream<Fut> {
+    fn is_terminated(&self) -> bool {
+        self.future.is_none()
+    }
+}
+
+impl<Fut: TryFuture> Stream for OkIntoStream<Fut> {
+    type Item = Result<Fut::Ok, Fut::Error>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let v = match self.as_mut().future().as_pin_mut() {
+            Some(fut) => ready!(fut.try_poll(cx)),
+            None => return Poll::Ready(None),
+        };
+
+        self.as_mut().future().set(None);
+        Poll::Ready(Some(v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.future.is_some() {
+            (1, Some(1))
+        } else {
+            (0, Some(0))
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use core::pin::Pin;
+use futures_core::future::{Future, TryFuture};
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`timeout_with_default`](super::TryFutureExt::timeout_with_default)
+/// method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TimeoutWithDefault<Fut: TryFuture, D> {
+    future: Fut,
+    deadline: D,
+    default: Option<Fut::Ok>,
+}
+
+impl<Fut: TryFuture + Unpin, D: Unpin> Unpin for TimeoutWithDefault<Fut, D> {}
+
+impl<Fut: TryFuture, D: Future> TimeoutWithDefault<Fut, D> {
+    unsafe_pinned!(future: Fut);
+    unsafe_pinned!(deadline: D);
+    unsafe_unpinned!(default: Option<Fut::Ok>);
+
+    pub(super) fn new(future: Fut, deadline: D, default: Fut::Ok) -> Self {
+        TimeoutWithDefault { future, deadline, default: Some(default) }
+    }
+
+    /// Acquires a reference to the underlying future.
+    pub fn get_ref(&self) -> &Fut {
+        &self.future
+    }
+
+    /// Acquires a mutable reference to the underlying future.
+    pub fn get_mut(&mut self) -> &mut Fut {
+        &mut self.future
+    }
+}
+
+impl<Fut: TryFuture, D: Future> Future for TimeoutWithDefault<Fut, D> {
+    type Output = Result<Fut::Ok, Fut::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Poll::Ready(output) = self.as_mut().future().try_poll(cx) {
+            return Poll::Ready(output);
+        }
+        ready!(self.as_mut().deadline().poll(cx));
+        let default = self.as_mut().default().take()
+            .expect("TimeoutWithDefault polled after completion");
+        Poll::Ready(Ok(default))
+    }
+}
+
+/// Future for the [`timeout_with`](super::TryFutureExt::timeout_with) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TimeoutWith<Fut, D, F> {
+    future: Fut,
+    deadline: D,
+    f: Option<F>,
+}
+
+impl<Fut: Unpin, D: Unpin, F> Unpin for TimeoutWith<Fut, D, F> {}
+
+impl<Fut, D, F> TimeoutWith<Fut, D, F>
+    where Fut: TryFuture,
+          D: Future,
+          F: FnOnce() -> Fut::Ok,
+{
+    unsafe_pinned!(future: Fut);
+    unsafe_pinned!(deadline: D);
+    unsafe_unpinned!(f: Option<F>);
+
+    pub(super) fn new(future: Fut, deadline: D, f: F) -> Self {
+        TimeoutWith { future, deadline, f: Some(f) }
+    }
+
+    /// Acquires a reference to the underlying future.
+    pub fn get_ref(&self) -> &Fut {
+        &self.future
+    }
+
+    /// Acquires a mutable reference to the underlying future.
+    pub fn get_mut(&mut self) -> &mut Fut {
+        &mut self.future
+    }
+}
+
+impl<Fut, D, F> Future for TimeoutWith<Fut, D, F>
+    where Fut: TryFuture,
+          D: Future,
+          F: FnOnce() -> Fut::Ok,
+{
+    type Output = Result<Fut::Ok, Fut::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Poll::Ready(output) = self.as_mut().future().try_poll(cx) {
+            return Poll::Ready(output);
+        }
+        ready!(self.as_mut().deadline().poll(cx));
+        let f = self.as_mut().f().take()
+            .expect("TimeoutWith polled after completion");
+        Poll::Ready(Ok(f()))
+    }
+}
@@ -12,6 +12,39 @@ use alloc::vec::Vec;
 
 use super::TryFuture;
 
+cfg_target_has_atomic! {
+    use crate::stream::{FuturesUnordered, StreamExt};
+    use pin_utils::unsafe_pinned;
+
+    // Lists of up to this many futures are polled in place, all of them on
+    // every wakeup, which is cheaper than giving each future its own waker.
+    // Longer lists are driven through `FuturesUnordered`, which only polls the
+    // futures which have been woken.
+    const SMALL: usize = 30;
+
+    // A future along with its position in the input, so that its output can be
+    // put in its place no matter when it completes.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    #[derive(Debug)]
+    struct Indexed<F> {
+        future: F,
+        index: usize,
+    }
+
+    impl<F> Indexed<F> {
+        unsafe_pinned!(future: F);
+    }
+
+    impl<F: TryFuture> Future for Indexed<F> {
+        type Output = (usize, Result<F::Ok, F::Error>);
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let index = self.index;
+            self.as_mut().future().try_poll(cx).map(|output| (index, output))
+        }
+    }
+}
+
 #[derive(Debug)]
 enum ElemState<F>
 where
@@ -66,7 +99,24 @@ pub struct TryJoinAll<F>
 where
     F: TryFuture,
 {
-    elems: Pin<Box<[ElemState<F>]>>,
+    kind: TryJoinAllKind<F>,
+}
+
+enum TryJoinAllKind<F>
+where
+    F: TryFuture,
+{
+    Small {
+        elems: Pin<Box<[ElemState<F>]>>,
+    },
+    #[cfg_attr(
+        feature = "cfg-target-has-atomic",
+        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+    )]
+    Big {
+        in_progress: FuturesUnordered<Indexed<F>>,
+        outputs: Vec<Option<F::Ok>>,
+    },
 }
 
 impl<F> fmt::Debug for TryJoinAll<F>
@@ -76,23 +126,47 @@ where
     F::Error: fmt::Debug,
 {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct("TryJoinAll")
-            .field("elems", &self.elems)
-            .finish()
+        match &self.kind {
+            TryJoinAllKind::Small { elems } => {
+                fmt.debug_struct("TryJoinAll")
+                    .field("elems", elems)
+                    .finish()
+            }
+            #[cfg_attr(
+                feature = "cfg-target-has-atomic",
+                cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+            )]
+            TryJoinAllKind::Big { in_progress, outputs } => {
+                fmt.debug_struct("TryJoinAll")
+                    .field("in_progress", in_progress)
+                    .field("outputs", outputs)
+                    .finish()
+            }
+        }
     }
 }
 
+impl<F: TryFuture> Unpin for TryJoinAll<F> {}
+
 /// Creates a future which represents either a collection of the results of the
 /// futures given or an error.
 ///
 /// The returned future will drive execution for all of its underlying futures,
 /// collecting the results into a destination `Vec<T>` in the same order as they
-/// were provided.
+/// were provided, no matter in which order they complete.
+///
+/// When the iterator says it has only a few futures, all of them which are
+/// still pending are polled whenever the returned future is woken. Longer
+/// lists of futures are driven through
+/// [`FuturesUnordered`](crate::stream::FuturesUnordered) instead, so that each
+/// wakeup only polls the futures which have been woken.
 ///
 /// If any future returns an error then all other futures will be canceled and
-/// an error will be returned immediately. If all futures complete successfully,
-/// however, then the returned future will succeed with a `Vec` of all the
-/// successful results.
+/// an error will be returned immediately, even if futures provided before it
+/// haven't completed yet. The outputs of the futures which did succeed are
+/// dropped along with them. If all futures complete successfully, however,
+/// then the returned future will succeed with a `Vec` of all the successful
+/// results. An empty list of futures succeeds with an empty `Vec`.
 ///
 /// This function is only available when the `std` or `alloc` feature of this
 /// library is activated, and it is activated by default.
@@ -126,10 +200,28 @@ where
     I: IntoIterator,
     I::Item: TryFuture,
 {
-    let elems: Box<[_]> = i.into_iter().map(ElemState::Pending).collect();
-    TryJoinAll {
-        elems: elems.into(),
+    let iter = i.into_iter();
+    #[cfg_attr(
+        feature = "cfg-target-has-atomic",
+        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+    )]
+    {
+        let small = match iter.size_hint().1 {
+            Some(max) => max <= SMALL,
+            None => false,
+        };
+        if !small {
+            let in_progress: FuturesUnordered<_> = iter
+                .enumerate()
+                .map(|(index, future)| Indexed { future, index })
+                .collect();
+            let mut outputs = Vec::with_capacity(in_progress.len());
+            outputs.resize_with(in_progress.len(), || None);
+            return TryJoinAll { kind: TryJoinAllKind::Big { in_progress, outputs } };
+        }
     }
+    let elems: Box<[_]> = iter.map(ElemState::Pending).collect();
+    TryJoinAll { kind: TryJoinAllKind::Small { elems: elems.into() } }
 }
 
 impl<F> Future for TryJoinAll<F>
@@ -139,36 +231,62 @@ where
     type Output = Result<Vec<F::Ok>, F::Error>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let mut state = FinalState::AllDone;
-
-        for mut elem in iter_pin_mut(self.elems.as_mut()) {
-            if let Some(pending) = elem.as_mut().pending_pin_mut() {
-                match pending.try_poll(cx) {
-                    Poll::Pending => state = FinalState::Pending,
-                    Poll::Ready(output) => match output {
-                        Ok(item) => elem.set(ElemState::Done(Some(item))),
-                        Err(e) => {
-                            state = FinalState::Error(e);
-                            break;
+        match &mut self.kind {
+            TryJoinAllKind::Small { elems } => {
+                let mut state = FinalState::AllDone;
+
+                for mut elem in iter_pin_mut(elems.as_mut()) {
+                    if let Some(pending) = elem.as_mut().pending_pin_mut() {
+                        match pending.try_poll(cx) {
+                            Poll::Pending => state = FinalState::Pending,
+                            Poll::Ready(output) => match output {
+                                Ok(item) => elem.set(ElemState::Done(Some(item))),
+                                Err(e) => {
+                                    state = FinalState::Error(e);
+                                    break;
+                                }
+                            }
                         }
                     }
                 }
-            }
-        }
 
-        match state {
-            FinalState::Pending => Poll::Pending,
-            FinalState::AllDone => {
-                let mut elems = mem::replace(&mut self.elems, Box::pin([]));
-                let results = iter_pin_mut(elems.as_mut())
-                    .map(|e| e.take_done().unwrap())
-                    .collect();
-                Poll::Ready(Ok(results))
-            },
-            FinalState::Error(e) => {
-                let _ = mem::replace(&mut self.elems, Box::pin([]));
-                Poll::Ready(Err(e))
-            },
+                match state {
+                    FinalState::Pending => Poll::Pending,
+                    FinalState::AllDone => {
+                        let mut elems = mem::replace(elems, Box::pin([]));
+                        let results = iter_pin_mut(elems.as_mut())
+                            .map(|e| e.take_done().unwrap())
+                            .collect();
+                        Poll::Ready(Ok(results))
+                    },
+                    FinalState::Error(e) => {
+                        let _ = mem::replace(elems, Box::pin([]));
+                        Poll::Ready(Err(e))
+                    },
+                }
+            }
+            #[cfg_attr(
+                feature = "cfg-target-has-atomic",
+                cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+            )]
+            TryJoinAllKind::Big { in_progress, outputs } => {
+                loop {
+                    match ready!(in_progress.poll_next_unpin(cx)) {
+                        Some((index, Ok(output))) => outputs[index] = Some(output),
+                        Some((_, Err(e))) => {
+                            // Cancel the remaining futures and drop the outputs
+                            // collected so far.
+                            *in_progress = FuturesUnordered::new();
+                            *outputs = Vec::new();
+                            return Poll::Ready(Err(e));
+                        }
+                        None => {
+                            let outputs = mem::replace(outputs, Vec::new());
+                            return Poll::Ready(Ok(outputs.into_iter().map(Option::unwrap).collect()));
+                        }
+                    }
+                }
+            }
         }
     }
 }
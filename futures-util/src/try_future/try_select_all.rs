@@ -0,0 +1,197 @@
+use crate::try_future::TryFutureExt;
+use core::fmt;
+use core::mem;
+use core::pin::Pin;
+use alloc::vec::Vec;
+use futures_core::future::{Future, TryFuture};
+use futures_core::task::{Context, Poll};
+
+/// Future for the [`try_select_all`] function.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TrySelectAll<Fut> {
+    inner: Vec<Fut>,
+}
+
+impl<Fut: Unpin> Unpin for TrySelectAll<Fut> {}
+
+/// Creates a new future which will select over a list of fallible futures.
+///
+/// The returned future will wait for any future within `iter` to be ready.
+/// If that future succeeded, its output is returned along with the index of
+/// the future and the list of all the remaining futures. If it failed, its
+/// error is returned and the remaining futures are dropped.
+///
+/// Unlike [`select_all`](crate::future::select_all), an empty `iter` doesn't
+/// panic but resolves to [`TrySelectAllError::Empty`]. To keep racing the
+/// remaining futures when one of them fails, use [`try_select_all_ok`].
+///
+/// This function is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await)]
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, TrySelectAllError};
+///
+/// let futures = vec![future::err::<i32, i32>(1), future::ok(2)];
+/// assert_eq!(future::try_select_all(futures).await.err(), Some(TrySelectAllError::Failed(vec![1])));
+///
+/// let futures: Vec<future::Ready<Result<i32, i32>>> = Vec::new();
+/// assert_eq!(future::try_select_all(futures).await.err(), Some(TrySelectAllError::Empty));
+/// # });
+/// ```
+pub fn try_select_all<I>(iter: I) -> TrySelectAll<I::Item>
+    where I: IntoIterator,
+          I::Item: TryFuture + Unpin,
+{
+    TrySelectAll {
+        inner: iter.into_iter().collect()
+    }
+}
+
+impl<Fut: TryFuture + Unpin> Future for TrySelectAll<Fut> {
+    type Output = Result<(Fut::Ok, usize, Vec<Fut>), TrySelectAllError<Fut::Error>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.inner.is_empty() {
+            return Poll::Ready(Err(TrySelectAllError::Empty))
+        }
+        let item = self.inner.iter_mut().enumerate().find_map(|(i, f)| {
+            match f.try_poll_unpin(cx) {
+                Poll::Pending => None,
+                Poll::Ready(e) => Some((i, e)),
+            }
+        });
+        match item {
+            Some((idx, res)) => {
+                self.inner.remove(idx);
+                let rest = mem::replace(&mut self.inner, Vec::new());
+                match res {
+                    Ok(e) => Poll::Ready(Ok((e, idx, rest))),
+                    Err(e) => Poll::Ready(Err(TrySelectAllError::Failed(alloc::vec![e]))),
+                }
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+
+/// Future for the [`try_select_all_ok`] function.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TrySelectAllOk<Fut: TryFuture> {
+    inner: Vec<Fut>,
+    errors: Vec<Fut::Error>,
+}
+
+impl<Fut: TryFuture + Unpin> Unpin for TrySelectAllOk<Fut> {}
+
+/// Creates a new future which will select the first successful future over a
+/// list of fallible futures, collecting the errors of those which fail.
+///
+/// The returned future will wait for any future within `iter` to be ready
+/// and `Ok`. Futures which fail are dropped, and the remaining ones keep
+/// being raced. This is useful to race requests to several replicas, where
+/// any answer will do.
+///
+/// On success, the output is returned along with the list of the futures
+/// which haven't completed yet. If all futures fail, their errors are
+/// returned in the order they failed in. Unlike
+/// [`select_ok`](crate::future::select_ok), an empty `iter` doesn't panic
+/// but resolves to [`TrySelectAllError::Empty`].
+///
+/// This function is only available when the `std` or `alloc` feature of this
+/// library is activated, and it is activated by default.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await)]
+/// # futures::executor::block_on(async {
+/// use futures::future::{self, TrySelectAllError};
+///
+/// let futures = vec![future::err::<i32, i32>(1), future::ok(2), future::ok(3)];
+/// let (value, rest) = future::try_select_all_ok(futures).await.unwrap();
+/// assert_eq!(value, 2);
+/// assert_eq!(rest.len(), 1);
+///
+/// let futures = vec![future::err::<i32, i32>(1), future::err(2)];
+/// assert_eq!(future::try_select_all_ok(futures).await.err(), Some(TrySelectAllError::Failed(vec![1, 2])));
+/// # });
+/// ```
+pub fn try_select_all_ok<I>(iter: I) -> TrySelectAllOk<I::Item>
+    where I: IntoIterator,
+          I::Item: TryFuture + Unpin,
+{
+    TrySelectAllOk {
+        inner: iter.into_iter().collect(),
+        errors: Vec::new(),
+    }
+}
+
+impl<Fut: TryFuture + Unpin> Future for TrySelectAllOk<Fut> {
+    type Output = Result<(Fut::Ok, Vec<Fut>), TrySelectAllError<Fut::Error>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // loop until we've either exhausted all errors, a success was hit, or nothing is ready
+        loop {
+            if self.inner.is_empty() {
+                let errors = mem::replace(&mut self.errors, Vec::new());
+                return Poll::Ready(Err(if errors.is_empty() {
+                    TrySelectAllError::Empty
+                } else {
+                    TrySelectAllError::Failed(errors)
+                }))
+            }
+            let item = self.inner.iter_mut().enumerate().find_map(|(i, f)| {
+                match f.try_poll_unpin(cx) {
+                    Poll::Pending => None,
+                    Poll::Ready(e) => Some((i, e)),
+                }
+            });
+            match item {
+                Some((idx, res)) => {
+                    drop(self.inner.remove(idx));
+                    match res {
+                        Ok(e) => {
+                            let rest = mem::replace(&mut self.inner, Vec::new());
+                            return Poll::Ready(Ok((e, rest)))
+                        }
+                        Err(e) => self.errors.push(e),
+                    }
+                }
+                None => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Error returned by [`try_select_all`] and [`try_select_all_ok`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrySelectAllError<E> {
+    /// There were no futures to select over.
+    Empty,
+    /// The futures failed with these errors, in the order they failed in.
+    ///
+    /// [`try_select_all`] gives up on the first error, so this only ever
+    /// holds one error there.
+    Failed(Vec<E>),
+}
+
+impl<E: fmt::Display> fmt::Display for TrySelectAllError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrySelectAllError::Empty => write!(f, "no futures to select over"),
+            TrySelectAllError::Failed(errors) => match errors.last() {
+                Some(e) => e.fmt(f),
+                None => write!(f, "all futures failed"),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: fmt::Debug + fmt::Display> std::error::Error for TrySelectAllError<E> {}
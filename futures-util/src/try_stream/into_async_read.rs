@@ -1,8 +1,8 @@
-use crate::try_stream::TryStreamExt;
 use core::pin::Pin;
 use futures_core::stream::TryStream;
 use futures_core::task::{Context, Poll};
 use futures_io::{AsyncRead, AsyncBufRead};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
 use std::cmp;
 use std::io::{Error, Result};
 
@@ -11,7 +11,7 @@ use std::io::{Error, Result};
 #[must_use = "streams do nothing unless polled"]
 pub struct IntoAsyncRead<St>
 where
-    St: TryStream<Error = Error> + Unpin,
+    St: TryStream<Error = Error>,
     St::Ok: AsRef<[u8]>,
 {
     stream: St,
@@ -34,9 +34,12 @@ enum ReadState<T: AsRef<[u8]>> {
 
 impl<St> IntoAsyncRead<St>
 where
-    St: TryStream<Error = Error> + Unpin,
+    St: TryStream<Error = Error>,
     St::Ok: AsRef<[u8]>,
 {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(state: ReadState<St::Ok>);
+
     pub(super) fn new(stream: St) -> Self {
         IntoAsyncRead {
             stream,
@@ -47,7 +50,7 @@ where
 
 impl<St> AsyncRead for IntoAsyncRead<St>
 where
-    St: TryStream<Error = Error> + Unpin,
+    St: TryStream<Error = Error>,
     St::Ok: AsRef<[u8]>,
 {
     fn poll_read(
@@ -56,7 +59,7 @@ where
         buf: &mut [u8],
     ) -> Poll<Result<usize>> {
         loop {
-            match &mut self.state {
+            match self.as_mut().state() {
                 ReadState::Ready { chunk, chunk_start } => {
                     let chunk = chunk.as_ref();
                     let len = cmp::min(buf.len(), chunk.len() - *chunk_start);
@@ -67,27 +70,27 @@ where
                     *chunk_start += len;
 
                     if chunk.len() == *chunk_start {
-                        self.state = ReadState::PendingChunk;
+                        *self.as_mut().state() = ReadState::PendingChunk;
                     }
 
                     return Poll::Ready(Ok(len));
                 }
                 ReadState::PendingChunk => {
-                    match ready!(self.stream.try_poll_next_unpin(cx)) {
+                    match ready!(self.as_mut().stream().try_poll_next(cx)) {
                         Some(Ok(chunk)) => {
                             if !chunk.as_ref().is_empty() {
-                                self.state = ReadState::Ready {
+                                *self.as_mut().state() = ReadState::Ready {
                                     chunk,
                                     chunk_start: 0,
                                 };
                             }
                         }
                         Some(Err(err)) => {
-                            self.state = ReadState::Eof;
+                            *self.as_mut().state() = ReadState::Eof;
                             return Poll::Ready(Err(err));
                         }
                         None => {
-                            self.state = ReadState::Eof;
+                            *self.as_mut().state() = ReadState::Eof;
                             return Poll::Ready(Ok(0));
                         }
                     }
@@ -102,7 +105,7 @@ where
 
 impl<St> AsyncBufRead for IntoAsyncRead<St>
 where
-    St: TryStream<Error = Error> + Unpin,
+    St: TryStream<Error = Error>,
     St::Ok: AsRef<[u8]>,
 {
     fn poll_fill_buf<'a>(
@@ -110,21 +113,21 @@ where
         cx: &mut Context<'_>,
     ) -> Poll<Result<&'a [u8]>> {
         while let ReadState::PendingChunk = self.state {
-            match ready!(self.stream.try_poll_next_unpin(cx)) {
+            match ready!(self.as_mut().stream().try_poll_next(cx)) {
                 Some(Ok(chunk)) => {
                     if !chunk.as_ref().is_empty() {
-                        self.state = ReadState::Ready {
+                        *self.as_mut().state() = ReadState::Ready {
                             chunk,
                             chunk_start: 0,
                         };
                     }
                 }
                 Some(Err(err)) => {
-                    self.state = ReadState::Eof;
+                    *self.as_mut().state() = ReadState::Eof;
                     return Poll::Ready(Err(err));
                 }
                 None => {
-                    self.state = ReadState::Eof;
+                    *self.as_mut().state() = ReadState::Eof;
                     return Poll::Ready(Ok(&[]));
                 }
             }
@@ -145,11 +148,12 @@ where
     ) {
          // https://github.com/rust-lang-nursery/futures-rs/pull/1556#discussion_r281644295
         if amount == 0 { return }
-        if let ReadState::Ready { chunk, chunk_start } = &mut self.state {
+        let state = self.as_mut().state();
+        if let ReadState::Ready { chunk, chunk_start } = state {
             *chunk_start += amount;
             debug_assert!(*chunk_start <= chunk.as_ref().len());
             if *chunk_start >= chunk.as_ref().len() {
-                self.state = ReadState::PendingChunk;
+                *state = ReadState::PendingChunk;
             }
         } else {
             debug_assert!(false, "Attempted to consume from IntoAsyncRead without chunk");
@@ -38,6 +38,18 @@ pub use self::or_else::OrElse;
 mod try_next;
 pub use self::try_next::TryNext;
 
+mod try_last;
+pub use self::try_last::TryLast;
+
+mod try_nth;
+pub use self::try_nth::TryNth;
+
+mod try_all;
+pub use self::try_all::TryAll;
+
+mod try_any;
+pub use self::try_any::TryAny;
+
 mod try_for_each;
 pub use self::try_for_each::TryForEach;
 
@@ -53,12 +65,39 @@ pub use self::try_collect::TryCollect;
 mod try_concat;
 pub use self::try_concat::TryConcat;
 
+#[cfg(feature = "alloc")]
+mod try_concat_with_capacity;
+#[cfg(feature = "alloc")]
+pub use self::try_concat_with_capacity::TryConcatWithCapacity;
+
+#[cfg(feature = "alloc")]
+mod try_chunks;
+#[cfg(feature = "alloc")]
+pub use self::try_chunks::{TryChunks, TryChunksError};
+
+#[cfg(feature = "alloc")]
+mod try_ready_chunks;
+#[cfg(feature = "alloc")]
+pub use self::try_ready_chunks::{TryReadyChunks, TryReadyChunksError};
+
+mod try_flatten;
+pub use self::try_flatten::TryFlatten;
+
 mod try_fold;
 pub use self::try_fold::TryFold;
 
+mod try_fold_with_checkpoint;
+pub use self::try_fold_with_checkpoint::TryFoldWithCheckpoint;
+
 mod try_skip_while;
 pub use self::try_skip_while::TrySkipWhile;
 
+mod try_take_while;
+pub use self::try_take_while::TryTakeWhile;
+
+mod try_unfold;
+pub use self::try_unfold::{try_unfold, TryUnfold};
+
 cfg_target_has_atomic! {
     #[cfg(feature = "alloc")]
     mod try_buffer_unordered;
@@ -69,6 +108,11 @@ cfg_target_has_atomic! {
     mod try_for_each_concurrent;
     #[cfg(feature = "alloc")]
     pub use self::try_for_each_concurrent::TryForEachConcurrent;
+
+    #[cfg(feature = "alloc")]
+    mod try_flatten_unordered;
+    #[cfg(feature = "alloc")]
+    pub use self::try_flatten_unordered::TryFlattenUnordered;
 }
 
 #[cfg(feature = "std")]
@@ -221,6 +265,22 @@ pub trait TryStreamExt: TryStream {
     ///
     /// Note that this function consumes the receiving stream and returns a
     /// wrapped version of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, StreamExt, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Err(0), Ok(3), Err(-1)]);
+    /// let stream = stream.or_else(|e| future::ready(if e == 0 { Ok(2) } else { Err("negative") }));
+    ///
+    /// let items: Vec<_> = stream.collect().await;
+    /// assert_eq!(items, vec![Ok(1), Ok(2), Ok(3), Err("negative")]);
+    /// # });
+    /// ```
     fn or_else<Fut, F>(self, f: F) -> OrElse<Self, Fut, F>
         where F: FnMut(Self::Error) -> Fut,
               Fut: TryFuture<Ok = Self::Ok>,
@@ -235,6 +295,25 @@ pub trait TryStreamExt: TryStream {
     /// This is similar to the `StreamExt::inspect` method where it allows
     /// easily inspecting the success value as it passes through the stream, for
     /// example to debug what's going on.
+    ///
+    /// The closure is called as each success value is yielded, so it sees them
+    /// in the order of the stream; errors are passed on without calling it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let mut seen = Vec::new();
+    /// let stream = stream::iter(vec![Ok(1), Err("oops"), Ok(2)])
+    ///     .inspect_ok(|x| seen.push(*x));
+    ///
+    /// assert_eq!(stream.try_collect::<Vec<i32>>().await, Err("oops"));
+    /// assert_eq!(seen, vec![1]);
+    /// # });
+    /// ```
     fn inspect_ok<F>(self, f: F) -> InspectOk<Self, F>
         where F: FnMut(&Self::Ok),
               Self: Sized,
@@ -247,6 +326,26 @@ pub trait TryStreamExt: TryStream {
     /// This is similar to the `StreamExt::inspect` method where it allows
     /// easily inspecting the error value as it passes through the stream, for
     /// example to debug what's going on.
+    ///
+    /// The closure is called as each error is yielded, so it sees them in the
+    /// order of the stream; success values are passed on without calling it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt, TryStreamExt};
+    ///
+    /// let mut errors = 0;
+    /// let stream = stream::iter(vec![Ok(1), Err("a"), Ok(2), Err("b")])
+    ///     .inspect_err(|_| errors += 1);
+    ///
+    /// let items: Vec<Result<i32, &str>> = stream.collect().await;
+    /// assert_eq!(items, vec![Ok(1), Err("a"), Ok(2), Err("b")]);
+    /// assert_eq!(errors, 2);
+    /// # });
+    /// ```
     fn inspect_err<F>(self, f: F) -> InspectErr<Self, F>
         where F: FnMut(&Self::Error),
               Self: Sized,
@@ -308,6 +407,130 @@ pub trait TryStreamExt: TryStream {
         TryNext::new(self)
     }
 
+    /// Attempts to run this stream to completion, returning its last
+    /// successful item.
+    ///
+    /// This is similar to the [`StreamExt::last`](crate::stream::StreamExt::last)
+    /// combinator, but returns early with the first error encountered in the
+    /// stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Ok(2)]);
+    /// assert_eq!(stream.try_last().await, Ok(Some(2)));
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Err(2), Ok(3)]);
+    /// assert_eq!(stream.try_last().await, Err(2));
+    /// # })
+    /// ```
+    fn try_last(self) -> TryLast<Self>
+        where Self: Sized,
+    {
+        TryLast::new(self)
+    }
+
+    /// Attempts to return the `n`th successful item of this stream, counting
+    /// from zero.
+    ///
+    /// This is similar to the [`StreamExt::nth`](crate::stream::StreamExt::nth)
+    /// combinator, but returns early with the first error encountered in the
+    /// stream before the `n`th item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Ok(2), Err(3)]);
+    /// assert_eq!(stream.try_nth(1).await, Ok(Some(2)));
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Err(2), Ok(3)]);
+    /// assert_eq!(stream.try_nth(2).await, Err(2));
+    /// # })
+    /// ```
+    fn try_nth(self, n: usize) -> TryNth<Self>
+        where Self: Sized,
+    {
+        TryNth::new(self, n)
+    }
+
+    /// Execute a predicate over the successful items of this stream,
+    /// resolving to `Ok(false)` as soon as the predicate fails for any item.
+    ///
+    /// This is similar to [`StreamExt::all`](crate::stream::StreamExt::all),
+    /// but resolves to the first error of the stream encountered before the
+    /// answer is known. Once a predicate future resolves to `false`, neither
+    /// the stream nor the predicate is polled any further. An empty stream
+    /// resolves to `Ok(true)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(2), Ok(4), Ok(6)]);
+    /// assert_eq!(stream.try_all(|x| future::ready(x % 2 == 0)).await, Ok(true));
+    ///
+    /// let stream = stream::iter(vec![Ok(2), Ok(5), Err(6)]);
+    /// assert_eq!(stream.try_all(|x| future::ready(x % 2 == 0)).await, Ok(false));
+    ///
+    /// let stream = stream::iter(vec![Ok(2), Err(4), Ok(5)]);
+    /// assert_eq!(stream.try_all(|x| future::ready(x % 2 == 0)).await, Err(4));
+    /// # })
+    /// ```
+    fn try_all<Fut, F>(self, f: F) -> TryAll<Self, Fut, F>
+        where F: FnMut(Self::Ok) -> Fut,
+              Fut: Future<Output = bool>,
+              Self: Sized
+    {
+        TryAll::new(self, f)
+    }
+
+    /// Execute a predicate over the successful items of this stream,
+    /// resolving to `Ok(true)` as soon as the predicate holds for any item.
+    ///
+    /// This is similar to [`StreamExt::any`](crate::stream::StreamExt::any),
+    /// but resolves to the first error of the stream encountered before the
+    /// answer is known. Once a predicate future resolves to `true`, neither
+    /// the stream nor the predicate is polled any further. An empty stream
+    /// resolves to `Ok(false)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Ok(3), Ok(5)]);
+    /// assert_eq!(stream.try_any(|x| future::ready(x % 2 == 0)).await, Ok(false));
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Ok(4), Err(5)]);
+    /// assert_eq!(stream.try_any(|x| future::ready(x % 2 == 0)).await, Ok(true));
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Err(3), Ok(4)]);
+    /// assert_eq!(stream.try_any(|x| future::ready(x % 2 == 0)).await, Err(3));
+    /// # })
+    /// ```
+    fn try_any<Fut, F>(self, f: F) -> TryAny<Self, Fut, F>
+        where F: FnMut(Self::Ok) -> Fut,
+              Fut: Future<Output = bool>,
+              Self: Sized
+    {
+        TryAny::new(self, f)
+    }
+
     /// Attempts to run this stream to completion, executing the provided
     /// asynchronous closure for each element on the stream.
     ///
@@ -351,10 +574,14 @@ pub trait TryStreamExt: TryStream {
     }
 
     /// Skip elements on this stream while the provided asynchronous predicate
-    /// resolves to `true`.
+    /// resolves to `Ok(true)`.
     ///
     /// This function is similar to [`StreamExt::skip_while`](crate::stream::StreamExt::skip_while)
-    /// but exits early if an error occurs.
+    /// but the predicate may fail. Once it resolves to `Ok(false)`, that item
+    /// and all the following ones are passed through without calling the
+    /// predicate again. Errors from either this stream or the predicate are
+    /// yielded without ending the skipping; the item the predicate failed on
+    /// is dropped.
     ///
     /// # Examples
     ///
@@ -379,6 +606,39 @@ pub trait TryStreamExt: TryStream {
         TrySkipWhile::new(self, f)
     }
 
+    /// Take elements on this stream while the provided asynchronous predicate
+    /// resolves to `Ok(true)`.
+    ///
+    /// This function is similar to
+    /// [`StreamExt::take_while`](crate::stream::StreamExt::take_while) but the
+    /// predicate may fail. The stream ends, without yielding the item, once
+    /// the predicate resolves to `Ok(false)`. An error from either this stream
+    /// or the predicate is yielded and then ends the stream as well; the item
+    /// the predicate failed on is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let stream = stream::iter(vec![Ok::<i32, i32>(1), Ok(2), Ok(3), Ok(2)]);
+    /// let stream = stream.try_take_while(|x| future::ready(Ok(*x < 3)));
+    ///
+    /// let output: Result<Vec<i32>, i32> = stream.try_collect().await;
+    /// assert_eq!(output, Ok(vec![1, 2]));
+    /// # })
+    /// ```
+    fn try_take_while<Fut, F>(self, f: F) -> TryTakeWhile<Self, Fut, F>
+        where F: FnMut(&Self::Ok) -> Fut,
+              Fut: TryFuture<Ok = bool, Error = Self::Error>,
+              Self: Sized
+    {
+        TryTakeWhile::new(self, f)
+    }
+
     /// Attempts to run this stream to completion, executing the provided asynchronous
     /// closure for each element on the stream concurrently as elements become
     /// available, exiting as soon as an error occurs.
@@ -388,6 +648,20 @@ pub trait TryStreamExt: TryStream {
     /// but will resolve to an error immediately if the underlying stream or the provided
     /// closure return an error.
     ///
+    /// The first argument is an optional limit on the number of concurrent
+    /// futures. If this limit is not `None`, no more than `limit` futures
+    /// will be run concurrently. The `limit` argument is of type
+    /// `Into<Option<usize>>`, and so can be provided as either `None`,
+    /// `Some(10)`, or just `10`. Note: a limit of zero is interpreted as
+    /// no limit at all, and will have the same result as passing in `None`.
+    ///
+    /// On the first error, whether it comes from the stream or from one of
+    /// the futures, the stream is dropped without being polled again and the
+    /// other futures still in flight are dropped, cancelling them. They are
+    /// not awaited, and the returned future resolves with the error right
+    /// away. Completed futures are checked before the next item is pulled,
+    /// so the closure isn't called again once a future has failed.
+    ///
     /// This method is only available when the `std` or `alloc` feature of this
     /// library is activated, and it is activated by default.
     ///
@@ -594,6 +868,102 @@ pub trait TryStreamExt: TryStream {
         TryFold::new(self, f, init)
     }
 
+    /// Like [`try_fold`](TryStreamExt::try_fold), but periodically hands the
+    /// accumulator to a checkpoint closure so progress can be persisted.
+    ///
+    /// After every `every` items have been folded into the accumulator,
+    /// `checkpoint` is called with a reference to the current accumulator and
+    /// the future it returns is awaited before the next item is pulled from
+    /// the stream. A long-running fold can use this to save its progress and,
+    /// after a restart, resume by skipping the items already folded into the
+    /// saved accumulator.
+    ///
+    /// An error returned by the stream, the folding closure or a checkpoint
+    /// ends the fold early with that error.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `every` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::future;
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let mut saved = Vec::new();
+    /// let stream = stream::iter((1..=5).map(Ok::<i32, ()>));
+    /// let sum = stream.try_fold_with_checkpoint(
+    ///     0,
+    ///     |acc, x| future::ready(Ok(acc + x)),
+    ///     2,
+    ///     |acc| {
+    ///         saved.push(*acc);
+    ///         future::ready(Ok(()))
+    ///     },
+    /// );
+    ///
+    /// assert_eq!(sum.await, Ok(15));
+    /// assert_eq!(saved, vec![3, 10]);
+    /// # })
+    /// ```
+    fn try_fold_with_checkpoint<T, Fut, F, CFut, C>(
+        self,
+        init: T,
+        f: F,
+        every: usize,
+        checkpoint: C,
+    ) -> TryFoldWithCheckpoint<Self, Fut, T, F, CFut, C>
+        where F: FnMut(T, Self::Ok) -> Fut,
+              Fut: TryFuture<Ok = T, Error = Self::Error>,
+              C: FnMut(&T) -> CFut,
+              CFut: TryFuture<Ok = (), Error = Self::Error>,
+              Self: Sized,
+    {
+        TryFoldWithCheckpoint::new(self, init, f, every, checkpoint)
+    }
+
+    /// Flattens a stream of streams into just one continuous stream.
+    ///
+    /// This is the fallible counterpart of
+    /// [`flatten`](crate::stream::StreamExt::flatten): every successful item
+    /// of this stream is itself a [`TryStream`], whose successful items are
+    /// yielded in order before the next inner stream is pulled from this
+    /// stream. Errors of the inner streams are converted into the error type
+    /// of this stream.
+    ///
+    /// Errors from either this stream or the current inner stream are yielded
+    /// as items of the returned stream and don't terminate it. An error of
+    /// this stream takes the place of an inner stream, so the returned stream
+    /// carries on with the inner stream after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt, TryStreamExt};
+    ///
+    /// let pages = stream::iter(vec![
+    ///     Ok(stream::iter(vec![Ok(1), Ok(2)])),
+    ///     Err("no such page"),
+    ///     Ok(stream::iter(vec![Ok(3), Err("truncated page")])),
+    /// ]);
+    ///
+    /// let items: Vec<_> = pages.try_flatten().collect().await;
+    /// assert_eq!(items, vec![Ok(1), Ok(2), Err("no such page"), Ok(3), Err("truncated page")]);
+    /// # });
+    /// ```
+    fn try_flatten(self) -> TryFlatten<Self>
+        where Self::Ok: TryStream,
+              Self::Error: From<<Self::Ok as TryStream>::Error>,
+              Self: Sized,
+    {
+        TryFlatten::new(self)
+    }
+
     /// Attempt to concatenate all items of a stream into a single
     /// extendable destination, returning a future representing the end result.
     ///
@@ -606,6 +976,14 @@ pub trait TryStreamExt: TryStream {
     /// This method is similar to [`concat`](super::StreamExt::concat), but will
     /// exit early if an error is encountered in the stream.
     ///
+    /// The first chunk becomes the accumulator without being copied, and every
+    /// later chunk is appended to it in place through `Extend`, so the cost is
+    /// linear in the total length. A generic `Extend` collection can't reserve
+    /// room ahead of time though, so a `Vec` still grows by reallocating. When
+    /// the total length is known up front, collecting into a `Vec` with
+    /// [`try_concat_with_capacity`](TryStreamExt::try_concat_with_capacity)
+    /// avoids that.
+    ///
     /// # Examples
     ///
     // TODO: https://github.com/rust-lang-nursery/futures-rs/issues/1657
@@ -638,6 +1016,133 @@ pub trait TryStreamExt: TryStream {
         TryConcat::new(self)
     }
 
+    /// Attempt to concatenate all chunks of a stream into a single vector,
+    /// returning a future representing the end result.
+    ///
+    /// This is similar to [`try_concat`](TryStreamExt::try_concat), but the
+    /// result is always a `Vec` that starts out with room for `capacity`
+    /// elements, which avoids reallocating it while collecting when the total
+    /// size is known up front, e.g. from a `Content-Length` header. The chunks
+    /// can be any type that can be turned into an iterator over the elements,
+    /// such as `Vec<u8>`. If more than `capacity` elements arrive, the vector
+    /// keeps growing geometrically.
+    ///
+    /// Exits early with the error if one is encountered in the stream.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, TryStreamExt};
+    ///
+    /// let chunks = vec![Ok::<_, ()>(vec![1u8, 2]), Ok(vec![3]), Ok(vec![4, 5])];
+    /// let body = stream::iter(chunks).try_concat_with_capacity(5).await.unwrap();
+    ///
+    /// assert_eq!(body, vec![1, 2, 3, 4, 5]);
+    /// assert!(body.capacity() >= 5);
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_concat_with_capacity<T>(self, capacity: usize) -> TryConcatWithCapacity<Self, T>
+    where Self: Sized,
+          Self::Ok: IntoIterator<Item = T>,
+    {
+        TryConcatWithCapacity::new(self, capacity)
+    }
+
+    /// An adaptor for chunking up successful items of the stream inside a
+    /// vector.
+    ///
+    /// This combinator will attempt to pull successful items from this stream
+    /// and buffer them into a local vector. At most `capacity` items will get
+    /// buffered before they're yielded from the returned stream. If the
+    /// underlying stream ended and only a partial vector was created, it'll be
+    /// returned.
+    ///
+    /// If an error is encountered, it is yielded as a [`TryChunksError`]
+    /// together with the items buffered so far, so that they aren't lost.
+    /// The stream is not terminated by an error: subsequent successful items
+    /// are chunked as usual.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt, TryStreamExt, TryChunksError};
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Ok(2), Ok(3), Err("oops"), Ok(4)]);
+    /// let chunks: Vec<_> = stream.try_chunks(2).collect().await;
+    ///
+    /// assert_eq!(chunks, vec![
+    ///     Ok(vec![1, 2]),
+    ///     Err(TryChunksError(vec![3], "oops")),
+    ///     Ok(vec![4]),
+    /// ]);
+    /// # });
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` is zero.
+    #[cfg(feature = "alloc")]
+    fn try_chunks(self, capacity: usize) -> TryChunks<Self>
+        where Self: Sized,
+    {
+        TryChunks::new(self, capacity)
+    }
+
+    /// An adaptor for chunking up the successful items of the stream that are
+    /// ready right now inside a vector.
+    ///
+    /// Unlike [`try_chunks`](TryStreamExt::try_chunks), this doesn't wait for
+    /// a chunk to fill up: whenever the underlying stream returns
+    /// [`Poll::Pending`], the items collected so far are yielded. Chunks
+    /// contain at most `capacity` items and are never empty.
+    ///
+    /// If an error is encountered, it is yielded as a
+    /// [`TryReadyChunksError`] together with the items buffered so far, so
+    /// that they aren't lost. The stream is not terminated by an error:
+    /// subsequent successful items are chunked as usual.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt, TryStreamExt, TryReadyChunksError};
+    ///
+    /// let stream = stream::iter(vec![Ok(1), Ok(2), Ok(3), Err("oops"), Ok(4)]);
+    /// let chunks: Vec<_> = stream.try_ready_chunks(2).collect().await;
+    ///
+    /// assert_eq!(chunks, vec![
+    ///     Ok(vec![1, 2]),
+    ///     Err(TryReadyChunksError(vec![3], "oops")),
+    ///     Ok(vec![4]),
+    /// ]);
+    /// # });
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` is zero.
+    #[cfg(feature = "alloc")]
+    fn try_ready_chunks(self, capacity: usize) -> TryReadyChunks<Self>
+        where Self: Sized,
+    {
+        TryReadyChunks::new(self, capacity)
+    }
+
     /// Attempt to execute several futures from a stream concurrently.
     ///
     /// This stream's `Ok` type must be a [`TryFuture`](futures_core::future::TryFuture) with an `Error` type
@@ -710,6 +1215,66 @@ pub trait TryStreamExt: TryStream {
         TryBufferUnordered::new(self, n)
     }
 
+    /// Flattens a stream of streams into just one continuous stream, running
+    /// up to `limit` of the inner streams concurrently.
+    ///
+    /// This is the fallible and concurrent counterpart of
+    /// [`flatten`](crate::stream::StreamExt::flatten): every successful item
+    /// of this stream is itself a [`TryStream`], and up to `limit` of those
+    /// inner streams are polled at the same time. Their successful items are
+    /// yielded in the order in which they become available, so items of
+    /// different inner streams may be interleaved. Only the inner streams
+    /// which have been woken up are polled again.
+    ///
+    /// Errors from either this stream or any of the inner streams are yielded
+    /// as items of the returned stream and don't terminate it, so the other
+    /// inner streams keep going. Use e.g.
+    /// [`try_for_each`](TryStreamExt::try_for_each) or
+    /// [`try_collect`](TryStreamExt::try_collect) to stop at the first error
+    /// instead. The returned stream ends once this stream and all of the inner
+    /// streams have ended.
+    ///
+    /// The `limit` argument is of type `Into<Option<usize>>`, and so can be
+    /// provided as either `None`, `Some(10)`, or just `10`. Note: a limit of
+    /// zero is interpreted as no limit at all.
+    ///
+    /// This method is only available when the `std` or `alloc` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, StreamExt, TryStreamExt};
+    ///
+    /// let pages = stream::iter(vec![
+    ///     Ok(stream::iter(vec![Ok(1), Ok(2)])),
+    ///     Err("no such page"),
+    ///     Ok(stream::iter(vec![Ok(3), Err("truncated page")])),
+    /// ]);
+    ///
+    /// let mut items: Vec<_> = pages.try_flatten_unordered(2).collect().await;
+    /// items.sort();
+    /// assert_eq!(items, vec![Ok(1), Ok(2), Ok(3), Err("no such page"), Err("truncated page")]);
+    /// # });
+    /// ```
+    #[cfg_attr(
+        feature = "cfg-target-has-atomic",
+        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+    )]
+    #[cfg(feature = "alloc")]
+    fn try_flatten_unordered(
+        self,
+        limit: impl Into<Option<usize>>,
+    ) -> TryFlattenUnordered<Self>
+        where Self::Ok: TryStream,
+              <Self::Ok as TryStream>::Error: From<Self::Error>,
+              Self: Sized,
+    {
+        TryFlattenUnordered::new(self, limit.into())
+    }
+
     // TODO: false positive warning from rustdoc. Verify once #43466 settles
     //
     /// A convenience method for calling [`TryStream::try_poll_next`] on [`Unpin`]
@@ -754,10 +1319,9 @@ pub trait TryStreamExt: TryStream {
 
     /// Adapter that converts this stream into an [`AsyncRead`](crate::io::AsyncRead).
     ///
-    /// Note that because `into_async_read` moves the stream, the [`Stream`](futures_core::stream::Stream) type must be
-    /// [`Unpin`]. If you want to use `into_async_read` with a [`!Unpin`](Unpin) stream, you'll
-    /// first have to pin the stream. This can be done by boxing the stream using [`Box::pin`]
-    /// or pinning it to the stack using the `pin_mut!` macro from the `pin_utils` crate.
+    /// The returned reader is only [`Unpin`] if the stream is. To read from a
+    /// reader wrapping a [`!Unpin`](Unpin) stream, pin it first, e.g. with
+    /// [`Box::pin`] or the `pin_mut!` macro from the `pin_utils` crate.
     ///
     /// This method is only available when the `std` feature of this
     /// library is activated, and it is activated by default.
@@ -781,9 +1345,50 @@ pub trait TryStreamExt: TryStream {
     #[cfg(feature = "std")]
     fn into_async_read(self) -> IntoAsyncRead<Self>
     where
-        Self: Sized + TryStreamExt<Error = std::io::Error> + Unpin,
+        Self: Sized + TryStreamExt<Error = std::io::Error>,
         Self::Ok: AsRef<[u8]>,
     {
         IntoAsyncRead::new(self)
     }
+
+    /// Adapter that converts this stream into an [`AsyncRead`](crate::io::AsyncRead),
+    /// converting the errors of the stream into [`std::io::Error`]s with `f`.
+    ///
+    /// This is a shorthand for `self.map_err(f).into_async_read()`, for
+    /// streams whose error type isn't [`std::io::Error`] already, such as
+    /// HTTP bodies or decoders with their own error type. `f` is called once
+    /// for every error, when it is returned by the reader. Like
+    /// [`into_async_read`](TryStreamExt::into_async_read), the reader only
+    /// returns end-of-file after the first error.
+    ///
+    /// This method is only available when the `std` feature of this
+    /// library is activated, and it is activated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_await)]
+    /// # futures::executor::block_on(async {
+    /// use futures::stream::{self, TryStreamExt};
+    /// use futures::io::AsyncReadExt;
+    /// use std::io;
+    ///
+    /// let stream = stream::iter(vec![Ok(vec![1, 2, 3]), Err("connection reset")]);
+    /// let mut reader = stream.into_async_read_map_err(|e| io::Error::new(io::ErrorKind::Other, e));
+    /// let mut buf = Vec::new();
+    ///
+    /// let err = reader.read_to_end(&mut buf).await.unwrap_err();
+    /// assert_eq!(err.to_string(), "connection reset");
+    /// assert_eq!(buf, &[1, 2, 3]);
+    /// # })
+    /// ```
+    #[cfg(feature = "std")]
+    fn into_async_read_map_err<F>(self, f: F) -> IntoAsyncRead<MapErr<Self, F>>
+    where
+        Self: Sized,
+        Self::Ok: AsRef<[u8]>,
+        F: FnMut(Self::Error) -> std::io::Error,
+    {
+        IntoAsyncRead::new(self.map_err(f))
+    }
 }
@@ -0,0 +1,84 @@
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::TryStream;
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`try_any`](super::TryStreamExt::try_any) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TryAny<St, Fut, F> {
+    stream: St,
+    f: F,
+    done: bool,
+    future: Option<Fut>,
+}
+
+impl<St, Fut, F> Unpin for TryAny<St, Fut, F>
+where St: TryStream + Unpin,
+      F: FnMut(St::Ok) -> Fut,
+      Fut: Future<Output = bool> + Unpin,
+{}
+
+impl<St, Fut, F> TryAny<St, Fut, F>
+where St: TryStream,
+      F: FnMut(St::Ok) -> Fut,
+      Fut: Future<Output = bool>,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(done: bool);
+    unsafe_pinned!(future: Option<Fut>);
+
+    pub(super) fn new(stream: St, f: F) -> TryAny<St, Fut, F> {
+        TryAny {
+            stream,
+            f,
+            done: false,
+            future: None,
+        }
+    }
+}
+
+impl<St, Fut, F> FusedFuture for TryAny<St, Fut, F> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St, Fut, F> Future for TryAny<St, Fut, F>
+    where St: TryStream,
+          F: FnMut(St::Ok) -> Fut,
+          Fut: Future<Output = bool>,
+{
+    type Output = Result<bool, St::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        assert!(!self.done, "TryAny polled after completion");
+        loop {
+            if let Some(future) = self.as_mut().future().as_pin_mut() {
+                let result = ready!(future.poll(cx));
+                self.as_mut().future().set(None);
+                if result {
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(Ok(true));
+                }
+            }
+
+            match ready!(self.as_mut().stream().try_poll_next(cx)) {
+                Some(Ok(item)) => {
+                    let future = (self.as_mut().f())(item);
+                    self.as_mut().future().set(Some(future));
+                }
+                Some(Err(e)) => {
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(Err(e));
+                }
+                None => {
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(Ok(false));
+                }
+            }
+        }
+    }
+}
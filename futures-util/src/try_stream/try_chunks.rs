@@ -0,0 +1,132 @@
+use crate::stream::{ChunkBuf, Fuse, StreamExt};
+use crate::try_stream::IntoStream;
+use alloc::vec::Vec;
+use core::fmt;
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream, TryStream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`try_chunks`](super::TryStreamExt::try_chunks) method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct TryChunks<St: TryStream> {
+    stream: Fuse<IntoStream<St>>,
+    items: ChunkBuf<St::Ok>,
+}
+
+impl<St: TryStream + Unpin> Unpin for TryChunks<St> {}
+
+impl<St: TryStream> TryChunks<St> {
+    unsafe_unpinned!(items: ChunkBuf<St::Ok>);
+    unsafe_pinned!(stream: Fuse<IntoStream<St>>);
+
+    pub(super) fn new(stream: St, capacity: usize) -> TryChunks<St> {
+        TryChunks {
+            stream: IntoStream::new(stream).fuse(),
+            items: ChunkBuf::new(capacity),
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.stream.get_ref().get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.stream.get_mut().get_mut()
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut St> {
+        self.stream().get_pin_mut().get_pin_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream.into_inner().into_inner()
+    }
+}
+
+impl<St: TryStream> Stream for TryChunks<St> {
+    type Item = Result<Vec<St::Ok>, TryChunksError<St::Ok, St::Error>>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            match ready!(self.as_mut().stream().poll_next(cx)) {
+                Some(Ok(item)) => {
+                    self.as_mut().items().push(item);
+                    if self.items.is_full() {
+                        return Poll::Ready(Some(Ok(self.as_mut().items().take())))
+                    }
+                }
+
+                // Hand the items gathered so far back along with the error,
+                // and start a fresh chunk with whatever comes next.
+                Some(Err(e)) => {
+                    let items = self.as_mut().items().take();
+                    return Poll::Ready(Some(Err(TryChunksError(items, e))))
+                }
+
+                None => {
+                    let last = if self.items.is_empty() {
+                        None
+                    } else {
+                        Some(Ok(self.as_mut().items().take()))
+                    };
+
+                    return Poll::Ready(last);
+                }
+            }
+        }
+    }
+}
+
+impl<St: TryStream> FusedStream for TryChunks<St> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated() && self.items.is_empty()
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, Item> Sink<Item> for TryChunks<S>
+where
+    S: TryStream + Sink<Item>,
+{
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream, Item);
+}
+
+/// Error yielded by [`TryChunks`] when the underlying stream fails.
+///
+/// It carries the items collected into the current chunk before the error
+/// occurred, followed by the error itself.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TryChunksError<T, E>(pub Vec<T>, pub E);
+
+impl<T, E: fmt::Display> fmt::Display for TryChunksError<T, E> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.1.fmt(fmt)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug, E: fmt::Debug + fmt::Display> std::error::Error for TryChunksError<T, E> {}
@@ -0,0 +1,67 @@
+use core::mem;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::{FusedStream, TryStream};
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+use alloc::vec::Vec;
+
+/// Future for the
+/// [`try_concat_with_capacity`](super::TryStreamExt::try_concat_with_capacity)
+/// method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TryConcatWithCapacity<St, T> {
+    stream: St,
+    accum: Vec<T>,
+}
+
+impl<St: Unpin, T> Unpin for TryConcatWithCapacity<St, T> {}
+
+impl<St, T> TryConcatWithCapacity<St, T>
+where
+    St: TryStream,
+    St::Ok: IntoIterator<Item = T>,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(accum: Vec<T>);
+
+    pub(super) fn new(stream: St, capacity: usize) -> TryConcatWithCapacity<St, T> {
+        TryConcatWithCapacity {
+            stream,
+            accum: Vec::with_capacity(capacity),
+        }
+    }
+}
+
+impl<St, T> FusedFuture for TryConcatWithCapacity<St, T>
+where
+    St: TryStream + FusedStream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<St, T> Future for TryConcatWithCapacity<St, T>
+where
+    St: TryStream,
+    St::Ok: IntoIterator<Item = T>,
+{
+    type Output = Result<Vec<T>, St::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            match ready!(self.as_mut().stream().try_poll_next(cx)?) {
+                // `Vec::extend` reserves based on the size hint of the chunk
+                // and grows the buffer geometrically, so collecting `n`
+                // elements costs `O(log n)` reallocations at most, and none
+                // if the capacity hint was large enough.
+                Some(chunk) => self.as_mut().accum().extend(chunk),
+                None => {
+                    return Poll::Ready(Ok(mem::replace(self.as_mut().accum(), Vec::new())))
+                }
+            }
+        }
+    }
+}
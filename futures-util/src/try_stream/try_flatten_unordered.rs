@@ -0,0 +1,151 @@
+use crate::stream::{Fuse, FuturesUnordered, StreamExt, StreamFuture};
+use crate::try_stream::IntoStream;
+use alloc::boxed::Box;
+use core::fmt;
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use futures_core::stream::{FusedStream, Stream, TryStream};
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+// An inner stream waiting for its next item
+type InnerFuture<St> = StreamFuture<Pin<Box<IntoStream<<St as TryStream>::Ok>>>>;
+
+/// Stream for the
+/// [`try_flatten_unordered`](super::TryStreamExt::try_flatten_unordered)
+/// method.
+#[must_use = "streams do nothing unless polled"]
+pub struct TryFlattenUnordered<St>
+    where St: TryStream,
+{
+    stream: Fuse<IntoStream<St>>,
+    // Each inner stream waits here for its next item, so that an inner stream
+    // ending is noticed and its slot can be given to the next one.
+    inner: FuturesUnordered<InnerFuture<St>>,
+    limit: Option<NonZeroUsize>,
+}
+
+impl<St> Unpin for TryFlattenUnordered<St>
+    where St: TryStream + Unpin,
+{}
+
+impl<St> fmt::Debug for TryFlattenUnordered<St>
+    where St: TryStream + fmt::Debug,
+          St::Ok: TryStream,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryFlattenUnordered")
+            .field("stream", &self.stream)
+            .field("active", &self.inner.len())
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+impl<St> TryFlattenUnordered<St>
+    where St: TryStream,
+          St::Ok: TryStream,
+{
+    unsafe_pinned!(stream: Fuse<IntoStream<St>>);
+    unsafe_unpinned!(inner: FuturesUnordered<InnerFuture<St>>);
+
+    pub(super) fn new(stream: St, limit: Option<usize>) -> Self {
+        TryFlattenUnordered {
+            stream: IntoStream::new(stream).fuse(),
+            inner: FuturesUnordered::new(),
+            // Note: `limit` = 0 gets ignored.
+            limit: limit.and_then(NonZeroUsize::new),
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        self.stream.get_ref().get_ref()
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        self.stream.get_mut().get_mut()
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut St> {
+        self.stream().get_pin_mut().get_pin_mut()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream.into_inner().into_inner()
+    }
+}
+
+impl<St> Stream for TryFlattenUnordered<St>
+    where St: TryStream,
+          St::Ok: TryStream,
+          <St::Ok as TryStream>::Error: From<St::Error>,
+{
+    type Item = Result<<St::Ok as TryStream>::Ok, <St::Ok as TryStream>::Error>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            // First up, try to start as many inner streams as the limit allows
+            let mut outer_pending = false;
+            while self.limit.map(|limit| limit.get() > self.inner.len()).unwrap_or(true) {
+                match self.as_mut().stream().poll_next(cx) {
+                    Poll::Ready(Some(Ok(inner))) => {
+                        self.as_mut().inner().push(Box::pin(IntoStream::new(inner)).into_future());
+                    }
+                    Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e.into()))),
+                    Poll::Ready(None) => break,
+                    Poll::Pending => {
+                        outer_pending = true;
+                        break
+                    }
+                }
+            }
+
+            // Only the inner streams which were woken up are polled here
+            match ready!(self.as_mut().inner().poll_next_unpin(cx)) {
+                Some((Some(item), rest)) => {
+                    self.as_mut().inner().push(rest.into_future());
+                    return Poll::Ready(Some(item));
+                }
+                // An inner stream ended, which made room for the outer stream
+                // to be polled again.
+                Some((None, _)) => {}
+                None => {
+                    if self.stream.is_done() {
+                        return Poll::Ready(None);
+                    }
+                    if outer_pending {
+                        return Poll::Pending;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<St> FusedStream for TryFlattenUnordered<St>
+    where St: TryStream,
+          St::Ok: TryStream,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_done() && self.inner.is_empty()
+    }
+}
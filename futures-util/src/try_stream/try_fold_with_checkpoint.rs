@@ -0,0 +1,143 @@
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future, TryFuture};
+use futures_core::stream::TryStream;
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the
+/// [`try_fold_with_checkpoint`](super::TryStreamExt::try_fold_with_checkpoint)
+/// method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TryFoldWithCheckpoint<St, Fut, T, F, CFut, C> {
+    stream: St,
+    f: F,
+    checkpoint: C,
+    every: usize,
+    count: usize,
+    accum: Option<T>,
+    future: Option<Fut>,
+    checkpoint_future: Option<CFut>,
+}
+
+impl<St, Fut, T, F, CFut, C> Unpin for TryFoldWithCheckpoint<St, Fut, T, F, CFut, C>
+where
+    St: Unpin,
+    Fut: Unpin,
+    CFut: Unpin,
+{}
+
+impl<St, Fut, T, F, CFut, C> TryFoldWithCheckpoint<St, Fut, T, F, CFut, C>
+where St: TryStream,
+      F: FnMut(T, St::Ok) -> Fut,
+      Fut: TryFuture<Ok = T, Error = St::Error>,
+      C: FnMut(&T) -> CFut,
+      CFut: TryFuture<Ok = (), Error = St::Error>,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_unpinned!(checkpoint: C);
+    unsafe_unpinned!(count: usize);
+    unsafe_unpinned!(accum: Option<T>);
+    unsafe_pinned!(future: Option<Fut>);
+    unsafe_pinned!(checkpoint_future: Option<CFut>);
+
+    pub(super) fn new(
+        stream: St,
+        t: T,
+        f: F,
+        every: usize,
+        checkpoint: C,
+    ) -> TryFoldWithCheckpoint<St, Fut, T, F, CFut, C> {
+        assert!(every > 0, "checkpoint interval must be greater than zero");
+
+        TryFoldWithCheckpoint {
+            stream,
+            f,
+            checkpoint,
+            every,
+            count: 0,
+            accum: Some(t),
+            future: None,
+            checkpoint_future: None,
+        }
+    }
+
+    fn terminate(mut self: Pin<&mut Self>) {
+        *self.as_mut().accum() = None;
+        self.as_mut().future().set(None);
+        self.as_mut().checkpoint_future().set(None);
+    }
+}
+
+impl<St, Fut, T, F, CFut, C> FusedFuture for TryFoldWithCheckpoint<St, Fut, T, F, CFut, C> {
+    fn is_terminated(&self) -> bool {
+        self.accum.is_none() && self.future.is_none()
+    }
+}
+
+impl<St, Fut, T, F, CFut, C> Future for TryFoldWithCheckpoint<St, Fut, T, F, CFut, C>
+    where St: TryStream,
+          F: FnMut(T, St::Ok) -> Fut,
+          Fut: TryFuture<Ok = T, Error = St::Error>,
+          C: FnMut(&T) -> CFut,
+          CFut: TryFuture<Ok = (), Error = St::Error>,
+{
+    type Output = Result<T, St::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            // we're currently waiting for a checkpoint to complete
+            if let Some(checkpoint_future) = self.as_mut().checkpoint_future().as_pin_mut() {
+                if let Err(e) = ready!(checkpoint_future.try_poll(cx)) {
+                    self.terminate();
+                    return Poll::Ready(Err(e));
+                }
+                self.as_mut().checkpoint_future().set(None);
+            }
+
+            // we're currently processing a future to produce a new accum value
+            if self.accum.is_none() {
+                let accum = match ready!(
+                    self.as_mut().future().as_pin_mut()
+                       .expect("TryFoldWithCheckpoint polled after completion")
+                       .try_poll(cx)
+                ) {
+                    Ok(accum) => accum,
+                    Err(e) => {
+                        self.terminate();
+                        return Poll::Ready(Err(e));
+                    }
+                };
+                self.as_mut().future().set(None);
+
+                *self.as_mut().count() += 1;
+                if self.count == self.every {
+                    *self.as_mut().count() = 0;
+                    let checkpoint_future = (self.as_mut().checkpoint())(&accum);
+                    self.as_mut().checkpoint_future().set(Some(checkpoint_future));
+                    *self.as_mut().accum() = Some(accum);
+                    continue;
+                }
+                *self.as_mut().accum() = Some(accum);
+            }
+
+            let item = match ready!(self.as_mut().stream().try_poll_next(cx)) {
+                Some(Ok(item)) => Some(item),
+                Some(Err(e)) => {
+                    self.terminate();
+                    return Poll::Ready(Err(e));
+                }
+                None => None,
+            };
+            let accum = self.as_mut().accum().take().unwrap();
+
+            if let Some(e) = item {
+                let future = (self.as_mut().f())(accum, e);
+                self.as_mut().future().set(Some(future));
+            } else {
+                return Poll::Ready(Ok(accum))
+            }
+        }
+    }
+}
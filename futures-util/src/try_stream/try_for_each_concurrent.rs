@@ -49,6 +49,14 @@ where St: TryStream,
             futures: FuturesUnordered::new(),
         }
     }
+
+    // Drops the stream and every future in flight, so that no more work is
+    // done after the first error and the future counts as completed.
+    fn abort(mut self: Pin<&mut Self>, e: St::Error) -> St::Error {
+        self.as_mut().stream().set(None);
+        drop(mem::replace(self.as_mut().futures(), FuturesUnordered::new()));
+        e
+    }
 }
 
 impl<St, Fut, F> Future for TryForEachConcurrent<St, Fut, F>
@@ -62,6 +70,20 @@ impl<St, Fut, F> Future for TryForEachConcurrent<St, Fut, F>
         loop {
             let mut made_progress_this_iter = false;
 
+            // Check on the futures in flight before pulling another item, so
+            // that no item is pulled and no future is started once one of
+            // them has failed.
+            match self.as_mut().futures().poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(()))) => made_progress_this_iter = true,
+                Poll::Ready(None) => {
+                    if self.stream.is_none() {
+                        return Poll::Ready(Ok(()))
+                    }
+                },
+                Poll::Pending => {}
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Err(self.abort(e))),
+            }
+
             // Try and pull an item from the stream
             let current_len = self.futures.len();
             // Check if we've already created a number of futures greater than `limit`
@@ -71,45 +93,20 @@ impl<St, Fut, F> Future for TryForEachConcurrent<St, Fut, F>
                     None => Poll::Ready(None),
                 };
 
-                let elem = match poll_res {
+                match poll_res {
                     Poll::Ready(Some(Ok(elem))) => {
                         made_progress_this_iter = true;
-                        Some(elem)
+                        let next_future = (self.as_mut().f())(elem);
+                        self.as_mut().futures().push(next_future);
                     },
                     Poll::Ready(None) => {
+                        if self.stream.is_some() {
+                            made_progress_this_iter = true;
+                        }
                         self.as_mut().stream().set(None);
-                        None
-                    }
-                    Poll::Pending => None,
-                    Poll::Ready(Some(Err(e))) => {
-                        // Empty the stream and futures so that we know
-                        // the future has completed.
-                        self.as_mut().stream().set(None);
-                        drop(mem::replace(self.as_mut().futures(), FuturesUnordered::new()));
-                        return Poll::Ready(Err(e));
                     }
-                };
-
-                if let Some(elem) = elem {
-                    let next_future = (self.as_mut().f())(elem);
-                    self.as_mut().futures().push(next_future);
-                }
-            }
-
-            match self.as_mut().futures().poll_next_unpin(cx) {
-                Poll::Ready(Some(Ok(()))) => made_progress_this_iter = true,
-                Poll::Ready(None) => {
-                    if self.stream.is_none() {
-                        return Poll::Ready(Ok(()))
-                    }
-                },
-                Poll::Pending => {}
-                Poll::Ready(Some(Err(e))) => {
-                    // Empty the stream and futures so that we know
-                    // the future has completed.
-                    self.as_mut().stream().set(None);
-                    drop(mem::replace(self.as_mut().futures(), FuturesUnordered::new()));
-                    return Poll::Ready(Err(e));
+                    Poll::Pending => {}
+                    Poll::Ready(Some(Err(e))) => return Poll::Ready(Err(self.abort(e))),
                 }
             }
 
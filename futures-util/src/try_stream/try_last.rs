@@ -0,0 +1,57 @@
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::TryStream;
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`try_last`](super::TryStreamExt::try_last) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TryLast<St: TryStream> {
+    stream: St,
+    last: Option<St::Ok>,
+    done: bool,
+}
+
+impl<St: TryStream + Unpin> Unpin for TryLast<St> {}
+
+impl<St: TryStream> TryLast<St> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(last: Option<St::Ok>);
+    unsafe_unpinned!(done: bool);
+
+    pub(super) fn new(stream: St) -> TryLast<St> {
+        TryLast {
+            stream,
+            last: None,
+            done: false,
+        }
+    }
+}
+
+impl<St: TryStream> FusedFuture for TryLast<St> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St: TryStream> Future for TryLast<St> {
+    type Output = Result<Option<St::Ok>, St::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        assert!(!self.done, "TryLast polled after completion");
+        loop {
+            match ready!(self.as_mut().stream().try_poll_next(cx)) {
+                Some(Ok(item)) => *self.as_mut().last() = Some(item),
+                Some(Err(e)) => {
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(Err(e));
+                }
+                None => {
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(Ok(self.as_mut().last().take()));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future};
+use futures_core::stream::TryStream;
+use futures_core::task::{Context, Poll};
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Future for the [`try_nth`](super::TryStreamExt::try_nth) method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TryNth<St> {
+    stream: St,
+    remaining: usize,
+    done: bool,
+}
+
+impl<St: Unpin> Unpin for TryNth<St> {}
+
+impl<St: TryStream> TryNth<St> {
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(remaining: usize);
+    unsafe_unpinned!(done: bool);
+
+    pub(super) fn new(stream: St, n: usize) -> TryNth<St> {
+        TryNth {
+            stream,
+            remaining: n,
+            done: false,
+        }
+    }
+}
+
+impl<St> FusedFuture for TryNth<St> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<St: TryStream> Future for TryNth<St> {
+    type Output = Result<Option<St::Ok>, St::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        assert!(!self.done, "TryNth polled after completion");
+        loop {
+            let item = match ready!(self.as_mut().stream().try_poll_next(cx)) {
+                Some(Ok(item)) => Some(item),
+                Some(Err(e)) => {
+                    *self.as_mut().done() = true;
+                    return Poll::Ready(Err(e));
+                }
+                None => None,
+            };
+            if item.is_none() || self.remaining == 0 {
+                *self.as_mut().done() = true;
+                return Poll::Ready(Ok(item));
+            }
+            *self.as_mut().remaining() -= 1;
+        }
+    }
+}
@@ -99,11 +99,13 @@ impl<St, Fut, F> Stream for TrySkipWhile<St, Fut, F>
                 *self.as_mut().pending_item() = Some(item);
             }
 
-            let skipped = ready!(self.as_mut().pending_fut().as_pin_mut().unwrap().try_poll(cx)?);
+            let res = ready!(self.as_mut().pending_fut().as_pin_mut().unwrap().try_poll(cx));
             let item = self.as_mut().pending_item().take().unwrap();
             self.as_mut().pending_fut().set(None);
 
-            if !skipped {
+            // The item the predicate failed on is dropped, and skipping
+            // carries on with the next one.
+            if !res? {
                 *self.as_mut().done_skipping() = true;
                 return Poll::Ready(Some(Ok(item)))
             }
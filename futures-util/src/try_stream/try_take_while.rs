@@ -0,0 +1,146 @@
+use core::pin::Pin;
+use futures_core::future::TryFuture;
+use futures_core::stream::{FusedStream, Stream, TryStream};
+use futures_core::task::{Context, Poll};
+use futures_sink::Sink;
+use pin_utils::{unsafe_pinned, unsafe_unpinned};
+
+/// Stream for the [`try_take_while`](super::TryStreamExt::try_take_while)
+/// method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct TryTakeWhile<St, Fut, F> where St: TryStream {
+    stream: St,
+    f: F,
+    pending_fut: Option<Fut>,
+    pending_item: Option<St::Ok>,
+    done_taking: bool,
+}
+
+impl<St: Unpin + TryStream, Fut: Unpin, F> Unpin for TryTakeWhile<St, Fut, F> {}
+
+impl<St, Fut, F> TryTakeWhile<St, Fut, F>
+    where St: TryStream,
+          F: FnMut(&St::Ok) -> Fut,
+          Fut: TryFuture<Ok = bool, Error = St::Error>,
+{
+    unsafe_pinned!(stream: St);
+    unsafe_unpinned!(f: F);
+    unsafe_pinned!(pending_fut: Option<Fut>);
+    unsafe_unpinned!(pending_item: Option<St::Ok>);
+    unsafe_unpinned!(done_taking: bool);
+
+    pub(super) fn new(stream: St, f: F) -> TryTakeWhile<St, Fut, F> {
+        TryTakeWhile {
+            stream,
+            f,
+            pending_fut: None,
+            pending_item: None,
+            done_taking: false,
+        }
+    }
+
+    /// Acquires a reference to the underlying stream that this combinator is
+    /// pulling from.
+    pub fn get_ref(&self) -> &St {
+        &self.stream
+    }
+
+    /// Acquires a mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_mut(&mut self) -> &mut St {
+        &mut self.stream
+    }
+
+    /// Acquires a pinned mutable reference to the underlying stream that this
+    /// combinator is pulling from.
+    ///
+    /// Note that care must be taken to avoid tampering with the state of the
+    /// stream which may otherwise confuse this combinator.
+    pub fn get_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut St> {
+        self.stream()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    ///
+    /// Note that this may discard intermediate state of this combinator, so
+    /// care should be taken to avoid losing resources when this is called.
+    pub fn into_inner(self) -> St {
+        self.stream
+    }
+}
+
+impl<St, Fut, F> Stream for TryTakeWhile<St, Fut, F>
+    where St: TryStream,
+          F: FnMut(&St::Ok) -> Fut,
+          Fut: TryFuture<Ok = bool, Error = St::Error>,
+{
+    type Item = Result<St::Ok, St::Error>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        if self.done_taking {
+            return Poll::Ready(None);
+        }
+
+        if self.pending_item.is_none() {
+            let item = match ready!(self.as_mut().stream().try_poll_next(cx)) {
+                Some(Ok(item)) => item,
+                Some(Err(e)) => {
+                    *self.as_mut().done_taking() = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+                None => {
+                    *self.as_mut().done_taking() = true;
+                    return Poll::Ready(None);
+                }
+            };
+            let fut = (self.as_mut().f())(&item);
+            self.as_mut().pending_fut().set(Some(fut));
+            *self.as_mut().pending_item() = Some(item);
+        }
+
+        let take = ready!(self.as_mut().pending_fut().as_pin_mut().unwrap().try_poll(cx));
+        self.as_mut().pending_fut().set(None);
+        let item = self.as_mut().pending_item().take().unwrap();
+
+        match take {
+            Ok(true) => Poll::Ready(Some(Ok(item))),
+            Ok(false) => {
+                *self.as_mut().done_taking() = true;
+                Poll::Ready(None)
+            }
+            // The item the predicate failed on is dropped.
+            Err(e) => {
+                *self.as_mut().done_taking() = true;
+                Poll::Ready(Some(Err(e)))
+            }
+        }
+    }
+}
+
+impl<St, Fut, F> FusedStream for TryTakeWhile<St, Fut, F>
+    where St: TryStream,
+          F: FnMut(&St::Ok) -> Fut,
+          Fut: TryFuture<Ok = bool, Error = St::Error>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done_taking
+    }
+}
+
+// Forwarding impl of Sink from the underlying stream
+impl<S, Fut, F, Item> Sink<Item> for TryTakeWhile<S, Fut, F>
+    where S: TryStream + Sink<Item>,
+          F: FnMut(&S::Ok) -> Fut,
+          Fut: TryFuture<Ok = bool, Error = S::Error>,
+{
+    type SinkError = S::SinkError;
+
+    delegate_sink!(stream, Item);
+}
@@ -66,6 +66,7 @@ compile_error!("The `never-type` feature requires the `nightly` feature as an ex
 
 // Macro reexports
 pub use futures_util::ready; // Readiness propagation
+pub use futures_util::merge; // Zero-allocation stream merging
 #[cfg(feature = "async-await")]
 pub use futures_util::{
     // Async-await
@@ -180,9 +181,10 @@ pub mod executor {
     pub use futures_executor::{
         BlockingStream,
         Enter, EnterError,
-        LocalSpawner, LocalPool,
+        Interrupted,
+        LocalSpawner, LocalPool, NestedSpawn,
         ThreadPool, ThreadPoolBuilder,
-        block_on, block_on_stream, enter,
+        block_on, block_on_interruptible, block_on_stream, enter,
     };
 }
 
@@ -205,13 +207,13 @@ pub mod future {
     };
 
     #[cfg(feature = "alloc")]
-    pub use futures_core::future::BoxFuture;
+    pub use futures_core::future::{BoxFuture, LocalBoxFuture};
 
     pub use futures_util::future::{
         empty, Empty,
         lazy, Lazy,
         maybe_done, MaybeDone,
-        poll_fn, PollFn,
+        poll_fn, PollFn, poll_fn_local, LocalPollFn,
         ready, ok, err, Ready,
         select, Select,
         join, join3, join4, join5,
@@ -221,7 +223,8 @@ pub mod future {
         OptionFuture,
 
         FutureExt,
-        FlattenStream, Flatten, Fuse, Inspect, IntoStream, Map, Then, UnitError,
+        DependOn, FlattenStream, Flatten, Fuse, Inspect, IntoStream, Map, MapInto, Then,
+        UnitError, Unless,
     };
 
     #[cfg(feature = "alloc")]
@@ -239,11 +242,18 @@ pub mod future {
         abortable, Abortable, AbortHandle, AbortRegistration, Aborted,
     };
 
+    #[cfg_attr(
+        feature = "cfg-target-has-atomic",
+        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+    )]
+    #[cfg(feature = "std")]
+    pub use futures_util::future::AbortRegistry;
+
     #[cfg(feature = "std")]
     pub use futures_util::future::{
         Remote, RemoteHandle,
         // For FutureExt:
-        CatchUnwind, Shared,
+        CatchUnwind, Memoize, Shared, WeakShared,
     };
 
     pub use futures_util::try_future::{
@@ -252,8 +262,9 @@ pub mod future {
         try_select, TrySelect,
 
         TryFutureExt,
-        AndThen, ErrInto, FlattenSink, IntoFuture, MapErr, MapOk, OrElse,
-        InspectOk, InspectErr, TryFlattenStream, UnwrapOrElse,
+        AndThen, ErrInto, FlattenSink, IntoFuture, MapErr, MapOk, OkInto, OkIntoStream,
+        OrElse, InspectOk, InspectErr, TimeoutWith, TimeoutWithDefault,
+        TryFlattenStream, UnwrapOrElse,
     };
 
     #[cfg(feature = "never-type")]
@@ -263,6 +274,7 @@ pub mod future {
     pub use futures_util::try_future::{
         try_join_all, TryJoinAll,
         select_ok, SelectOk,
+        try_select_all, try_select_all_ok, TrySelectAll, TrySelectAllOk, TrySelectAllError,
     };
 }
 
@@ -299,9 +311,11 @@ pub mod io {
 
     pub use futures_util::io::{
         AsyncReadExt, AsyncWriteExt, AsyncSeekExt, AsyncBufReadExt, AllowStdIo,
-        BufReader, BufWriter, Close, CopyInto, Flush, Lines, Read, ReadExact,
-        ReadHalf, ReadLine, ReadToEnd, ReadUntil, ReadVectored, Seek, Window,
-        Write, WriteAll, WriteHalf, WriteVectored,
+        BufReader, BufWriter, Close, CopyInto, Flush, Lines, LinesRef, Metered, Read,
+        ReadExact, ReadHalf, ReadLine, ReadMeter, ReadTimeout, ReadToEnd, ReadUntil,
+        ReadVectored, Seek,
+        SharedReadHalf, SharedWriteHalf, TakeLine, TakeWrite, Window, Write, WriteAll,
+        WriteAllFromStream, WriteHalf, WriteVectored,
     };
 }
 
@@ -365,9 +379,9 @@ pub mod sink {
     pub use futures_sink::Sink;
 
     pub use futures_util::sink::{
-        Close, Flush, Send, SendAll, SinkErrInto, SinkMapErr, With,
+        Close, Flush, Send, SendAll, SinkErrInto, SinkFlushEvery, SinkMapErr, With,
         SinkExt, Fanout, Drain, DrainError, drain,
-        WithFlatMap,
+        WithFlatMap, WithTryFlatMap, FromFn, from_fn,
     };
 
     #[cfg(feature = "alloc")]
@@ -391,11 +405,14 @@ pub mod stream {
     };
 
     #[cfg(feature = "alloc")]
-    pub use futures_core::stream::BoxStream;
+    pub use futures_core::stream::{
+        BoxStream, LocalBoxStream, LocalSyncBoxStream, SyncBoxStream,
+    };
 
     pub use futures_util::stream::{
         iter, Iter,
         repeat, Repeat,
+        repeat_with, RepeatWith,
         empty, Empty,
         once, Once,
         poll_fn, PollFn,
@@ -403,16 +420,19 @@ pub mod stream {
         unfold, Unfold,
 
         StreamExt,
-        Chain, Collect, Concat, Enumerate, Filter, FilterMap, Flatten, Fold,
-        Forward, ForEach, Fuse, StreamFuture, Inspect, Map, Next,
-        SelectNextSome, Peekable, Skip, SkipWhile, Take, TakeWhile,
-        Then, Zip
+        All, Any, Chain, Collect, Concat, Dedup, DedupByKey, Enumerate, Filter, FilterMap,
+        Find, Flatten, Fold, Forward, ForEach, Fuse, StreamFuture, Inspect,
+        Interleave, Last, Latest, Map, MapWhile, MergeSorted, Next, NextPinned, Nth, Position,
+        SelectNextSome, Peekable, Skip, SkipWhile, SwitchMap, Take, TakeWhile,
+        TakeWhileInclusive, Then, Unzip, Zip, ZipLongest, EitherOrBoth,
     };
 
     #[cfg(feature = "alloc")]
     pub use futures_util::stream::{
+        merge_sorted_all, MergeSortedAll,
+
         // For StreamExt:
-        Chunks,
+        ArmedPrefetch, Chunks, ChunksBySize, NextMany,
     };
 
     #[cfg_attr(
@@ -426,24 +446,33 @@ pub mod stream {
 
         // For StreamExt:
         BufferUnordered, Buffered, ForEachConcurrent, SplitStream, SplitSink,
-        ReuniteError,
+        ReuniteError, TapEachConcurrent, ThenConcurrent,
 
         select_all, SelectAll,
     };
 
+    #[cfg_attr(
+        feature = "cfg-target-has-atomic",
+        cfg(all(target_has_atomic = "cas", target_has_atomic = "ptr"))
+    )]
+    #[cfg(feature = "std")]
+    pub use futures_util::stream::OnEmpty;
+
     #[cfg(feature = "std")]
     pub use futures_util::stream::{
         // For StreamExt:
-        CatchUnwind,
+        CatchUnwind, Shared,
     };
 
     pub use futures_util::try_stream::{
         TryStreamExt,
         AndThen, ErrInto, MapOk, MapErr, OrElse,
         InspectOk, InspectErr,
-        TryNext, TryForEach, TryFilterMap,
-        TryCollect, TryFold, TrySkipWhile,
+        TryNext, TryLast, TryNth, TryAll, TryAny, TryForEach, TryFilterMap,
+        TryCollect, TryFlatten, TryFold, TryFoldWithCheckpoint, TrySkipWhile, TryTakeWhile,
         IntoStream,
+
+        try_unfold, TryUnfold,
     };
 
     #[cfg_attr(
@@ -453,7 +482,14 @@ pub mod stream {
     #[cfg(feature = "alloc")]
     pub use futures_util::try_stream::{
         // For TryStreamExt:
-        TryBufferUnordered, TryForEachConcurrent,
+        TryBufferUnordered, TryFlattenUnordered, TryForEachConcurrent,
+    };
+
+    #[cfg(feature = "alloc")]
+    pub use futures_util::try_stream::{
+        // For TryStreamExt:
+        TryChunks, TryChunksError, TryConcatWithCapacity, TryReadyChunks,
+        TryReadyChunksError,
     };
 
     #[cfg(feature = "std")]
@@ -1,8 +1,12 @@
 use futures::channel::oneshot;
 use futures::executor::block_on;
-use futures::future::{abortable, Aborted, FutureExt};
-use futures::task::{Context, Poll};
+use futures::executor::LocalPool;
+use futures::future::{self, abortable, Abortable, AbortRegistry, Aborted, FutureExt};
+use futures::stream::{self, StreamExt};
+use futures::task::{Context, LocalSpawnExt, Poll};
 use futures_test::task::new_count_waker;
+use std::cell::RefCell;
+use std::rc::Rc;
 
 #[test]
 fn abortable_works() {
@@ -37,3 +41,70 @@ fn abortable_resolves() {
 
     assert_eq!(Ok(Ok(())), block_on(abortable_rx));
 }
+
+#[test]
+fn abortable_stream_terminates() {
+    let (tx, rx) = futures::channel::mpsc::unbounded::<u32>();
+    let (handle, reg) = futures::future::AbortHandle::new_pair();
+    let mut stream = Abortable::new(rx, reg);
+
+    tx.unbounded_send(1).unwrap();
+    assert_eq!(block_on(stream.next()), Some(1));
+    assert!(!handle.is_aborted());
+    handle.abort();
+    assert!(handle.is_aborted());
+    tx.unbounded_send(2).unwrap();
+    assert_eq!(block_on(stream.next()), None);
+}
+
+#[test]
+fn abort_registry_aborts_all() {
+    let mut pool = LocalPool::new();
+    let mut spawner = pool.spawner();
+    let registry = AbortRegistry::new();
+    let results = Rc::new(RefCell::new(Vec::new()));
+
+    for i in 0..3 {
+        let results = results.clone();
+        let fut = Abortable::new(future::empty::<()>(), registry.register());
+        spawner.spawn_local(fut.map(move |res| {
+            results.borrow_mut().push(format!("future {}: {:?}", i, res));
+        })).unwrap();
+    }
+    for i in 0..2 {
+        let results = results.clone();
+        let st = Abortable::new(
+            stream::iter(vec![1, 2]).chain(stream::once(future::empty())),
+            registry.register(),
+        );
+        spawner.spawn_local(st.collect::<Vec<u32>>().map(move |items| {
+            results.borrow_mut().push(format!("stream {}: {:?}", i, items));
+        })).unwrap();
+    }
+
+    pool.run_until_stalled();
+    assert!(results.borrow().is_empty());
+
+    // A registration that completes normally isn't affected.
+    let done = Abortable::new(future::ready(5), registry.register());
+    assert_eq!(pool.run_until(done), Ok(5));
+
+    assert!(!registry.is_aborted());
+    registry.abort();
+    assert!(registry.is_aborted());
+    pool.run();
+
+    let mut results = results.borrow().clone();
+    results.sort();
+    assert_eq!(results, vec![
+        "future 0: Err(Aborted)",
+        "future 1: Err(Aborted)",
+        "future 2: Err(Aborted)",
+        "stream 0: [1, 2]",
+        "stream 1: [1, 2]",
+    ]);
+
+    // Registrations created after aborting start out aborted.
+    let late = Abortable::new(future::ready(()), registry.clone().register());
+    assert_eq!(block_on(late), Err(Aborted));
+}
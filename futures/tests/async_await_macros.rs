@@ -4,7 +4,7 @@
 use futures::{Poll, pending, poll, join, try_join, select};
 use futures::channel::{mpsc, oneshot};
 use futures::executor::block_on;
-use futures::future::{self, FutureExt};
+use futures::future::{self, FusedFuture, FutureExt};
 use futures::stream::StreamExt;
 use futures::sink::SinkExt;
 use pin_utils::pin_mut;
@@ -100,6 +100,52 @@ fn select_streams() {
     assert!(ran);
 }
 
+#[test]
+fn select_guards() {
+    let (tx, rx) = mpsc::unbounded::<i32>();
+    let mut rx = rx.fuse();
+    let mut ready = future::ready(10).fuse();
+    let mut guard_evaluations = 0;
+    let mut received = Vec::new();
+    for i in 0..3 {
+        tx.unbounded_send(i).unwrap();
+    }
+    drop(tx);
+
+    block_on(async {
+        loop {
+            select! {
+                x = rx.next(), if { guard_evaluations += 1; received.len() < 2 } => {
+                    received.push(x.unwrap());
+                },
+                // disabled until the channel has been read from twice
+                x = ready, if received.len() >= 2 => received.push(x),
+                complete => break,
+            }
+        }
+    });
+
+    // The item left in the channel is never taken, as its branch is disabled
+    // once two items have been received.
+    assert_eq!(received, vec![0, 1, 10]);
+    assert_eq!(guard_evaluations, 4);
+}
+
+#[test]
+fn select_disabled_branches_count_as_complete() {
+    let mut fut = future::ready(1).fuse();
+    let mut res = 0;
+    block_on(async {
+        select! {
+            x = fut, if false => res = x,
+            default => res = 2,
+            complete => res = 3,
+        }
+    });
+    assert_eq!(res, 3);
+    assert!(!fut.is_terminated());
+}
+
 #[test]
 fn select_can_move_uncompleted_futures() {
     let (tx1, rx1) = oneshot::channel::<i32>();
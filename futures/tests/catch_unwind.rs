@@ -0,0 +1,95 @@
+use futures::executor::block_on;
+use futures::future::{self, FusedFuture, Future, FutureExt};
+use futures::stream::{self, FusedStream, Stream, StreamExt};
+use futures::task::{Context, Poll};
+use futures_test::task::noop_context;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::thread;
+
+#[test]
+fn stream_terminates_after_panic() {
+    let mut polls = 0;
+    let inner = stream::poll_fn(move |_| {
+        polls += 1;
+        match polls {
+            1 => Poll::Ready(Some(1)),
+            2 => panic!("boom"),
+            _ => panic!("polled after panicking"),
+        }
+    });
+    let mut stream = inner.fuse().catch_unwind();
+    let mut cx = noop_context();
+
+    assert!(!stream.is_terminated());
+    match stream.poll_next_unpin(&mut cx) {
+        Poll::Ready(Some(Ok(1))) => {}
+        _ => panic!("expected the first item"),
+    }
+    match stream.poll_next_unpin(&mut cx) {
+        Poll::Ready(Some(Err(e))) => assert_eq!(*e.downcast::<&str>().unwrap(), "boom"),
+        _ => panic!("expected the panic payload"),
+    }
+    assert!(stream.is_terminated());
+    assert_eq!(stream.size_hint(), (0, Some(0)));
+    for _ in 0..3 {
+        assert!(stream.poll_next_unpin(&mut cx).map(|item| item.is_none()) == Poll::Ready(true));
+    }
+}
+
+#[test]
+fn stream_size_hint_panic() {
+    struct PanickingHint;
+
+    impl Stream for PanickingHint {
+        type Item = ();
+
+        fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<()>> {
+            Poll::Ready(Some(()))
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            panic!("size_hint")
+        }
+    }
+
+    let mut stream = PanickingHint.catch_unwind();
+    assert_eq!(stream.size_hint(), (0, None));
+    assert!(block_on(stream.next()).unwrap().is_ok());
+}
+
+#[test]
+fn future_drops_panicked_future() {
+    // Panics when polled, and again when dropped afterwards.
+    struct Poisoned;
+
+    impl Future for Poisoned {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<()> {
+            panic!("boom")
+        }
+    }
+
+    impl Drop for Poisoned {
+        fn drop(&mut self) {
+            if !thread::panicking() {
+                panic!("drop")
+            }
+        }
+    }
+
+    let mut fut = AssertUnwindSafe(Poisoned).catch_unwind();
+
+    assert!(!fut.is_terminated());
+    let err = block_on(&mut fut).unwrap_err();
+    assert_eq!(*err.downcast::<&str>().unwrap(), "boom");
+    assert!(fut.is_terminated());
+}
+
+#[test]
+fn future_completes() {
+    let mut fut = future::ready(1).catch_unwind();
+    assert_eq!(block_on(&mut fut).unwrap(), 1);
+    assert!(fut.is_terminated());
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "compat")]
+
+use futures::compat::Sink01CompatExt;
+use futures::executor::block_on;
+use futures::future::Future;
+use futures::sink::SinkExt;
+use futures::stream;
+use futures::task::Poll;
+use futures_test::sink::MockSink;
+use futures_test::task::noop_context;
+use pin_utils::pin_mut;
+use std::io::Cursor;
+use tokio::codec::{FramedWrite, LinesCodec};
+use tokio::prelude::{Async, AsyncSink, Future as Future01, Sink as Sink01, Stream as Stream01};
+
+// A 0.1 sink with room for a single unflushed item, which relies on the
+// caller to drive `poll_complete` instead of notifying the task.
+#[derive(Default)]
+struct OneSlot {
+    slot: Option<u32>,
+    flushed: Vec<u32>,
+}
+
+impl Sink01 for OneSlot {
+    type SinkItem = u32;
+    type SinkError = ();
+
+    fn start_send(&mut self, item: u32) -> Result<AsyncSink<u32>, ()> {
+        if self.slot.is_some() {
+            return Ok(AsyncSink::NotReady(item));
+        }
+        self.slot = Some(item);
+        Ok(AsyncSink::Ready)
+    }
+
+    fn poll_complete(&mut self) -> Result<Async<()>, ()> {
+        self.flushed.extend(self.slot.take());
+        Ok(Async::Ready(()))
+    }
+}
+
+#[test]
+fn sink_01_backpressure_drives_poll_complete() {
+    let mut sink = OneSlot::default().sink_compat();
+    let mut items = stream::iter(vec![1, 2, 3, 4]);
+    {
+        let send_all = sink.send_all(&mut items);
+        pin_mut!(send_all);
+        assert_eq!(send_all.poll(&mut noop_context()), Poll::Ready(Ok(())));
+    }
+    assert_eq!(sink.get_ref().flushed, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn framed_01_sink_is_flushed_on_close() {
+    let mut sink = FramedWrite::new(Cursor::new(Vec::new()), LinesCodec::new()).sink_compat();
+
+    block_on(sink.send("hello".to_string())).unwrap();
+    block_on(sink.send_all(&mut stream::iter(vec!["a".to_string(), "b".to_string()]))).unwrap();
+    block_on(SinkExt::close(&mut sink)).unwrap();
+
+    assert_eq!(sink.get_ref().get_ref().get_ref(), b"hello\na\nb\n");
+}
+
+#[test]
+fn sink_03_is_flushed_and_closed_by_01_forward() {
+    let mut mock = MockSink::<u32, ()>::with_capacity(2);
+    mock.push_ready(Poll::Pending);
+    mock.push_flush(Poll::Pending);
+
+    let items = tokio::prelude::stream::iter_ok::<_, ()>(vec![1, 2, 3, 4, 5]);
+    let (_, sink) = items.forward(mock.compat()).wait().unwrap();
+    let mock = sink.into_inner();
+
+    assert_eq!(mock.items(), vec![1, 2, 3, 4, 5]);
+    assert_eq!(mock.flushed_len(), 5);
+    assert!(mock.is_closed());
+}
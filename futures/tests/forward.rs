@@ -0,0 +1,72 @@
+use futures::executor::block_on;
+use futures::sink::Sink;
+use futures::stream::{self, StreamExt};
+use futures::task::{Context, Poll};
+use std::pin::Pin;
+
+/// Sink that accepts `capacity` items and then fails in `poll_ready`.
+#[derive(Debug)]
+struct FailingSink {
+    items: Vec<i32>,
+    capacity: usize,
+}
+
+impl Sink<i32> for FailingSink {
+    type SinkError = &'static str;
+
+    fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        if self.items.len() < self.capacity {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Ready(Err("full"))
+        }
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: i32) -> Result<(), Self::SinkError> {
+        self.items.push(item);
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::SinkError>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[test]
+fn recover_parts_after_sink_error() {
+    let sink = FailingSink { items: Vec::new(), capacity: 2 };
+    let mut forward = stream::iter(vec![Ok(1), Ok(2), Ok(3), Ok(4)]).forward(sink);
+
+    assert_eq!(block_on(&mut forward), Err("full"));
+
+    let (sink, stream, item) = forward.into_parts();
+    let mut sink = sink.unwrap();
+    assert_eq!(sink.items, vec![1, 2]);
+    // The item pulled from the stream while the sink failed is not lost.
+    assert_eq!(item, Some(3));
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![Ok(4)]);
+
+    // The sink can be reused after making room.
+    sink.capacity = 4;
+    let forward = stream::iter(vec![Ok(3), Ok(4)]).forward(&mut sink);
+    assert_eq!(block_on(forward), Ok(()));
+    assert_eq!(sink.items, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn sink_is_gone_after_completion() {
+    let sink = FailingSink { items: Vec::new(), capacity: 2 };
+    let mut forward = stream::iter(vec![Ok(1), Ok(2)]).forward(sink);
+
+    assert!(Pin::new(&mut forward).sink_pin_mut().is_some());
+    assert_eq!(block_on(&mut forward), Ok(()));
+    assert!(Pin::new(&mut forward).sink_pin_mut().is_none());
+
+    let (sink, _stream, item) = forward.into_parts();
+    assert!(sink.is_none());
+    assert_eq!(item, None);
+}
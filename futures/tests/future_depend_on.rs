@@ -0,0 +1,57 @@
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::{self, FusedFuture, FutureExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+struct Guard(&'static str, Rc<RefCell<Vec<&'static str>>>);
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        self.1.borrow_mut().push(self.0);
+    }
+}
+
+#[test]
+fn resource_dropped_on_completion() {
+    let mut cx = noop_context();
+    let dropped = Rc::new(RefCell::new(Vec::new()));
+    let (tx, rx) = oneshot::channel::<i32>();
+    let mut fut = rx.fuse().depend_on(Guard("permit", dropped.clone()));
+
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Pending);
+    assert!(fut.get_resource().is_some());
+    assert!(dropped.borrow().is_empty());
+
+    tx.send(1).unwrap();
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(Ok(1)));
+    // Dropped right away, not only once the combinator goes away.
+    assert_eq!(*dropped.borrow(), vec!["permit"]);
+    assert!(fut.get_resource().is_none());
+    assert!(fut.is_terminated());
+}
+
+#[test]
+fn resource_outlives_dropped_future() {
+    let dropped = Rc::new(RefCell::new(Vec::new()));
+    let inner = future::empty::<()>().depend_on(Guard("future", dropped.clone()));
+    let fut = inner.depend_on(Guard("permit", dropped.clone()));
+
+    drop(fut);
+    assert_eq!(*dropped.borrow(), vec!["future", "permit"]);
+}
+
+#[test]
+fn into_inner_releases_resource() {
+    let dropped = Rc::new(RefCell::new(Vec::new()));
+    let fut = future::ready(5).depend_on(Guard("permit", dropped.clone()));
+
+    let (fut, resource) = fut.into_inner();
+    assert!(resource.is_some());
+    assert_eq!(block_on(fut), 5);
+    assert!(dropped.borrow().is_empty());
+    drop(resource);
+    assert_eq!(*dropped.borrow(), vec!["permit"]);
+}
@@ -0,0 +1,78 @@
+use futures::executor::block_on;
+use futures::future::{self, FutureExt};
+use futures::sink::{Sink, SinkExt};
+use futures::stream::{Stream, StreamExt};
+use futures::task::{Context, Poll};
+use futures_test::future::FutureTestExt;
+use futures_test::task::noop_context;
+use std::collections::VecDeque;
+use std::pin::Pin;
+
+/// A duplex stream which yields back the items sent into it.
+#[derive(Default)]
+struct Echo {
+    queue: VecDeque<i32>,
+    closed: bool,
+}
+
+impl Stream for Echo {
+    type Item = i32;
+
+    fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<i32>> {
+        match self.queue.pop_front() {
+            Some(item) => Poll::Ready(Some(item)),
+            None if self.closed => Poll::Ready(None),
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl Sink<i32> for Echo {
+    type SinkError = ();
+
+    fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), ()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: i32) -> Result<(), ()> {
+        self.queue.push_back(item);
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), ()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), ()>> {
+        self.closed = true;
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[test]
+fn sink_passthrough() {
+    let mut echo = future::ready(Echo::default()).pending_once().flatten_stream();
+
+    // Sending before the future resolved waits for the connection.
+    block_on(echo.send(1)).unwrap();
+    block_on(echo.send(2)).unwrap();
+    block_on(echo.close()).unwrap();
+
+    assert_eq!(block_on(echo.collect::<Vec<_>>()), vec![1, 2]);
+}
+
+#[test]
+fn flush_and_close_before_resolution() {
+    let mut cx = noop_context();
+
+    // Flushing waits for the future to resolve to the sink.
+    let mut echo = future::ready(Echo::default()).pending_once().flatten_stream();
+    assert!(Pin::new(&mut echo).poll_flush(&mut cx).is_pending());
+    assert_eq!(Pin::new(&mut echo).poll_flush(&mut cx), Poll::Ready(Ok(())));
+
+    // So does closing, which then reaches the sink and ends the stream.
+    let mut echo = future::ready(Echo::default()).pending_once().flatten_stream();
+    assert!(Pin::new(&mut echo).poll_close(&mut cx).is_pending());
+    assert_eq!(Pin::new(&mut echo).poll_close(&mut cx), Poll::Ready(Ok(())));
+    assert_eq!(echo.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
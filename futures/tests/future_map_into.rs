@@ -0,0 +1,45 @@
+#![feature(async_await)]
+
+use futures::executor::block_on;
+use futures::future::{self, FusedFuture, FutureExt, MapInto, OkInto, TryFutureExt};
+use futures_test::future::FutureTestExt;
+
+#[test]
+fn map_into() {
+    assert_eq!(block_on(future::ready(3u8).map_into::<u64>()), 3);
+    assert_eq!(block_on(future::ready(4u8).pending_once().map_into::<u64>()), 4);
+}
+
+#[test]
+fn ok_into() {
+    assert_eq!(block_on(future::ok::<u8, u8>(5).ok_into::<u32>()), Ok(5));
+    assert_eq!(block_on(future::err::<u8, u8>(6).ok_into::<u32>()), Err(6));
+}
+
+#[test]
+fn nameable_types() {
+    struct Holder {
+        map: MapInto<future::Ready<u16>, i64>,
+        ok: OkInto<future::Ready<Result<u16, ()>>, i64>,
+    }
+
+    let holder = Holder {
+        map: future::ready(7).map_into(),
+        ok: future::ok(8).ok_into(),
+    };
+    assert_eq!(block_on(holder.map), 7);
+    assert_eq!(block_on(holder.ok), Ok(8));
+}
+
+#[test]
+fn fused_when_inner_is_fused() {
+    let mut map = future::ready(1u8).fuse().map_into::<u32>();
+    assert!(!map.is_terminated());
+    assert_eq!(block_on(&mut map), 1);
+    assert!(map.is_terminated());
+
+    let mut ok = future::ok::<u8, ()>(2).fuse().ok_into::<u32>();
+    assert!(!ok.is_terminated());
+    assert_eq!(block_on(&mut ok), Ok(2));
+    assert!(ok.is_terminated());
+}
@@ -0,0 +1,51 @@
+#![feature(async_await)]
+
+use futures::channel::oneshot;
+use futures::future::{self, FutureExt};
+use std::panic::{self, AssertUnwindSafe};
+
+#[test]
+fn ready_future() {
+    assert_eq!(future::ready(1).now_or_never(), Some(1));
+    assert_eq!(future::lazy(|_| 2).now_or_never(), Some(2));
+}
+
+#[test]
+fn fulfilled_oneshot() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    tx.send(3).unwrap();
+    assert_eq!(rx.now_or_never(), Some(Ok(3)));
+}
+
+#[test]
+fn pending_future_is_dropped() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    assert_eq!(rx.now_or_never(), None);
+    assert!(tx.is_canceled());
+    assert_eq!(future::empty::<()>().now_or_never(), None);
+}
+
+#[test]
+fn not_unpin_future() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    tx.send(4).unwrap();
+    let fut = async {
+        let x = rx.await.unwrap();
+        x + 1
+    };
+    assert_eq!(fut.now_or_never(), Some(5));
+
+    let fut = async {
+        future::empty::<()>().await;
+        unreachable!()
+    };
+    assert_eq!(fut.now_or_never(), None::<()>);
+}
+
+#[test]
+fn panic_is_propagated() {
+    let res = panic::catch_unwind(AssertUnwindSafe(|| {
+        future::lazy(|_| panic!("future panicked")).now_or_never()
+    }));
+    assert_eq!(*res.unwrap_err().downcast::<&str>().unwrap(), "future panicked");
+}
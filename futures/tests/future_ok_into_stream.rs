@@ -0,0 +1,27 @@
+use futures::future::{err, ok, TryFutureExt};
+use futures::stream::{FusedStream, StreamExt};
+use futures::task::Poll;
+use futures_test::future::FutureTestExt;
+use futures_test::task::noop_context;
+
+#[test]
+fn yields_ok_then_terminates() {
+    let mut cx = noop_context();
+    let mut stream = ok::<i32, ()>(1).pending_once().ok_into_stream();
+
+    assert!(!stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(1))));
+    assert!(stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn yields_err_then_terminates() {
+    let mut cx = noop_context();
+    let mut stream = err::<(), i32>(2).ok_into_stream();
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Err(2))));
+    assert!(stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
@@ -0,0 +1,48 @@
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::{self, Either, FutureExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+
+#[test]
+fn future_completes_first() {
+    let (_signal_tx, signal_rx) = oneshot::channel::<()>();
+    match block_on(future::ready(1).unless(signal_rx)) {
+        Either::Left(x) => assert_eq!(x, 1),
+        Either::Right(_) => panic!("signal never fired"),
+    }
+}
+
+#[test]
+fn signal_cancels_future() {
+    let mut cx = noop_context();
+    let (tx, rx) = oneshot::channel::<i32>();
+    let (signal_tx, signal_rx) = oneshot::channel::<&str>();
+    let mut fut = rx.unless(signal_rx);
+
+    assert!(fut.poll_unpin(&mut cx).is_pending());
+    signal_tx.send("stop").unwrap();
+    match fut.poll_unpin(&mut cx) {
+        Poll::Ready(Either::Right(Ok("stop"))) => {}
+        _ => panic!("expected the signal to win"),
+    }
+
+    // The cancelled future was dropped along with the combinator.
+    drop(fut);
+    assert!(tx.is_canceled());
+}
+
+#[test]
+fn bias() {
+    let fut = future::ready(1).unless(future::ready(2));
+    match block_on(fut) {
+        Either::Left(_) => panic!("the signal is polled first"),
+        Either::Right(x) => assert_eq!(x, 2),
+    }
+
+    let fut = future::ready(1).unless(future::ready(2)).prefer_future();
+    match block_on(fut) {
+        Either::Left(x) => assert_eq!(x, 1),
+        Either::Right(_) => panic!("the future is polled first"),
+    }
+}
@@ -2,8 +2,11 @@ use futures::channel::oneshot;
 use futures::executor::{block_on, block_on_stream};
 use futures::future::{self, join, Future, FutureExt, TryFutureExt};
 use futures::stream::{StreamExt, FuturesOrdered};
+use futures::task::Poll;
 use futures_test::task::noop_context;
 use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
 
 #[test]
 fn works_1() {
@@ -81,3 +84,99 @@ fn queue_never_unblocked() {
     assert!(stream.poll_next_unpin(cx).is_pending());
     assert!(stream.poll_next_unpin(cx).is_pending());
 }
+
+#[test]
+fn push_front_and_back() {
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+    let (c_tx, c_rx) = oneshot::channel::<i32>();
+    let (d_tx, d_rx) = oneshot::channel::<i32>();
+    let (e_tx, e_rx) = oneshot::channel::<i32>();
+    let cx = &mut noop_context();
+
+    let mut stream = FuturesOrdered::new();
+    stream.push_back(a_rx);
+    stream.push_back(b_rx);
+
+    // `b` completes first and is buffered until `a` is done.
+    b_tx.send(2).unwrap();
+    assert!(stream.poll_next_unpin(cx).is_pending());
+
+    // `c` goes in front of both `a` and the buffered output of `b`.
+    stream.push_front(c_rx);
+    stream.push_back(d_rx);
+    assert_eq!(stream.len(), 4);
+
+    a_tx.send(1).unwrap();
+    assert!(stream.poll_next_unpin(cx).is_pending());
+    c_tx.send(3).unwrap();
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(Ok(3))));
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(Ok(1))));
+
+    // Pushing to the front after some outputs have been yielded still puts
+    // the future before the buffered output of `b`.
+    stream.push_front(e_rx);
+    assert_eq!(stream.len(), 3);
+    e_tx.send(5).unwrap();
+    d_tx.send(4).unwrap();
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(Ok(5))));
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(Ok(2))));
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(Ok(4))));
+    assert_eq!(stream.len(), 0);
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+}
+
+#[test]
+fn push_front_on_empty_queue() {
+    let mut stream = FuturesOrdered::new();
+    stream.push_front(future::ready(2));
+    stream.push_front(future::ready(1));
+    stream.push_back(future::ready(3));
+
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![1, 2, 3]);
+}
+
+#[test]
+fn reorder_limit() {
+    let polled = Rc::new(RefCell::new(Vec::new()));
+    let ready = Rc::new(RefCell::new(vec![false; 4]));
+    let cx = &mut noop_context();
+
+    let mut stream = FuturesOrdered::new();
+    stream.set_reorder_limit(Some(2));
+    assert_eq!(stream.reorder_limit(), Some(2));
+    for i in 0..4 {
+        let polled = polled.clone();
+        let ready = ready.clone();
+        stream.push_back(future::poll_fn(move |cx| {
+            polled.borrow_mut().push(i);
+            if ready.borrow()[i] {
+                Poll::Ready(i)
+            } else {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }).boxed_local());
+    }
+    assert_eq!(stream.len(), 4);
+
+    // Only the first two futures are polled.
+    ready.borrow_mut()[1] = true;
+    assert!(stream.poll_next_unpin(cx).is_pending());
+    assert!(polled.borrow().iter().all(|&i| i < 2));
+    assert!(polled.borrow().contains(&1));
+
+    // `1` is buffered, so nothing else is started until `0` is done.
+    polled.borrow_mut().clear();
+    ready.borrow_mut()[2] = true;
+    assert!(stream.poll_next_unpin(cx).is_pending());
+    assert!(polled.borrow().iter().all(|&i| i == 0));
+
+    // `push_front` futures are polled regardless of the limit.
+    stream.push_front(future::ready(10).boxed_local());
+    assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(10)));
+
+    ready.borrow_mut()[0] = true;
+    ready.borrow_mut()[3] = true;
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![0, 1, 2, 3]);
+}
@@ -161,3 +161,138 @@ fn futures_not_moved_after_poll() {
     assert_stream_next!(stream, ());
     assert_stream_done!(stream);
 }
+
+#[test]
+fn iter_len() {
+    let stream = vec![
+        future::empty::<()>(),
+        future::empty::<()>(),
+        future::empty::<()>()
+    ].into_iter().collect::<FuturesUnordered<_>>();
+
+    let mut iter = stream.iter();
+    assert_eq!(iter.len(), 3);
+    assert!(iter.next().is_some());
+    assert_eq!(iter.len(), 2);
+    assert!(iter.next().is_some());
+    assert!(iter.next().is_some());
+    assert_eq!(iter.len(), 0);
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn extend_while_iterating() {
+    let mut stream = (0..3).map(future::ready).collect::<FuturesUnordered<_>>();
+
+    // Futures can't be pushed while the set is borrowed, so new futures are
+    // collected separately and added once iteration is done.
+    let new_futures = stream.iter().map(|_| future::ready(10)).collect::<Vec<_>>();
+    stream.extend(new_futures);
+    assert_eq!(stream.len(), 6);
+
+    let mut results = block_on(stream.collect::<Vec<_>>());
+    results.sort();
+    assert_eq!(results, vec![0, 1, 2, 10, 10, 10]);
+}
+
+#[test]
+fn into_iter_migrates_pending_futures() {
+    let mut cx = noop_context();
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+    let (c_tx, c_rx) = oneshot::channel::<i32>();
+
+    let mut stream = vec![a_rx, b_rx, c_rx].into_iter().collect::<FuturesUnordered<_>>();
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+    a_tx.send(1).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(1))));
+
+    // Only the unfinished futures are yielded, and they still work in the new
+    // set after having been polled in the old one.
+    let mut iter = stream.into_iter();
+    assert_eq!(iter.len(), 2);
+    let migrated = (&mut iter).collect::<FuturesUnordered<_>>();
+    assert_eq!(iter.len(), 0);
+    assert!(iter.next().is_none());
+    assert_eq!(migrated.len(), 2);
+
+    b_tx.send(2).unwrap();
+    c_tx.send(3).unwrap();
+    let mut results = block_on(migrated.map(Result::unwrap).collect::<Vec<_>>());
+    results.sort();
+    assert_eq!(results, vec![2, 3]);
+}
+
+#[test]
+fn into_iter_drop_releases_remaining() {
+    let mut cx = noop_context();
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+
+    let mut stream = vec![a_rx, b_rx].into_iter().collect::<FuturesUnordered<_>>();
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+
+    let mut iter = stream.into_iter();
+    let first = iter.next().unwrap();
+    drop(iter);
+
+    // The remaining future was dropped together with the iterator, the one
+    // taken out of it is still alive.
+    assert_eq!(a_tx.is_canceled() as u8 + b_tx.is_canceled() as u8, 1);
+    drop(first);
+    assert!(a_tx.is_canceled());
+    assert!(b_tx.is_canceled());
+}
+
+#[test]
+fn into_iter_references() {
+    let mut stream = vec![future::ready(1), future::ready(2)]
+        .into_iter()
+        .collect::<FuturesUnordered<_>>();
+
+    assert_eq!((&stream).into_iter().count(), 2);
+    for fut in &mut stream {
+        *fut = future::ready(5);
+    }
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![5, 5]);
+}
+
+#[test]
+fn poll_budget_bounds_self_waking_futures() {
+    use futures_test::task::new_count_waker;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let polls = Rc::new(Cell::new(0));
+    let polls2 = polls.clone();
+    let self_waking = future::poll_fn(move |cx| -> Poll<i32> {
+        polls2.set(polls2.get() + 1);
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    });
+
+    let (waker, count) = new_count_waker();
+    let mut cx = futures::task::Context::from_waker(&waker);
+    let mut stream = FuturesUnordered::new();
+    assert_eq!(stream.poll_budget(), 32);
+    stream.set_poll_budget(5);
+    stream.push(self_waking.left_future());
+
+    // `poll_next` returns after exhausting its budget and asks to be polled
+    // again, instead of looping forever.
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+    assert_eq!(polls.get(), 5);
+    assert!(count.get() > 0);
+
+    // Other futures still make progress.
+    stream.push(future::ready(7).right_future());
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(7)));
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+}
+
+#[test]
+#[should_panic(expected = "poll budget must be greater than zero")]
+fn poll_budget_zero_panics() {
+    let mut stream = FuturesUnordered::<future::Ready<()>>::new();
+    stream.set_poll_budget(0);
+}
@@ -0,0 +1,86 @@
+use futures::executor::block_on;
+use futures::future::{Future, FutureExt};
+use futures::stream::{self, StreamExt, TryStreamExt};
+use futures::io::{AsyncBufReadExt, BufReader};
+use futures::task::Poll;
+use futures_test::io::AsyncReadTestExt;
+use futures_test::task::noop_context;
+use std::io::{Cursor, ErrorKind};
+use std::pin::Pin;
+
+fn run<F: Future + Unpin>(mut f: F) -> F::Output {
+    let mut cx = noop_context();
+    loop {
+        if let Poll::Ready(x) = f.poll_unpin(&mut cx) {
+            return x;
+        }
+    }
+}
+
+#[test]
+fn take_line() {
+    let mut s = Cursor::new(&b"12\r\n\nab\rcd\n34"[..]).lines_ref();
+    assert_eq!(block_on(s.take_line()).unwrap(), Some("12"));
+    assert_eq!(block_on(s.take_line()).unwrap(), Some(""));
+    assert_eq!(block_on(s.take_line()).unwrap(), Some("ab\rcd"));
+    assert_eq!(block_on(s.take_line()).unwrap(), Some("34"));
+    assert_eq!(block_on(s.take_line()).unwrap(), None);
+    assert_eq!(block_on(s.take_line()).unwrap(), None);
+}
+
+#[test]
+fn borrows_buffered_lines() {
+    let data = &b"first\nsecond\n"[..];
+    let range = data.as_ptr() as usize..data.as_ptr() as usize + data.len();
+    let mut s = Cursor::new(data).lines_ref();
+    for expected in &["first", "second"] {
+        let line = block_on(s.take_line()).unwrap().unwrap();
+        assert_eq!(line, *expected);
+        assert!(range.contains(&(line.as_ptr() as usize)));
+    }
+    assert_eq!(block_on(s.take_line()).unwrap(), None);
+}
+
+#[test]
+fn lines_spanning_refills() {
+    let reader = BufReader::with_capacity(4, Cursor::new(&b"a\nlonger line\nb\r\nlast"[..]));
+    let mut s = reader.lines_ref();
+    assert_eq!(block_on(s.take_line()).unwrap(), Some("a"));
+    assert_eq!(block_on(s.take_line()).unwrap(), Some("longer line"));
+    assert_eq!(block_on(s.take_line()).unwrap(), Some("b"));
+    assert_eq!(block_on(s.take_line()).unwrap(), Some("last"));
+    assert_eq!(block_on(s.take_line()).unwrap(), None);
+}
+
+#[test]
+fn maybe_pending() {
+    let buf = stream::iter(vec![&b"12"[..], &b"\r"[..], &b"\n34\n"[..], &b"5"[..]])
+        .map(Ok)
+        .into_async_read()
+        .interleave_pending();
+    let mut s = buf.lines_ref();
+    assert_eq!(run(s.take_line()).unwrap(), Some("12"));
+    assert_eq!(run(s.take_line()).unwrap(), Some("34"));
+    assert_eq!(run(s.take_line()).unwrap(), Some("5"));
+    assert_eq!(run(s.take_line()).unwrap(), None);
+}
+
+#[test]
+fn poll_next_line_borrowed() {
+    let mut cx = noop_context();
+    let mut s = Cursor::new(&b"12\n34"[..]).lines_ref();
+    let mut lines = Vec::new();
+    while let Poll::Ready(Some(line)) = Pin::new(&mut s).poll_next_line_borrowed(&mut cx).map(Result::unwrap) {
+        lines.push(line.to_string());
+    }
+    assert_eq!(lines, vec!["12", "34"]);
+}
+
+#[test]
+fn invalid_utf8() {
+    let mut s = Cursor::new(&b"12\n\xff\n34\n"[..]).lines_ref();
+    assert_eq!(block_on(s.take_line()).unwrap(), Some("12"));
+    assert_eq!(block_on(s.take_line()).unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(block_on(s.take_line()).unwrap(), Some("34"));
+    assert_eq!(block_on(s.take_line()).unwrap(), None);
+}
@@ -0,0 +1,38 @@
+use futures::executor::block_on;
+use futures::io::AsyncReadExt;
+use futures_test::io::AsyncReadTestExt;
+use std::io::Cursor;
+use std::thread;
+
+#[test]
+fn counts_bytes_and_reads() {
+    let mut reader = Cursor::new(vec![1, 2, 3, 4, 5, 6, 7]).interleave_pending().metered();
+    let meter = reader.meter();
+
+    let mut buf = [0; 4];
+    assert_eq!(block_on(reader.read(&mut buf)).unwrap(), 4);
+    assert_eq!(meter.bytes_read(), 4);
+    assert_eq!(meter.reads(), 1);
+
+    // End of file doesn't count as a read.
+    let mut rest = Vec::new();
+    block_on(reader.read_to_end(&mut rest)).unwrap();
+    assert_eq!(rest, [5, 6, 7]);
+    assert_eq!(meter.bytes_read(), 7);
+    assert_eq!(meter.reads(), 2);
+}
+
+#[test]
+fn meter_is_shared_across_threads() {
+    let mut reader = Cursor::new(vec![0; 100]).metered();
+    let meter = reader.meter();
+
+    let handle = thread::spawn(move || {
+        let mut buf = Vec::new();
+        block_on(reader.read_to_end(&mut buf)).unwrap();
+    });
+    handle.join().unwrap();
+
+    assert_eq!(meter.bytes_read(), 100);
+    assert_eq!(meter.clone().reads(), meter.reads());
+}
@@ -0,0 +1,183 @@
+use futures::channel::{mpsc, oneshot};
+use futures::future::FutureExt;
+use futures::io::{AsyncRead, AsyncReadExt};
+use futures::stream::TryStreamExt;
+use futures::task::Poll;
+use futures_test::task::noop_context;
+use std::cell::RefCell;
+use std::io;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::time::Duration;
+
+// The timers started so far, with the timeout each was started for.
+type Timers = RefCell<Vec<(Duration, oneshot::Sender<()>)>>;
+
+// A timer, which completes once it is fired.
+type Delay = futures::future::Map<oneshot::Receiver<()>, fn(Result<(), oneshot::Canceled>)>;
+
+// A delay factory whose timers are fired by hand.
+fn timers() -> (Rc<Timers>, impl FnMut(Duration) -> Delay) {
+    let timers = Rc::new(RefCell::new(Vec::new()));
+    let timers2 = timers.clone();
+    let delay = move |timeout| {
+        let (tx, rx) = oneshot::channel();
+        timers2.borrow_mut().push((timeout, tx));
+        rx.map(drop as fn(_))
+    };
+    (timers, delay)
+}
+
+fn fire(timers: &Timers, timeout: Duration) {
+    let mut timers = timers.borrow_mut();
+    let i = timers.iter().position(|(t, _)| *t == timeout).unwrap();
+    timers.remove(i).1.send(()).unwrap();
+}
+
+const IDLE: Duration = Duration::from_secs(1);
+const TOTAL: Duration = Duration::from_secs(10);
+
+#[test]
+fn idle_timeout() {
+    let (timers, delay) = timers();
+    let (tx, rx) = mpsc::unbounded::<io::Result<Vec<u8>>>();
+    let mut reader = rx.into_async_read().read_timeout(IDLE, None, delay);
+    let mut cx = noop_context();
+    let mut buf = [0; 4];
+
+    // Reads that complete right away don't start a timer.
+    tx.unbounded_send(Ok(vec![1, 2])).unwrap();
+    assert_eq!(Pin::new(&mut reader).poll_read(&mut cx, &mut buf).map_err(|_| ()), Poll::Ready(Ok(2)));
+    assert_eq!(timers.borrow().len(), 0);
+
+    assert!(Pin::new(&mut reader).poll_read(&mut cx, &mut buf).is_pending());
+    assert_eq!(timers.borrow().len(), 1);
+
+    // A completed read resets the idle timer.
+    tx.unbounded_send(Ok(vec![3])).unwrap();
+    assert_eq!(Pin::new(&mut reader).poll_read(&mut cx, &mut buf).map_err(|_| ()), Poll::Ready(Ok(1)));
+    assert!(Pin::new(&mut reader).poll_read(&mut cx, &mut buf).is_pending());
+    assert_eq!(timers.borrow().len(), 2);
+    timers.borrow_mut().remove(0);
+    assert!(Pin::new(&mut reader).poll_read(&mut cx, &mut buf).is_pending());
+
+    fire(&timers, IDLE);
+    match Pin::new(&mut reader).poll_read(&mut cx, &mut buf) {
+        Poll::Ready(Err(e)) => assert_eq!(e.kind(), io::ErrorKind::TimedOut),
+        res => panic!("unexpected {:?}", res),
+    }
+}
+
+#[test]
+fn total_timeout() {
+    let (timers, delay) = timers();
+    let (tx, rx) = mpsc::unbounded::<io::Result<Vec<u8>>>();
+    let mut reader = rx.into_async_read().read_timeout(IDLE, TOTAL, delay);
+    let mut cx = noop_context();
+    let mut buf = [0; 4];
+
+    assert!(Pin::new(&mut reader).poll_read(&mut cx, &mut buf).is_pending());
+    assert_eq!(timers.borrow().len(), 2);
+
+    // Data trickling in doesn't extend the total timeout.
+    tx.unbounded_send(Ok(vec![1])).unwrap();
+    assert_eq!(Pin::new(&mut reader).poll_read(&mut cx, &mut buf).map_err(|_| ()), Poll::Ready(Ok(1)));
+    fire(&timers, TOTAL);
+    match Pin::new(&mut reader).poll_read(&mut cx, &mut buf) {
+        Poll::Ready(Err(e)) => assert_eq!(e.kind(), io::ErrorKind::Other),
+        res => panic!("unexpected {:?}", res),
+    }
+}
+
+#[test]
+fn no_timeouts() {
+    let (timers, delay) = timers();
+    let (tx, rx) = mpsc::unbounded::<io::Result<Vec<u8>>>();
+    let mut reader = rx.into_async_read().read_timeout(None, None, delay);
+    tx.unbounded_send(Ok(vec![1, 2, 3])).unwrap();
+    drop(tx);
+
+    let mut buf = Vec::new();
+    futures::executor::block_on(reader.read_to_end(&mut buf)).unwrap();
+    assert_eq!(buf, vec![1, 2, 3]);
+    assert_eq!(timers.borrow().len(), 0);
+}
+
+#[test]
+fn read_again_after_idle_timeout() {
+    let (timers, delay) = timers();
+    let (tx, rx) = mpsc::unbounded::<io::Result<Vec<u8>>>();
+    let mut reader = rx.into_async_read().read_timeout(IDLE, None, delay);
+    let mut cx = noop_context();
+    let mut buf = [0; 4];
+
+    assert!(Pin::new(&mut reader).poll_read(&mut cx, &mut buf).is_pending());
+    fire(&timers, IDLE);
+    match Pin::new(&mut reader).poll_read(&mut cx, &mut buf) {
+        Poll::Ready(Err(e)) => assert_eq!(e.kind(), io::ErrorKind::TimedOut),
+        res => panic!("unexpected {:?}", res),
+    }
+
+    // A retried read starts a fresh idle timer.
+    assert!(Pin::new(&mut reader).poll_read(&mut cx, &mut buf).is_pending());
+    assert_eq!(timers.borrow().len(), 1);
+    tx.unbounded_send(Ok(vec![1])).unwrap();
+    assert_eq!(Pin::new(&mut reader).poll_read(&mut cx, &mut buf).map_err(|_| ()), Poll::Ready(Ok(1)));
+}
+
+#[test]
+fn read_again_after_total_timeout() {
+    let (timers, delay) = timers();
+    let (tx, rx) = mpsc::unbounded::<io::Result<Vec<u8>>>();
+    let mut reader = rx.into_async_read().read_timeout(IDLE, TOTAL, delay);
+    let mut cx = noop_context();
+    let mut buf = [0; 4];
+
+    assert!(Pin::new(&mut reader).poll_read(&mut cx, &mut buf).is_pending());
+    fire(&timers, TOTAL);
+    match Pin::new(&mut reader).poll_read(&mut cx, &mut buf) {
+        Poll::Ready(Err(e)) => assert_eq!(e.kind(), io::ErrorKind::Other),
+        res => panic!("unexpected {:?}", res),
+    }
+
+    // Later reads fail at once, even with data available.
+    tx.unbounded_send(Ok(vec![1])).unwrap();
+    for _ in 0..2 {
+        match Pin::new(&mut reader).poll_read(&mut cx, &mut buf) {
+            Poll::Ready(Err(e)) => assert_eq!(e.kind(), io::ErrorKind::Other),
+            res => panic!("unexpected {:?}", res),
+        }
+    }
+    // The idle timer was dropped along with the total one.
+    assert!(timers.borrow().iter().all(|(_, tx)| tx.is_canceled()));
+}
+
+#[test]
+fn read_again_after_immediate_timeouts() {
+    use futures::future::{self, Ready};
+
+    fn delay(_timeout: Duration) -> Ready<()> {
+        future::ready(())
+    }
+
+    let mut cx = noop_context();
+    let mut buf = [0; 4];
+
+    let (_tx, rx) = mpsc::unbounded::<io::Result<Vec<u8>>>();
+    let mut reader = rx.into_async_read().read_timeout(IDLE, None, delay);
+    for _ in 0..2 {
+        match Pin::new(&mut reader).poll_read(&mut cx, &mut buf) {
+            Poll::Ready(Err(e)) => assert_eq!(e.kind(), io::ErrorKind::TimedOut),
+            res => panic!("unexpected {:?}", res),
+        }
+    }
+
+    let (_tx, rx) = mpsc::unbounded::<io::Result<Vec<u8>>>();
+    let mut reader = rx.into_async_read().read_timeout(None, TOTAL, delay);
+    for _ in 0..2 {
+        match Pin::new(&mut reader).poll_read(&mut cx, &mut buf) {
+            Poll::Ready(Err(e)) => assert_eq!(e.kind(), io::ErrorKind::Other),
+            res => panic!("unexpected {:?}", res),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use futures::executor::block_on;
+use futures::future::join;
+use futures::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use futures::task::{Context, Poll};
+use std::io;
+use std::pin::Pin;
+use std::sync::Mutex;
+
+/// A loopback object which, like a socket, can be read from and written to
+/// both directly and through a shared reference. Writes are appended to a
+/// buffer that reads are served from.
+#[derive(Default)]
+struct Loopback {
+    buf: Mutex<Vec<u8>>,
+}
+
+impl AsyncRead for &Loopback {
+    fn poll_read(self: Pin<&mut Self>, _cx: &mut Context<'_>, out: &mut [u8])
+        -> Poll<io::Result<usize>>
+    {
+        let mut buf = self.buf.lock().unwrap();
+        let n = out.len().min(buf.len());
+        out[..n].copy_from_slice(&buf[..n]);
+        buf.drain(..n);
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl AsyncWrite for &Loopback {
+    fn poll_write(self: Pin<&mut Self>, _cx: &mut Context<'_>, data: &[u8])
+        -> Poll<io::Result<usize>>
+    {
+        self.buf.lock().unwrap().extend_from_slice(data);
+        Poll::Ready(Ok(data.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncRead for Loopback {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, out: &mut [u8])
+        -> Poll<io::Result<usize>>
+    {
+        Pin::new(&mut &*self).poll_read(cx, out)
+    }
+}
+
+impl AsyncWrite for Loopback {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, data: &[u8])
+        -> Poll<io::Result<usize>>
+    {
+        Pin::new(&mut &*self).poll_write(cx, data)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut &*self).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut &*self).poll_close(cx)
+    }
+}
+
+#[test]
+fn split_shared() {
+    let (mut reader, mut writer) = Loopback::default().split_shared();
+
+    block_on(writer.write_all(b"hello")).unwrap();
+    let mut out = [0; 5];
+    block_on(reader.read_exact(&mut out)).unwrap();
+    assert_eq!(&out, b"hello");
+
+    // Both halves can be used at the same time. `join` polls the write
+    // first, so the read sees all of it.
+    let mut out = [0; 3];
+    let (written, read) = block_on(join(writer.write_all(b"abc"), reader.read(&mut out)));
+    written.unwrap();
+    assert_eq!(read.unwrap(), 3);
+    assert_eq!(&out, b"abc");
+}
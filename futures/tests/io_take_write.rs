@@ -0,0 +1,32 @@
+use futures::executor::block_on;
+use futures::io::AsyncWriteExt;
+use futures_test::io::AsyncWriteTestExt;
+use std::io;
+
+#[test]
+fn truncates_at_limit() {
+    let mut writer = Vec::new().take_write(5);
+
+    assert_eq!(block_on(writer.write(&[1, 2, 3])).unwrap(), 3);
+    assert_eq!(writer.limit(), 2);
+    assert_eq!(block_on(writer.write(&[4, 5, 6])).unwrap(), 2);
+    assert_eq!(writer.limit(), 0);
+    assert_eq!(block_on(writer.write(&[7])).unwrap(), 0);
+    assert_eq!(block_on(writer.write(&[])).unwrap(), 0);
+
+    assert_eq!(writer.get_ref(), &[1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn write_all_past_limit_fails() {
+    let mut writer = Vec::new().interleave_pending_write().take_write(3);
+
+    block_on(writer.write_all(&[1, 2])).unwrap();
+    let err = block_on(writer.write_all(&[3, 4])).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+    assert_eq!(writer.get_ref().get_ref(), &[1, 2, 3]);
+
+    writer.set_limit(1);
+    block_on(writer.write_all(&[4])).unwrap();
+    assert_eq!(writer.into_inner().into_inner(), [1, 2, 3, 4]);
+}
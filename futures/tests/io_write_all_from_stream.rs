@@ -0,0 +1,104 @@
+use futures::executor::block_on;
+use futures::io::{AsyncWrite, AsyncWriteExt, IoSlice};
+use futures::stream;
+use futures::task::{Context, Poll};
+use futures_test::io::AsyncWriteTestExt;
+use futures_test::stream::StreamTestExt;
+use std::io;
+use std::pin::Pin;
+
+#[derive(Default)]
+struct Recorder {
+    data: Vec<u8>,
+    writes: usize,
+    flushes: usize,
+}
+
+impl AsyncWrite for Recorder {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        self.poll_write_vectored(cx, &[IoSlice::new(buf)])
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        self.writes += 1;
+        let mut n = 0;
+        for buf in bufs {
+            self.data.extend_from_slice(buf);
+            n += buf.len();
+        }
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.flushes += 1;
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.poll_flush(cx)
+    }
+}
+
+#[test]
+fn ready_chunks_are_written_together() {
+    let chunks = stream::iter(vec![Ok(vec![1, 2]), Ok(vec![]), Ok(vec![3]), Ok(vec![4, 5, 6])]);
+    let mut writer = Recorder::default();
+
+    block_on(writer.write_all_from_stream(chunks)).unwrap();
+
+    assert_eq!(writer.data, [1, 2, 3, 4, 5, 6]);
+    assert_eq!(writer.writes, 1);
+    assert_eq!(writer.flushes, 1);
+}
+
+#[test]
+fn short_writes_and_pending_stream() {
+    let chunks = stream::iter(vec![Ok(vec![1, 2, 3, 4]), Ok(vec![5]), Ok(vec![6, 7])])
+        .interleave_pending();
+    let mut writer = Vec::new().limited_write(3).interleave_pending_write();
+
+    block_on(writer.write_all_from_stream(chunks)).unwrap();
+
+    assert_eq!(writer.into_inner().into_inner(), [1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn empty_stream_only_flushes() {
+    let mut writer = Recorder::default();
+
+    block_on(writer.write_all_from_stream(stream::empty::<io::Result<&[u8]>>())).unwrap();
+
+    assert!(writer.data.is_empty());
+    assert_eq!(writer.writes, 0);
+    assert_eq!(writer.flushes, 1);
+}
+
+#[test]
+fn stream_error_is_returned() {
+    let chunks = stream::iter(vec![
+        Ok(vec![1, 2]),
+        Err(io::Error::new(io::ErrorKind::Other, "boom")),
+        Ok(vec![3]),
+    ]);
+    let mut writer = Recorder::default();
+
+    let err = block_on(writer.write_all_from_stream(chunks)).unwrap_err();
+
+    assert_eq!(err.kind(), io::ErrorKind::Other);
+    assert_eq!(writer.flushes, 0);
+}
+
+#[test]
+fn full_writer_returns_write_zero() {
+    let chunks = stream::iter(vec![Ok([0u8; 4]), Ok([0u8; 4])]);
+    let mut writer = Vec::new().take_write(6);
+
+    let err = block_on(writer.write_all_from_stream(chunks)).unwrap_err();
+
+    assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+    assert_eq!(writer.into_inner().len(), 6);
+}
@@ -2,8 +2,14 @@
 
 use futures_util::future::*;
 use std::future::Future;
+use futures::channel::oneshot;
 use futures::executor::block_on;
+use futures::task::{Context, Poll};
+use futures_test::task::noop_context;
+use std::cell::Cell;
 use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
 
 fn assert_done<T, F>(actual_fut: F, expected: T)
 where
@@ -43,3 +49,74 @@ fn join_all_from_iter() {
         vec![1, 2],
     )
 }
+
+// A future which counts how many times it has been polled.
+struct CountPolls<'a, F> {
+    future: F,
+    polls: &'a Cell<usize>,
+}
+
+impl<F: Future + Unpin> Future for CountPolls<'_, F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        self.polls.set(self.polls.get() + 1);
+        Pin::new(&mut self.future).poll(cx)
+    }
+}
+
+fn join_one_at_a_time(n: usize) -> usize {
+    let polls = Cell::new(0);
+    let (txs, rxs): (Vec<_>, Vec<_>) = (0..n).map(|_| oneshot::channel::<usize>()).unzip();
+    let mut fut = join_all(rxs.into_iter().map(|rx| CountPolls { future: rx, polls: &polls }));
+    let mut cx = noop_context();
+    assert!(fut.poll_unpin(&mut cx).is_pending());
+    // Complete the futures in reverse order, one per wakeup.
+    let mut txs = txs.into_iter().enumerate().rev();
+    let (i, tx) = txs.next().unwrap();
+    tx.send(i).unwrap();
+    for (i, tx) in txs {
+        assert!(fut.poll_unpin(&mut cx).is_pending());
+        tx.send(i).unwrap();
+    }
+    let outputs: Vec<usize> = match fut.poll_unpin(&mut cx) {
+        Poll::Ready(outputs) => outputs.into_iter().map(Result::unwrap).collect(),
+        Poll::Pending => panic!("join_all should be done"),
+    };
+    assert_eq!(outputs, (0..n).collect::<Vec<_>>());
+    polls.get()
+}
+
+#[test]
+fn join_all_keeps_order() {
+    assert_done(|| Box::new(join_all(Vec::<Ready<i32>>::new())), vec![]);
+    for n in &[1, 10, 100, 1000] {
+        join_one_at_a_time(*n);
+    }
+    let futures = (0..100).map(ready).filter(|_| true);
+    assert_done(|| Box::new(join_all(futures)), (0..100).collect::<Vec<_>>());
+}
+
+#[test]
+fn join_all_only_polls_woken_futures() {
+    // Each future is polled once to start and once when it completes.
+    assert_eq!(join_one_at_a_time(1000), 2000);
+}
+
+#[test]
+fn join_all_propagates_panics() {
+    for n in &[3, 1000] {
+        let futures = (0..*n).map(|i| {
+            if i == n / 2 {
+                Either::Left(lazy(|_| panic!("child panicked")))
+            } else {
+                Either::Right(empty::<()>())
+            }
+        });
+        let mut fut = join_all(futures);
+        let res = panic::catch_unwind(AssertUnwindSafe(|| {
+            let _ = fut.poll_unpin(&mut noop_context());
+        }));
+        assert_eq!(*res.unwrap_err().downcast::<&str>().unwrap(), "child panicked");
+    }
+}
@@ -0,0 +1,92 @@
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::{self, FutureExt};
+use futures::task::{Context, Poll};
+use futures_test::task::new_count_waker;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn runs_once_and_only_when_awaited() {
+    let runs = Arc::new(AtomicUsize::new(0));
+    let runs2 = runs.clone();
+    let memoized = future::lazy(move |_| runs2.fetch_add(1, Ordering::SeqCst) + 10).memoize();
+    let handle = memoized.clone();
+    assert_eq!(runs.load(Ordering::SeqCst), 0);
+
+    assert_eq!(block_on(memoized), 10);
+    assert_eq!(block_on(handle.clone()), 10);
+    assert_eq!(block_on(handle), 10);
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn single_waiter_is_woken_directly() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let mut memoized = rx.memoize();
+    let (waker, count) = new_count_waker();
+    let cx = &mut Context::from_waker(&waker);
+
+    assert_eq!(memoized.poll_unpin(cx), Poll::Pending);
+    tx.send(3).unwrap();
+    assert_eq!(count, 1);
+    assert_eq!(memoized.poll_unpin(cx), Poll::Ready(Ok(3)));
+}
+
+#[test]
+fn all_waiters_are_woken() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let mut first = rx.memoize();
+    let mut second = first.clone();
+    let (waker1, count1) = new_count_waker();
+    let (waker2, count2) = new_count_waker();
+
+    assert_eq!(first.poll_unpin(&mut Context::from_waker(&waker1)), Poll::Pending);
+    assert_eq!(second.poll_unpin(&mut Context::from_waker(&waker2)), Poll::Pending);
+    tx.send(3).unwrap();
+    assert!(count1.get() >= 1);
+    assert!(count2.get() >= 1);
+
+    assert_eq!(second.poll_unpin(&mut Context::from_waker(&waker2)), Poll::Ready(Ok(3)));
+    assert_eq!(first.poll_unpin(&mut Context::from_waker(&waker1)), Poll::Ready(Ok(3)));
+}
+
+#[test]
+fn dropping_a_waiter_hands_over_to_another() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let mut first = rx.memoize();
+    let mut second = first.clone();
+    let (waker1, _count1) = new_count_waker();
+    let (waker2, count2) = new_count_waker();
+
+    assert_eq!(first.poll_unpin(&mut Context::from_waker(&waker1)), Poll::Pending);
+    assert_eq!(second.poll_unpin(&mut Context::from_waker(&waker2)), Poll::Pending);
+    let before = count2.get();
+    drop(first);
+    assert!(count2.get() > before);
+
+    tx.send(3).unwrap();
+    assert_eq!(block_on(second), Ok(3));
+}
+
+#[test]
+fn many_threads() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let memoized = rx.memoize();
+    let join_handles = (0..100)
+        .map(|_| {
+            let handle = memoized.clone();
+            thread::spawn(move || {
+                assert_eq!(block_on(handle).unwrap(), 6);
+            })
+        })
+        .collect::<Vec<_>>();
+
+    tx.send(6).unwrap();
+
+    assert_eq!(block_on(memoized).unwrap(), 6);
+    for join_handle in join_handles {
+        join_handle.join().unwrap();
+    }
+}
@@ -64,3 +64,21 @@ fn oneshot_drop_rx() {
     drop(rx);
     assert_eq!(Err(2), tx.send(2));
 }
+
+#[test]
+fn oneshot_channel_with_map() {
+    use futures::executor::block_on;
+
+    let (tx, rx) = oneshot::channel_with_map(|x: i32| x * 2);
+    thread::spawn(move || tx.send(21).unwrap());
+    assert_eq!(block_on(rx), Ok(42));
+
+    // The function isn't called when the sender goes away.
+    let (tx, rx) = oneshot::channel_with_map(|_: i32| -> i32 { panic!("mapped a canceled value") });
+    drop(tx);
+    assert_eq!(block_on(rx), Err(oneshot::Canceled));
+
+    let (tx, mut rx) = oneshot::channel_with_map(|x: i32| x + 1);
+    rx.close();
+    assert_eq!(tx.send(1), Err(1));
+}
@@ -1,9 +1,11 @@
 use futures::channel::oneshot;
-use futures::executor::{block_on, LocalPool};
+use futures::executor::{block_on, LocalPool, ThreadPool};
 use futures::future::{self, FutureExt, TryFutureExt, LocalFutureObj};
-use futures::task::LocalSpawn;
+use futures::task::{ArcWake, Context, LocalSpawn, Poll, SpawnExt};
+use futures_test::task::noop_context;
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
+use std::sync::Arc;
 use std::thread;
 
 fn send_shared_oneshot_and_wait_on_multiple_threads(threads_number: u32) {
@@ -149,3 +151,158 @@ fn dont_do_unnecessary_clones_on_output() {
     assert_eq!(block_on(rx.clone()).unwrap().0.get(), 2);
     assert_eq!(block_on(rx).unwrap().0.get(), 2);
 }
+
+#[test]
+fn strong_count() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let f1 = rx.shared();
+    assert_eq!(f1.strong_count(), Some(1));
+    let f2 = f1.clone();
+    assert_eq!(f1.strong_count(), Some(2));
+    let weak = f1.downgrade().unwrap();
+    assert_eq!(f2.strong_count(), Some(2));
+    drop(weak);
+
+    tx.send(1).unwrap();
+    assert_eq!(block_on(f1), Ok(1));
+    assert_eq!(f2.strong_count(), Some(1));
+    let mut f2 = f2;
+    assert_eq!(f2.poll_unpin(&mut noop_context()), Poll::Ready(Ok(1)));
+    assert_eq!(f2.strong_count(), None);
+    assert!(f2.downgrade().is_none());
+}
+
+#[test]
+fn weak_shared_upgrade() {
+    let (tx, rx) = oneshot::channel::<i32>();
+    let f1 = rx.shared();
+    let weak = f1.downgrade().unwrap();
+    let f2 = weak.upgrade().unwrap();
+    assert_eq!(f1.strong_count(), Some(2));
+
+    tx.send(42).unwrap();
+    assert_eq!(block_on(f2), Ok(42));
+    assert_eq!(*f1.peek().unwrap(), Ok(42));
+    assert_eq!(block_on(weak.clone().upgrade().unwrap()), Ok(42));
+    assert_eq!(block_on(f1), Ok(42));
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn weak_shared_does_not_keep_future_alive() {
+    let (mut tx, rx) = oneshot::channel::<i32>();
+    let f1 = rx.shared();
+    let f2 = f1.clone();
+    let weak = f1.downgrade().unwrap();
+
+    drop(f1);
+    assert!(!tx.poll_cancel(&mut noop_context()).is_ready());
+    assert!(weak.upgrade().is_some());
+    drop(f2);
+    // The receiver was dropped along with the last `Shared`.
+    assert!(tx.poll_cancel(&mut noop_context()).is_ready());
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn peek_while_completing_on_thread_pool() {
+    let mut pool = ThreadPool::builder().pool_size(4).create().unwrap();
+
+    for _ in 0..50 {
+        let (tx, rx) = oneshot::channel::<Vec<i32>>();
+        let shared = rx.map(Result::unwrap).shared();
+        let mut handles = Vec::new();
+        for i in 0..16 {
+            let shared = shared.clone();
+            let handle = if i % 2 == 0 {
+                pool.spawn_with_handle(shared).unwrap().boxed()
+            } else {
+                // Spin on `peek` while the other tasks complete the future.
+                pool.spawn_with_handle(future::poll_fn(move |cx| {
+                    match shared.peek() {
+                        Some(output) => Poll::Ready(output.clone()),
+                        None => {
+                            cx.waker().wake_by_ref();
+                            Poll::Pending
+                        }
+                    }
+                })).unwrap().boxed()
+            };
+            handles.push(handle);
+        }
+        let waiter = shared.clone();
+        // The tasks are already running, so the output is completed by one
+        // of them while the others poll or peek.
+        tx.send(vec![1, 2, 3]).unwrap();
+        drop(shared);
+
+        for output in block_on(future::join_all(handles)) {
+            assert_eq!(output, vec![1, 2, 3]);
+        }
+        assert_eq!(*waiter.peek().unwrap(), vec![1, 2, 3]);
+    }
+}
+
+// A waker which does nothing, but whose clones can be counted through the
+// strong count of its `Arc`.
+struct CountedWaker;
+
+impl ArcWake for CountedWaker {
+    fn wake_by_ref(_arc_self: &Arc<Self>) {}
+}
+
+#[test]
+fn many_clones_across_threads() {
+    let mut pool = ThreadPool::builder().pool_size(4).create().unwrap();
+    let (tx, rx) = oneshot::channel::<usize>();
+    let shared = rx.map(Result::unwrap).shared();
+
+    // Clones polled once and dropped before the output is ready must free the
+    // slots holding their wakers.
+    let counted = Arc::new(CountedWaker);
+    let dropped: Vec<_> = (0..2000).map(|_| {
+        let mut clone = shared.clone();
+        let counted = counted.clone();
+        pool.spawn_with_handle(future::lazy(move |_| {
+            let waker = ArcWake::into_waker(counted);
+            let _ = clone.poll_unpin(&mut Context::from_waker(&waker));
+        })).unwrap()
+    }).collect();
+    block_on(future::join_all(dropped));
+    assert_eq!(Arc::strong_count(&counted), 1);
+
+    let mut handles = Vec::new();
+    let mut dropped = Vec::new();
+    for i in 0..4000 {
+        let mut clone = shared.clone();
+        match i % 3 {
+            // Polled once and dropped, usually before the output is ready.
+            0 => {
+                let handle = pool.spawn_with_handle(future::lazy(move |_| {
+                    let _ = clone.now_or_never();
+                })).unwrap();
+                dropped.push(handle);
+            }
+            // Polled over and over by the same task until the output is ready.
+            1 => {
+                let handle = pool.spawn_with_handle(future::poll_fn(move |cx| {
+                    let poll = clone.poll_unpin(cx);
+                    if poll.is_pending() {
+                        cx.waker().wake_by_ref();
+                    }
+                    poll
+                })).unwrap();
+                handles.push(handle.boxed());
+            }
+            _ => handles.push(pool.spawn_with_handle(clone).unwrap().boxed()),
+        }
+    }
+    tx.send(7).unwrap();
+
+    for output in block_on(future::join_all(handles)) {
+        assert_eq!(output, 7);
+    }
+    block_on(future::join_all(dropped));
+    assert_eq!(shared.strong_count(), Some(1));
+    assert_eq!(block_on(shared), 7);
+}
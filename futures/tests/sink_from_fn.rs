@@ -0,0 +1,28 @@
+use futures::executor::block_on;
+use futures::sink::{self, SinkExt};
+use futures::stream::{self, StreamExt};
+
+#[test]
+fn forwards_items_to_closure() {
+    let mut received = Vec::new();
+    let sink = sink::from_fn(|item: i32| {
+        received.push(item);
+        Ok::<(), ()>(())
+    });
+
+    let src = stream::iter((0..5).map(Ok));
+    block_on(src.forward(sink)).unwrap();
+
+    assert_eq!(received, vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn closure_error_is_returned() {
+    let mut sink = sink::from_fn(|item: i32| {
+        if item < 2 { Ok(()) } else { Err(item) }
+    });
+
+    assert_eq!(block_on(sink.send(1)), Ok(()));
+    assert_eq!(block_on(sink.send(2)), Err(2));
+    assert_eq!(block_on(sink.close()), Ok(()));
+}
@@ -0,0 +1,165 @@
+use futures::executor::block_on;
+use futures::sink::{Sink, SinkExt};
+use futures::stream;
+use futures::task::Poll;
+use futures_test::sink::{MockSink, SinkTestExt};
+use futures_test::task::noop_context;
+use pin_utils::pin_mut;
+
+#[test]
+fn send_all_respects_scripted_readiness() {
+    let mut sink = MockSink::<u32, &str>::new();
+    sink.push_ready(Poll::Pending);
+    sink.push_ready(Poll::Ready(Ok(())));
+    sink.push_ready(Poll::Pending);
+    sink.push_flush(Poll::Pending);
+
+    let mut items = stream::iter(vec![1, 2, 3]);
+    block_on(sink.send_all(&mut items)).unwrap();
+
+    assert_eq!(sink.items(), vec![1, 2, 3]);
+    assert_eq!(sink.flushed_len(), 3);
+    assert!(!sink.is_closed());
+    assert!(sink.fed().windows(2).all(|w| w[0].0 <= w[1].0));
+}
+
+#[test]
+fn errors_are_propagated() {
+    let mut sink = MockSink::<u32, &str>::new();
+    sink.push_flush(Poll::Ready(Err("flush failed")));
+
+    assert_eq!(block_on(sink.send(1)), Err("flush failed"));
+    assert_eq!(sink.items(), vec![1]);
+    assert_eq!(sink.flushed_len(), 0);
+
+    // The script is exhausted, so the next flush succeeds.
+    block_on(sink.flush()).unwrap();
+    assert_eq!(sink.flushed_len(), 1);
+}
+
+#[test]
+fn capacity_forces_flush() {
+    let sink = MockSink::<u32>::with_capacity(2);
+    pin_mut!(sink);
+    let mut cx = noop_context();
+
+    for i in 0..2 {
+        assert_eq!(sink.as_mut().poll_ready(&mut cx), Poll::Ready(Ok(())));
+        sink.as_mut().start_send(i).unwrap();
+    }
+    assert_eq!(sink.flushed_len(), 0);
+
+    sink.push_flush(Poll::Pending);
+    assert_eq!(sink.as_mut().poll_ready(&mut cx), Poll::Pending);
+    assert_eq!(sink.flushed_len(), 0);
+    assert_eq!(sink.as_mut().poll_ready(&mut cx), Poll::Ready(Ok(())));
+    assert_eq!(sink.flushed_len(), 2);
+    sink.as_mut().start_send(2).unwrap();
+
+    assert_eq!(sink.as_mut().poll_close(&mut cx), Poll::Ready(Ok(())));
+    assert!(sink.is_closed());
+    assert_eq!(sink.items(), vec![0, 1, 2]);
+    assert_eq!(sink.flushed_len(), 3);
+}
+
+#[test]
+#[should_panic(expected = "without a successful `poll_ready`")]
+fn start_send_without_poll_ready_panics() {
+    let sink = MockSink::<u32>::new();
+    pin_mut!(sink);
+    let _ = sink.start_send(1);
+}
+
+#[test]
+fn buffer_with_slow_flushes() {
+    let mut mock = MockSink::<u32>::with_capacity(1);
+    for _ in 0..6 {
+        mock.push_flush(Poll::Pending);
+    }
+    let mut sink = mock.buffer(4);
+
+    let mut items = stream::iter(vec![1, 2, 3, 4, 5]);
+    block_on(sink.send_all(&mut items)).unwrap();
+    block_on(sink.close()).unwrap();
+
+    let mock = sink.get_ref();
+    assert_eq!(mock.items(), vec![1, 2, 3, 4, 5]);
+    assert!(mock.is_closed());
+}
+
+#[test]
+fn interleave_pending_sink_passes_items_through() {
+    let mut sink = MockSink::<u32>::new().interleave_pending_sink();
+
+    block_on(sink.send(7)).unwrap();
+    block_on(sink.send(8)).unwrap();
+
+    assert_eq!(sink.get_ref().items(), vec![7, 8]);
+}
+
+#[test]
+fn with_try_flat_map_unifies_errors() {
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        Sink(&'static str),
+        Expand(u32),
+    }
+
+    impl From<&'static str> for Error {
+        fn from(e: &'static str) -> Error {
+            Error::Sink(e)
+        }
+    }
+
+    let mut mock = MockSink::<u32, &str>::new();
+    mock.push_ready(Poll::Pending);
+    let mut sink = mock.with_try_flat_map(|x: u32| {
+        stream::iter((0..x).map(move |i| if i == 2 { Err(Error::Expand(x)) } else { Ok(i) }))
+    });
+
+    block_on(sink.send(2)).unwrap();
+    // The items before the error are sent, the rest of the expansion is
+    // dropped.
+    assert_eq!(block_on(sink.send(4)), Err(Error::Expand(4)));
+    block_on(sink.send(1)).unwrap();
+    assert_eq!(sink.get_ref().items(), vec![0, 1, 0, 1, 0]);
+
+    sink.get_mut().push_flush(Poll::Ready(Err("flush failed")));
+    assert_eq!(block_on(sink.flush()), Err(Error::Sink("flush failed")));
+}
+
+#[test]
+fn sink_flush_every() {
+    let mut cx = noop_context();
+    let mut sink = MockSink::<u32, &str>::new();
+    // The first periodic flush is pending once, the explicit one succeeds
+    // and the second periodic one fails.
+    sink.push_flush(Poll::Pending);
+    sink.push_flush(Poll::Ready(Ok(())));
+    sink.push_flush(Poll::Ready(Ok(())));
+    sink.push_flush(Poll::Ready(Err("flush failed")));
+    let sink = sink.sink_flush_every(2);
+    pin_mut!(sink);
+
+    for item in 1..=2 {
+        assert_eq!(sink.as_mut().poll_ready(&mut cx), Poll::Ready(Ok(())));
+        sink.as_mut().start_send(item).unwrap();
+    }
+    assert_eq!(sink.get_ref().flushed_len(), 0);
+
+    // The third item waits for the first two to be flushed.
+    assert_eq!(sink.as_mut().poll_ready(&mut cx), Poll::Pending);
+    assert_eq!(sink.as_mut().poll_ready(&mut cx), Poll::Ready(Ok(())));
+    assert_eq!(sink.get_ref().flushed_len(), 2);
+    sink.as_mut().start_send(3).unwrap();
+    assert_eq!(sink.unflushed(), 1);
+
+    // An explicit flush resets the count.
+    block_on(sink.as_mut().flush()).unwrap();
+    assert_eq!(sink.unflushed(), 0);
+    for item in 4..=5 {
+        assert_eq!(sink.as_mut().poll_ready(&mut cx), Poll::Ready(Ok(())));
+        sink.as_mut().start_send(item).unwrap();
+    }
+    assert_eq!(sink.as_mut().poll_ready(&mut cx), Poll::Ready(Err("flush failed")));
+}
@@ -1,8 +1,14 @@
 #![feature(async_await)]
 
+use futures::channel::mpsc;
 use futures::executor::block_on;
-use futures::stream;
+use futures::future;
+use futures::stream::{self, FusedStream, Stream};
+use futures::task::Poll;
 use futures_util::StreamExt;
+use futures_test::stream::StreamTestExt;
+use futures_test::task::noop_context;
+use std::pin::Pin;
 
 #[test]
 fn select() {
@@ -17,3 +23,574 @@ fn select() {
     select_and_compare(vec![1, 2, 3], vec![4, 5], vec![1, 4, 2, 5, 3]);
     select_and_compare(vec![1, 2], vec![4, 5, 6], vec![1, 4, 2, 5, 6]);
 }
+
+#[test]
+fn unzip() {
+    let stream = stream::iter(vec![(1, 'a'), (2, 'b'), (3, 'c')]).interleave_pending();
+    let (numbers, letters): (Vec<_>, String) = block_on(stream.unzip());
+    assert_eq!(numbers, vec![1, 2, 3]);
+    assert_eq!(letters, "abc");
+}
+
+#[test]
+fn unzip_empty() {
+    let stream = stream::iter(Vec::<(i32, i32)>::new());
+    let (left, right): (Vec<_>, Vec<_>) = block_on(stream.unzip());
+    assert!(left.is_empty());
+    assert!(right.is_empty());
+}
+
+#[test]
+fn repeat_with() {
+    let mut calls = 0;
+    let stream = stream::repeat_with(|| {
+        calls += 1;
+        calls
+    });
+    assert_eq!(stream.size_hint(), (usize::max_value(), None));
+    assert_eq!(block_on(stream.take(3).collect::<Vec<_>>()), vec![1, 2, 3]);
+    assert_eq!(calls, 3);
+}
+
+#[test]
+fn size_hint() {
+    assert_eq!(stream::iter(vec![1, 2, 3]).size_hint(), (3, Some(3)));
+    assert_eq!(stream::empty::<i32>().size_hint(), (0, Some(0)));
+    assert_eq!(stream::repeat(1).size_hint(), (usize::max_value(), None));
+
+    let mut once = stream::once(future::ready(1));
+    assert_eq!(once.size_hint(), (1, Some(1)));
+    assert_eq!(block_on(once.next()), Some(1));
+    assert_eq!(once.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn interleave() {
+    let stream1 = stream::iter(vec![1, 3, 5, 7]);
+    let stream2 = stream::iter(vec![2, 4]);
+    let stream = stream1.interleave(stream2);
+    assert_eq!(stream.size_hint(), (6, Some(6)));
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![1, 2, 3, 4, 5, 7]);
+
+    let stream1 = stream::iter(vec![1]);
+    let stream2 = stream::iter(vec![2, 4, 6]).interleave_pending();
+    assert_eq!(block_on(stream1.interleave(stream2).collect::<Vec<_>>()), vec![1, 2, 4, 6]);
+}
+
+#[test]
+fn interleave_waits_for_turn() {
+    let mut cx = noop_context();
+    let (mut tx, rx) = mpsc::unbounded();
+    let mut stream = rx.interleave(stream::iter(vec![10, 20]));
+
+    // The second stream is ready, but it is the first stream's turn.
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    tx.start_send(1).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(10)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    drop(tx);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(20)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(stream.is_terminated());
+}
+
+#[test]
+fn interleave_never_skips_a_pending_turn() {
+    let mut cx = noop_context();
+    let first = stream::iter(vec![1])
+        .chain(stream::poll_fn(|_| -> Poll<Option<i32>> { Poll::Pending }));
+    let mut stream = first.interleave(stream::iter(vec![10, 20, 30]));
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(10)));
+
+    // The first stream never becomes ready again, so the items waiting in the
+    // second stream are never yielded, unlike with `select`.
+    for _ in 0..3 {
+        assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    }
+    assert!(!stream.is_terminated());
+    assert_eq!(stream.get_ref().1.size_hint(), (2, Some(2)));
+}
+
+#[test]
+fn boxed_local() {
+    use futures::stream::LocalBoxStream;
+    use std::rc::Rc;
+
+    // The stream holds on to an `Rc`, so it isn't `Send`.
+    fn numbers<'a>(v: &'a [i32], offset: Rc<i32>) -> LocalBoxStream<'a, i32> {
+        stream::iter(v).map(move |x| x + *offset).boxed_local()
+    }
+
+    let v = vec![1, 2];
+    let result = block_on(numbers(&v, Rc::new(10)).collect::<Vec<_>>());
+    assert_eq!(result, vec![11, 12]);
+}
+
+#[test]
+fn boxed_sync() {
+    use futures::stream::{BoxStream, LocalBoxStream, SyncBoxStream};
+    use std::sync::Arc;
+
+    fn assert_sync<T: Sync>(_: &T) {}
+
+    let st: SyncBoxStream<'_, Arc<i32>> = stream::iter(1..=3).map(Arc::new).boxed_sync();
+    assert_sync(&st);
+    assert_eq!(st.size_hint(), (3, Some(3)));
+    assert_eq!(block_on(st.collect::<Vec<_>>()), vec![Arc::new(1), Arc::new(2), Arc::new(3)]);
+
+    let st: BoxStream<'_, i32> = stream::iter(1..=3).boxed();
+    assert_eq!(st.size_hint(), (3, Some(3)));
+    let st: LocalBoxStream<'_, i32> = stream::iter(1..=3).boxed_local();
+    assert_eq!(st.size_hint(), (3, Some(3)));
+}
+
+#[test]
+fn adapters_forward_size_hint() {
+    let st = || stream::iter(1..=10);
+
+    assert_eq!(st().map(|x| x * 2).size_hint(), (10, Some(10)));
+    assert_eq!(st().inspect(|_| ()).size_hint(), (10, Some(10)));
+    assert_eq!(st().enumerate().size_hint(), (10, Some(10)));
+    assert_eq!(st().then(future::ready).size_hint(), (10, Some(10)));
+    assert_eq!(st().filter(|_| future::ready(true)).size_hint(), (0, Some(10)));
+    assert_eq!(st().filter_map(|x| future::ready(Some(x))).size_hint(), (0, Some(10)));
+    assert_eq!(st().skip_while(|_| future::ready(true)).size_hint(), (0, Some(10)));
+    assert_eq!(st().take_while(|_| future::ready(true)).size_hint(), (0, Some(10)));
+    assert_eq!(st().skip(3).size_hint(), (7, Some(7)));
+    assert_eq!(st().skip(30).size_hint(), (0, Some(0)));
+    assert_eq!(st().take(3).size_hint(), (3, Some(3)));
+    assert_eq!(st().take(30).size_hint(), (10, Some(10)));
+    assert_eq!(stream::repeat(1).take(3).size_hint(), (3, Some(3)));
+    assert_eq!(st().chain(st()).size_hint(), (20, Some(20)));
+    assert_eq!(st().zip(stream::iter(1..=4)).size_hint(), (4, Some(4)));
+    assert_eq!(stream::select(st(), st()).size_hint(), (20, Some(20)));
+    assert_eq!(st().chunks(3).size_hint(), (4, Some(4)));
+    assert_eq!(st().peekable().size_hint(), (10, Some(10)));
+    assert_eq!(st().map(future::ready).buffered(2).size_hint(), (10, Some(10)));
+    assert_eq!(st().map(future::ready).buffer_unordered(2).size_hint(), (10, Some(10)));
+    assert_eq!(stream::iter(vec![st()]).flatten().size_hint(), (0, None));
+
+    // Items held by the adapters are counted too.
+    let mut cx = noop_context();
+    let mut peekable = st().peekable();
+    assert_eq!(Pin::new(&mut peekable).peek(&mut cx), Poll::Ready(Some(&1)));
+    assert_eq!(peekable.size_hint(), (10, Some(10)));
+
+    let mut buffered = st().map(future::ready).buffered(4);
+    assert_eq!(buffered.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(buffered.size_hint(), (9, Some(9)));
+
+    let mut flatten = stream::iter(vec![st()]).flatten();
+    assert_eq!(flatten.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(flatten.size_hint(), (9, Some(9)));
+
+    let mut chunks = st().chunks(4);
+    assert_eq!(chunks.poll_next_unpin(&mut cx), Poll::Ready(Some(vec![1, 2, 3, 4])));
+    assert_eq!(chunks.size_hint(), (2, Some(2)));
+
+    // Counting the item `Zip` holds from the first stream overflows its upper
+    // bound, so the bound of the second stream is the one that counts.
+    let first = stream::iter(vec![0]).chain(stream::iter(0..usize::max_value()));
+    let mut zip = first.zip(stream::once(future::empty::<usize>()));
+    assert_eq!(zip.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(zip.size_hint(), (1, Some(1)));
+}
+
+#[test]
+fn dedup() {
+    // Duplicates are separated by `Pending` on every poll.
+    let stream = stream::iter(vec![1, 1, 2, 2, 2, 3, 1, 1]).interleave_pending();
+    assert_eq!(block_on(stream.dedup().collect::<Vec<_>>()), vec![1, 2, 3, 1]);
+
+    let mut stream = stream::iter(vec![1, 1, 1]).dedup();
+    assert_eq!(stream.size_hint(), (1, Some(3)));
+    assert_eq!(block_on(stream.next()), Some(1));
+    assert_eq!(stream.size_hint(), (0, Some(2)));
+    assert_eq!(block_on(stream.next()), None);
+    assert_eq!(stream::empty::<i32>().dedup().size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn dedup_by_key() {
+    #[derive(Debug, PartialEq)]
+    struct NotClone(u32);
+
+    let items = vec![NotClone(10), NotClone(11), NotClone(20), NotClone(12)];
+    let stream = stream::iter(items).interleave_pending().dedup_by_key(|x| x.0 / 10);
+    assert_eq!(
+        block_on(stream.collect::<Vec<_>>()),
+        vec![NotClone(10), NotClone(20), NotClone(12)],
+    );
+
+    let mut stream = stream::iter(vec![5, 5, 6]).dedup_by_key(|x| *x);
+    assert_eq!(stream.size_hint(), (1, Some(3)));
+    assert_eq!(block_on(stream.next()), Some(5));
+    assert_eq!(stream.size_hint(), (0, Some(2)));
+    assert_eq!(block_on(stream.next()), Some(6));
+    assert_eq!(block_on(stream.next()), None);
+}
+
+#[test]
+fn zip_longest() {
+    use futures::stream::EitherOrBoth::{Both, Left, Right};
+
+    let stream1 = stream::iter(vec![1, 2]).interleave_pending();
+    let stream2 = stream::iter(vec!['a', 'b', 'c', 'd']);
+    let stream = stream1.zip_longest(stream2);
+    assert_eq!(
+        block_on(stream.collect::<Vec<_>>()),
+        vec![Both(1, 'a'), Both(2, 'b'), Right('c'), Right('d')],
+    );
+
+    let stream = stream::iter(vec![1, 2, 3]).zip_longest(stream::iter(vec!['a']));
+    assert_eq!(stream.size_hint(), (3, Some(3)));
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![Both(1, 'a'), Left(2), Left(3)]);
+}
+
+#[test]
+fn zip_longest_buffered_item_after_other_side_ends() {
+    use futures::stream::EitherOrBoth::{Both, Left};
+
+    let mut cx = noop_context();
+    let (mut tx, rx) = mpsc::unbounded();
+    let mut stream = stream::iter(vec![1, 2, 3]).zip_longest(rx);
+
+    // The first stream's item is buffered while the channel is empty.
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    tx.start_send('a').unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Both(1, 'a'))));
+
+    // The channel ends while the next item of the first stream is buffered.
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    drop(tx);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Left(2))));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Left(3))));
+    assert!(!stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn any_and_all_short_circuit() {
+    use futures::future::{FusedFuture, FutureExt};
+    use futures_test::future::FutureTestExt;
+
+    let mut cx = noop_context();
+    let never_polled = || stream::poll_fn(|_| -> Poll<Option<i32>> {
+        panic!("stream polled after the result was known")
+    });
+
+    // The predicate future is pending while it holds an item, and the stream
+    // isn't polled again once the result is known.
+    let mut any = stream::iter(vec![1, 2])
+        .chain(never_polled())
+        .any(|x| future::ready(x == 2).pending_once());
+    assert_eq!(any.poll_unpin(&mut cx), Poll::Pending);
+    assert_eq!(any.poll_unpin(&mut cx), Poll::Pending);
+    assert!(!any.is_terminated());
+    assert_eq!(any.poll_unpin(&mut cx), Poll::Ready(true));
+    assert!(any.is_terminated());
+
+    let mut all = stream::iter(vec![2, 4, 5])
+        .chain(never_polled())
+        .all(|x| future::ready(x % 2 == 0));
+    assert_eq!(all.poll_unpin(&mut cx), Poll::Ready(false));
+    assert!(all.is_terminated());
+
+    assert!(!block_on(stream::iter(vec![1, 3]).any(|x| future::ready(x == 2))));
+    assert!(block_on(stream::iter(vec![2, 4]).all(|x| future::ready(x % 2 == 0))));
+}
+
+#[test]
+fn find_and_position() {
+    use futures::future::{FusedFuture, FutureExt};
+    use futures_test::future::FutureTestExt;
+
+    let mut cx = noop_context();
+    let never_polled = || stream::poll_fn(|_| -> Poll<Option<i32>> {
+        panic!("stream polled after a match was found")
+    });
+
+    // The match arrives after several `Pending`s from both the stream and the
+    // predicate.
+    let mut find = stream::iter(vec![1, 3, 4, 5])
+        .interleave_pending()
+        .chain(never_polled())
+        .find(|x| future::ready(x % 2 == 0).pending_once());
+    let mut pending = 0;
+    let found = loop {
+        match find.poll_unpin(&mut cx) {
+            Poll::Ready(found) => break found,
+            Poll::Pending => pending += 1,
+        }
+    };
+    assert_eq!(found, Some(4));
+    assert!(pending >= 3);
+    assert!(find.is_terminated());
+
+    let mut position = stream::iter(vec![1, 3, 4, 5])
+        .interleave_pending()
+        .chain(never_polled())
+        .position(|x| future::ready(x % 2 == 0).pending_once());
+    let index = loop {
+        if let Poll::Ready(index) = position.poll_unpin(&mut cx) {
+            break index;
+        }
+    };
+    assert_eq!(index, Some(2));
+    assert!(position.is_terminated());
+
+    // No matching item.
+    let stream = stream::iter(vec![1, 3, 5]).interleave_pending();
+    assert_eq!(block_on(stream.find(|x| future::ready(x % 2 == 0))), None);
+    let stream = stream::iter(vec![1, 3, 5]).interleave_pending();
+    assert_eq!(block_on(stream.position(|x| future::ready(x % 2 == 0))), None);
+}
+
+#[test]
+fn take_while_inclusive() {
+    // The inner stream is never polled after the terminating item.
+    let st = stream::iter(vec![1, 2, 3, 4])
+        .chain(stream::poll_fn(|_| -> Poll<Option<i32>> { panic!("polled after end") }));
+    let mut st = st.take_while_inclusive(|x| future::ready(*x < 3));
+    assert_eq!(block_on(st.by_ref().collect::<Vec<_>>()), vec![1, 2, 3]);
+    assert!(st.is_terminated());
+    assert_eq!(block_on(st.next()), None);
+
+    // Failing on the very first item still yields it.
+    let mut st = stream::iter(vec![5, 1]).take_while_inclusive(|x| future::ready(*x < 3));
+    assert!(!st.is_terminated());
+    assert_eq!(block_on(st.next()), Some(5));
+    assert!(st.is_terminated());
+    assert_eq!(block_on(st.next()), None);
+
+    // Ending before any item fails the predicate.
+    let mut st = stream::iter(vec![1, 2]).interleave_pending()
+        .take_while_inclusive(|x| future::ready(*x < 3));
+    assert_eq!(block_on(st.by_ref().collect::<Vec<_>>()), vec![1, 2]);
+    assert!(st.is_terminated());
+}
+
+#[test]
+fn map_while() {
+    use futures_test::future::FutureTestExt;
+
+    // The closure's future stays pending across several polls before
+    // resolving to `None`, and the inner stream isn't polled afterwards.
+    let st = stream::iter(vec![1, 2, 3, 4])
+        .chain(stream::poll_fn(|_| -> Poll<Option<i32>> { panic!("polled after end") }));
+    let mut st = st.map_while(|x| {
+        let ret = if x < 3 { Some(x * 10) } else { None };
+        future::ready(ret).pending_once().pending_once().pending_once()
+    });
+    assert_eq!(st.size_hint(), (0, None));
+    assert_eq!(block_on(st.by_ref().collect::<Vec<_>>()), vec![10, 20]);
+    assert!(st.is_terminated());
+    assert_eq!(st.size_hint(), (0, Some(0)));
+    assert_eq!(block_on(st.next()), None);
+
+    // The upper bound comes from the underlying stream.
+    let mut st = stream::iter(vec![1, 2, 3]).map_while(|x| future::ready(Some(x)));
+    assert_eq!(st.size_hint(), (0, Some(3)));
+    assert!(!st.is_terminated());
+    assert_eq!(block_on(st.by_ref().collect::<Vec<_>>()), vec![1, 2, 3]);
+    assert!(st.is_terminated());
+}
+
+#[test]
+fn next_pinned() {
+    use futures::future::FusedFuture;
+    use futures_test::future::FutureTestExt;
+    use pin_utils::pin_mut;
+
+    // `AssertUnmoved` is `!Unpin`, so `StreamExt::next` can't be used.
+    let stream = stream::iter(vec![1, 2])
+        .interleave_pending()
+        .fuse()
+        .then(|x| future::ready(x).assert_unmoved());
+    pin_mut!(stream);
+
+    assert_eq!(block_on(stream.as_mut().next_pinned()), Some(1));
+    assert_eq!(block_on(stream.as_mut().next_pinned()), Some(2));
+    assert!(!stream.as_mut().next_pinned().is_terminated());
+    assert_eq!(block_on(stream.as_mut().next_pinned()), None);
+    assert!(stream.as_mut().next_pinned().is_terminated());
+}
+
+#[test]
+fn last_and_nth() {
+    use futures::future::FusedFuture;
+
+    let mut last = stream::iter(vec![1, 2, 3]).interleave_pending().last();
+    assert!(!last.is_terminated());
+    assert_eq!(block_on(&mut last), Some(3));
+    assert!(last.is_terminated());
+    assert_eq!(block_on(stream::empty::<i32>().last()), None);
+
+    // `nth` doesn't poll the stream past the item it returns.
+    let mut st = stream::iter(vec![1, 2, 3])
+        .chain(stream::poll_fn(|_| -> Poll<Option<i32>> { panic!("polled too far") }));
+    assert_eq!(block_on(st.by_ref().nth(0)), Some(1));
+    assert_eq!(block_on(st.by_ref().interleave_pending().nth(1)), Some(3));
+
+    let mut nth = stream::iter(vec![1, 2]).nth(2);
+    assert_eq!(block_on(&mut nth), None);
+    assert!(nth.is_terminated());
+}
+
+#[test]
+fn zip_drops_both_streams_when_one_ends() {
+    use std::sync::Arc;
+
+    let mut cx = noop_context();
+
+    // The receiver is dropped as soon as the other stream ends.
+    let (tx, rx) = mpsc::unbounded();
+    tx.unbounded_send(10).unwrap();
+    tx.unbounded_send(20).unwrap();
+    let mut zip = stream::iter(vec![1]).zip(rx);
+    assert_eq!(zip.get_ref().0.size_hint(), (1, Some(1)));
+    assert_eq!(zip.poll_next_unpin(&mut cx), Poll::Ready(Some((1, 10))));
+    assert!(!tx.is_closed());
+    assert_eq!(zip.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(tx.is_closed());
+    assert!(zip.is_terminated());
+    assert_eq!(zip.poll_next_unpin(&mut cx), Poll::Ready(None));
+
+    // An item buffered from the other stream is discarded.
+    let token = Arc::new(());
+    let (tx1, rx1) = mpsc::unbounded();
+    let (tx2, rx2) = mpsc::unbounded::<i32>();
+    tx1.unbounded_send(token.clone()).unwrap();
+    let mut zip = rx1.zip(rx2);
+    assert_eq!(zip.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(Arc::strong_count(&token), 2);
+    drop(tx2);
+    assert_eq!(zip.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert_eq!(Arc::strong_count(&token), 1);
+    assert!(tx1.is_closed());
+}
+
+#[test]
+fn merge_sorted() {
+    let st1 = stream::iter(vec![(1, 'a'), (3, 'a'), (3, 'b'), (8, 'a')]).interleave_pending();
+    let st2 = stream::iter(vec![(2, 'c'), (3, 'c'), (9, 'c')]).interleave_pending();
+    let merged = st1.merge_sorted(st2, |a, b| a.0.cmp(&b.0));
+    assert_eq!(block_on(merged.collect::<Vec<_>>()), vec![
+        (1, 'a'), (2, 'c'), (3, 'a'), (3, 'b'), (3, 'c'), (8, 'a'), (9, 'c'),
+    ]);
+
+    // Nothing is yielded until the next item of both streams is known.
+    let mut cx = noop_context();
+    let (tx1, rx1) = mpsc::unbounded();
+    let (tx2, rx2) = mpsc::unbounded();
+    let mut merged = rx1.merge_sorted(rx2, |a: &i32, b: &i32| a.cmp(b));
+    tx1.unbounded_send(2).unwrap();
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Pending);
+    tx2.unbounded_send(1).unwrap();
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Pending);
+    drop(tx2);
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert!(!merged.is_terminated());
+    drop(tx1);
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(merged.is_terminated());
+}
+
+#[test]
+fn merge_sorted_all() {
+    let streams = vec![
+        stream::iter(vec![(1, 'a'), (4, 'a')]).interleave_pending(),
+        stream::iter(vec![]).interleave_pending(),
+        stream::iter(vec![(0, 'c'), (4, 'c'), (5, 'c')]).interleave_pending(),
+        stream::iter(vec![(1, 'd'), (2, 'd')]).interleave_pending(),
+    ];
+    let merged = stream::merge_sorted_all(streams, |a, b| a.0.cmp(&b.0));
+    assert_eq!(block_on(merged.collect::<Vec<_>>()), vec![
+        (0, 'c'), (1, 'a'), (1, 'd'), (2, 'd'), (4, 'a'), (4, 'c'), (5, 'c'),
+    ]);
+
+    let merged = stream::merge_sorted_all(Vec::<stream::Iter<std::vec::IntoIter<i32>>>::new(), Ord::cmp);
+    assert_eq!(block_on(merged.collect::<Vec<_>>()), vec![]);
+}
+
+#[test]
+fn merge_macro() {
+    use futures::merge;
+
+    // Differently typed streams are polled in turns, even if they are
+    // always ready.
+    let a = stream::repeat('a');
+    let b = stream::iter(vec!['b', 'b']);
+    let c = stream::repeat('c').map(|c| c.to_ascii_uppercase());
+    let merged = merge!(a, b, c);
+    assert_eq!(
+        block_on(merged.take(8).collect::<String>()),
+        "abCabCaC",
+    );
+
+    // The merged stream only ends once all of the streams have ended.
+    let mut cx = noop_context();
+    let (tx1, rx1) = mpsc::unbounded::<i32>();
+    let (tx2, rx2) = mpsc::unbounded::<i32>();
+    let mut merged = merge!(rx1, rx2);
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Pending);
+    tx2.unbounded_send(2).unwrap();
+    drop(tx1);
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Pending);
+    drop(tx2);
+    assert_eq!(merged.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(merged.is_terminated());
+}
+
+#[test]
+fn armed_prefetch() {
+    use std::cell::Cell;
+
+    let pulled = Cell::new(0);
+    let mut st = stream::iter(1..=10)
+        .inspect(|_| pulled.set(pulled.get() + 1))
+        .armed_prefetch(3);
+    assert_eq!(block_on(st.next()), Some(1));
+    // The buffer is topped back up after yielding.
+    assert_eq!(pulled.get(), 4);
+    assert_eq!(st.prefetched(), 3);
+    assert_eq!(block_on(st.collect::<Vec<_>>()), (2..=10).collect::<Vec<_>>());
+
+    let mut st = stream::iter(1..=10).armed_prefetch(3);
+    assert_eq!(block_on(st.next()), Some(1));
+    assert_eq!(st.size_hint(), (9, Some(9)));
+
+    // Reading ahead of a pending stream.
+    let mut cx = noop_context();
+    let (tx, rx) = mpsc::unbounded();
+    let mut st = rx.armed_prefetch(3);
+    assert_eq!(st.poll_next_unpin(&mut cx), Poll::Pending);
+    tx.unbounded_send(1).unwrap();
+    tx.unbounded_send(2).unwrap();
+    assert_eq!(Pin::new(&mut st).poll_prefetch(&mut cx), Poll::Pending);
+    assert_eq!(st.prefetched(), 2);
+    tx.unbounded_send(3).unwrap();
+    tx.unbounded_send(4).unwrap();
+    assert_eq!(Pin::new(&mut st).poll_prefetch(&mut cx), Poll::Ready(()));
+    assert_eq!(st.prefetched(), 3);
+    drop(tx);
+    for i in 1..=4 {
+        assert!(!st.is_terminated());
+        assert_eq!(st.poll_next_unpin(&mut cx), Poll::Ready(Some(i)));
+    }
+    assert_eq!(st.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(st.is_terminated());
+}
+
+#[test]
+#[should_panic]
+fn armed_prefetch_zero() {
+    let _ = stream::empty::<()>().armed_prefetch(0);
+}
@@ -0,0 +1,186 @@
+use futures::channel::oneshot;
+use futures::future::{self, FutureExt};
+use futures::stream::{self, FusedStream, StreamExt};
+use futures::task::{Context, Poll};
+use futures_test::{assert_stream_pending, assert_stream_next, assert_stream_done};
+use futures_test::task::new_count_waker;
+use std::cell::Cell;
+
+#[test]
+fn buffer_unordered_shrink_limit_below_in_flight() {
+    let pulled = Cell::new(0);
+    let (txs, rxs): (Vec<_>, Vec<_>) = (0..4).map(|_| oneshot::channel::<i32>()).unzip();
+    let mut stream = stream::iter(rxs)
+        .inspect(|_| pulled.set(pulled.get() + 1))
+        .buffer_unordered(3);
+    let mut txs = txs.into_iter();
+
+    assert_stream_pending!(stream);
+    assert_eq!(pulled.get(), 3);
+
+    stream.set_limit(1);
+    assert_eq!(stream.limit(), 1);
+
+    // In-flight futures keep running, but nothing new is admitted until
+    // fewer than one future is left.
+    for i in 0..3 {
+        txs.next().unwrap().send(i).unwrap();
+        assert_stream_next!(stream, Ok(i));
+        assert_eq!(pulled.get(), 3);
+    }
+
+    assert_stream_pending!(stream);
+    assert_eq!(pulled.get(), 4);
+
+    txs.next().unwrap().send(3).unwrap();
+    assert_stream_next!(stream, Ok(3));
+    assert_stream_done!(stream);
+}
+
+#[test]
+fn buffered_grow_limit_mid_stream() {
+    let pulled = Cell::new(0);
+    let (txs, rxs): (Vec<_>, Vec<_>) = (0..4).map(|_| oneshot::channel::<i32>()).unzip();
+    let mut stream = stream::iter(rxs)
+        .inspect(|_| pulled.set(pulled.get() + 1))
+        .buffered(1);
+
+    assert_stream_pending!(stream);
+    assert_eq!(pulled.get(), 1);
+
+    stream.set_limit(4);
+    assert_eq!(stream.limit(), 4);
+    assert_stream_pending!(stream);
+    assert_eq!(pulled.get(), 4);
+
+    // Completing out of order still yields items in the original order.
+    for (i, tx) in txs.into_iter().enumerate().rev() {
+        tx.send(i as i32).unwrap();
+    }
+    for i in 0..4 {
+        assert_stream_next!(stream, Ok(i));
+    }
+    assert_stream_done!(stream);
+}
+
+#[test]
+#[should_panic(expected = "limit must be greater than zero")]
+fn set_limit_zero_panics() {
+    let mut stream = stream::iter(Vec::<oneshot::Receiver<()>>::new()).buffered(1);
+    stream.set_limit(0);
+}
+
+#[test]
+fn buffer_unordered_in_flight() {
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let (txs, rxs): (Vec<_>, Vec<_>) = (0..3).map(|_| oneshot::channel::<i32>()).unzip();
+    let mut stream = stream::iter(rxs).buffer_unordered(2);
+    let mut txs = txs.into_iter();
+
+    assert_eq!(stream.in_flight(), 0);
+    assert!(!stream.is_saturated());
+
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+    assert_eq!(stream.in_flight(), 2);
+    assert!(stream.is_saturated());
+
+    txs.next().unwrap().send(0).unwrap();
+    assert_eq!(counter, 1);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(0))));
+    assert_eq!(stream.in_flight(), 1);
+    assert!(!stream.is_saturated());
+
+    // The next poll refills the queue from the now exhausted source stream.
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+    assert_eq!(stream.in_flight(), 2);
+
+    for (i, tx) in txs.enumerate() {
+        tx.send(i as i32 + 1).unwrap();
+    }
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(1))));
+    assert_eq!(stream.in_flight(), 1);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(2))));
+    assert_eq!(stream.in_flight(), 0);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(stream.is_terminated());
+    assert_eq!(stream.in_flight(), 0);
+    assert!(!stream.is_saturated());
+}
+
+#[test]
+fn buffered_in_flight_counts_held_back_outputs() {
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+    let mut stream = stream::iter(vec![a_rx, b_rx]).buffered(2);
+
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+    assert_eq!(stream.in_flight(), 2);
+
+    // `b` completes first, but its output waits for `a`.
+    b_tx.send(2).unwrap();
+    assert_eq!(counter, 1);
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+    assert_eq!(stream.in_flight(), 2);
+    assert!(stream.is_saturated());
+
+    a_tx.send(1).unwrap();
+    assert_eq!(counter, 2);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(1))));
+    assert_eq!(stream.in_flight(), 1);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(2))));
+    assert_eq!(stream.in_flight(), 0);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert_eq!(stream.in_flight(), 0);
+}
+
+#[test]
+fn buffered_starts_next_future_when_yielding() {
+    let (waker, _) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    let pulled = Cell::new(0);
+    let started = Cell::new(0);
+    let (txs, rxs): (Vec<_>, Vec<_>) = (0..4).map(|_| oneshot::channel::<i32>()).unzip();
+    let mut stream = stream::iter(rxs)
+        .inspect(|_| pulled.set(pulled.get() + 1))
+        .map(|mut rx| {
+            let mut first_poll = true;
+            let started = &started;
+            future::poll_fn(move |cx| {
+                if first_poll {
+                    first_poll = false;
+                    started.set(started.get() + 1);
+                }
+                rx.poll_unpin(cx)
+            })
+        })
+        .buffered(2);
+    let mut txs = txs.into_iter();
+
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+    assert_eq!((pulled.get(), started.get()), (2, 2));
+
+    // The third future is pulled and started as the first output is
+    // yielded, not only once the stream is polled again.
+    txs.next().unwrap().send(0).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(0))));
+    assert_eq!((pulled.get(), started.get()), (3, 3));
+
+    // An output of a future started that way still waits for its turn.
+    let b_tx = txs.next().unwrap();
+    txs.next().unwrap().send(2).unwrap();
+    assert!(stream.poll_next_unpin(&mut cx).is_pending());
+    b_tx.send(1).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(1))));
+    assert_eq!((pulled.get(), started.get()), (4, 4));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(2))));
+
+    txs.next().unwrap().send(3).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(3))));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
@@ -0,0 +1,107 @@
+use futures::stream::{self, Stream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::pin::Pin;
+
+// Counts the allocations made on each thread, so that tests running in
+// parallel don't disturb each other's counts.
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = Cell::new(0);
+}
+
+fn record_allocation() {
+    let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record_allocation();
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record_allocation();
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn count_allocations<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let res = f();
+    (res, ALLOCATIONS.with(Cell::get) - before)
+}
+
+fn next<St: Stream + Unpin>(stream: &mut St) -> Option<St::Item> {
+    match stream.poll_next_unpin(&mut noop_context()) {
+        Poll::Ready(item) => item,
+        Poll::Pending => panic!("stream is not ready"),
+    }
+}
+
+#[test]
+fn chunks_allocate_once_per_chunk() {
+    let mut chunks = stream::iter(0..10).chunks(4);
+    assert_eq!(chunks.chunk_size(), 4);
+
+    // Full chunks are allocated exactly once, with the configured capacity.
+    let (chunk, allocs) = count_allocations(|| next(&mut chunks));
+    let chunk = chunk.unwrap();
+    assert_eq!(chunk, vec![0, 1, 2, 3]);
+    assert_eq!(chunk.capacity(), 4);
+    assert_eq!(allocs, 1);
+
+    let (chunk, allocs) = count_allocations(|| next(&mut chunks));
+    assert_eq!(chunk, Some(vec![4, 5, 6, 7]));
+    assert_eq!(allocs, 1);
+
+    // The partial final chunk is still yielded.
+    let (chunk, allocs) = count_allocations(|| next(&mut chunks));
+    assert_eq!(chunk, Some(vec![8, 9]));
+    assert_eq!(allocs, 1);
+
+    // Ending the stream doesn't allocate a buffer that is never used.
+    let (chunk, allocs) = count_allocations(|| next(&mut chunks));
+    assert_eq!(chunk, None);
+    assert_eq!(allocs, 0);
+
+    // Neither does a stream ending right after a full chunk.
+    let mut chunks = stream::iter(0..4).chunks(4);
+    assert_eq!(next(&mut chunks), Some(vec![0, 1, 2, 3]));
+    let (chunk, allocs) = count_allocations(|| next(&mut chunks));
+    assert_eq!(chunk, None);
+    assert_eq!(allocs, 0);
+}
+
+#[test]
+fn chunks_recycle() {
+    let mut chunks = stream::iter(0..10).chunks(4);
+
+    let chunk = next(&mut chunks).unwrap();
+    assert_eq!(chunk, vec![0, 1, 2, 3]);
+    let ptr = chunk.as_ptr();
+    Pin::new(&mut chunks).recycle(chunk);
+
+    // The recycled buffer is reused instead of allocating a new one.
+    let (chunk, allocs) = count_allocations(|| next(&mut chunks));
+    let chunk = chunk.unwrap();
+    assert_eq!(chunk, vec![4, 5, 6, 7]);
+    assert_eq!(chunk.as_ptr(), ptr);
+    assert_eq!(allocs, 0);
+
+    // A buffer that is too small is grown to the chunk size.
+    Pin::new(&mut chunks).recycle(Vec::with_capacity(1));
+    let chunk = next(&mut chunks).unwrap();
+    assert_eq!(chunk, vec![8, 9]);
+    assert!(chunk.capacity() >= 4);
+}
@@ -0,0 +1,40 @@
+use futures::executor::block_on;
+use futures::stream::{self, FusedStream, StreamExt};
+use futures_test::stream::StreamTestExt;
+
+fn chunk_lens(chunks: Vec<Vec<Vec<u8>>>) -> Vec<Vec<usize>> {
+    chunks.into_iter()
+        .map(|chunk| chunk.into_iter().map(|item| item.len()).collect())
+        .collect()
+}
+
+#[test]
+fn chunks_up_to_max_bytes() {
+    let items = vec![vec![0; 2], vec![0; 2], vec![0; 1], vec![0; 3], vec![0; 2], vec![0; 1]];
+    let stream = stream::iter(items).interleave_pending().chunks_by_size(5);
+    assert_eq!(stream.max_bytes(), 5);
+
+    let chunks = block_on(stream.collect::<Vec<_>>());
+    assert_eq!(chunk_lens(chunks), vec![vec![2, 2, 1], vec![3, 2], vec![1]]);
+}
+
+#[test]
+fn oversized_item_is_yielded_alone() {
+    let items = vec![vec![0; 1], vec![0; 10], vec![0; 1], vec![0; 10]];
+    let stream = stream::iter(items).interleave_pending().chunks_by_size(4);
+
+    let chunks = block_on(stream.collect::<Vec<_>>());
+    assert_eq!(chunk_lens(chunks), vec![vec![1], vec![10], vec![1], vec![10]]);
+}
+
+#[test]
+fn empty_items_are_yielded() {
+    let items = vec![vec![], vec![0; 4], vec![], vec![], vec![0; 1], vec![]];
+    let mut stream = stream::iter(items).interleave_pending().chunks_by_size(4);
+
+    assert_eq!(chunk_lens(vec![block_on(stream.next()).unwrap()]), vec![vec![0, 4]]);
+    assert_eq!(stream.buffered_bytes(), 0);
+    assert_eq!(chunk_lens(vec![block_on(stream.next()).unwrap()]), vec![vec![0, 0, 1, 0]]);
+    assert_eq!(block_on(stream.next()), None);
+    assert!(stream.is_terminated());
+}
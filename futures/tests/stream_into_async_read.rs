@@ -1,6 +1,8 @@
+#![feature(async_await)]
+
 use core::pin::Pin;
 use futures::io::{AsyncRead, AsyncBufRead};
-use futures::stream::{self, TryStreamExt};
+use futures::stream::{self, StreamExt, TryStreamExt};
 use futures::task::Poll;
 use futures_test::{task::noop_context, stream::StreamTestExt};
 
@@ -94,3 +96,99 @@ fn test_into_async_bufread() -> std::io::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_into_async_read_not_unpin() {
+    use futures::executor::block_on;
+    use futures::io::AsyncReadExt;
+    use pin_utils::pin_mut;
+
+    // The `async` block makes the source stream `!Unpin`.
+    let stream = stream::iter(vec![vec![1, 2, 3], vec![], vec![4, 5]])
+        .then(|chunk| async move { Ok(chunk) })
+        .interleave_pending();
+    let reader = stream.into_async_read();
+    pin_mut!(reader);
+
+    let mut buf = Vec::new();
+    block_on(reader.read_to_end(&mut buf)).unwrap();
+    assert_eq!(buf, vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_into_async_read_map_err() {
+    use std::cell::Cell;
+    use std::io;
+
+    let conversions = Cell::new(0);
+    let stream = stream::iter(vec![Ok(vec![1, 2, 3]), Err("reset"), Ok(vec![4])]);
+    let mut reader = stream.interleave_pending().into_async_read_map_err(|e| {
+        conversions.set(conversions.get() + 1);
+        io::Error::new(io::ErrorKind::ConnectionReset, e)
+    });
+    let mut cx = noop_context();
+    let mut buf = [0; 5];
+
+    assert_read!(reader, &mut buf, 3);
+    assert_eq!(&buf[..3], &[1, 2, 3]);
+    let err = loop {
+        match Pin::new(&mut reader).poll_read(&mut cx, &mut buf) {
+            Poll::Ready(res) => break res.unwrap_err(),
+            Poll::Pending => continue,
+        }
+    };
+    assert_eq!(err.kind(), io::ErrorKind::ConnectionReset);
+    assert_eq!(conversions.get(), 1);
+
+    // The reader is at end-of-file after the error.
+    assert_read!(reader, &mut buf, 0);
+    assert_eq!(conversions.get(), 1);
+}
+
+#[test]
+fn test_into_async_read_skips_empty_chunks() {
+    let chunks = || {
+        let chunks = vec![vec![], vec![1, 2], vec![], vec![], vec![3], vec![]];
+        stream::iter(chunks.into_iter().map(Ok)).interleave_pending()
+    };
+    let mut reader = chunks().into_async_read();
+    let mut buf = [0; 3];
+
+    // An empty chunk is never reported as end-of-file.
+    assert_read!(reader, &mut buf, 2);
+    assert_eq!(&buf[..2], &[1, 2]);
+    assert_read!(reader, &mut buf, 1);
+    assert_eq!(&buf[..1], &[3]);
+    assert_read!(reader, &mut buf, 0);
+
+    let mut reader = chunks().into_async_read();
+    let mut reader = Pin::new(&mut reader);
+    assert_fill_buf!(reader, &[1, 2][..]);
+    reader.as_mut().consume(2);
+    assert_fill_buf!(reader, &[3][..]);
+    reader.as_mut().consume(1);
+    assert_fill_buf!(reader, &[][..]);
+}
+
+#[test]
+fn test_into_async_read_consume_zero() {
+    let stream = stream::iter(vec![Ok(vec![1, 2]), Ok(vec![])]);
+    let mut reader = stream.interleave_pending().into_async_read();
+    let mut reader = Pin::new(&mut reader);
+
+    // Before the first chunk.
+    reader.as_mut().consume(0);
+    assert_fill_buf!(reader, &[1, 2][..]);
+    // Within a chunk.
+    reader.as_mut().consume(0);
+    reader.as_mut().consume(1);
+    reader.as_mut().consume(0);
+    assert_fill_buf!(reader, &[2][..]);
+    // Right after the chunk was fully consumed.
+    reader.as_mut().consume(1);
+    reader.as_mut().consume(0);
+    assert_fill_buf!(reader, &[][..]);
+    // At end-of-file.
+    reader.as_mut().consume(0);
+    assert_fill_buf!(reader, &[][..]);
+}
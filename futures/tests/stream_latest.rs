@@ -0,0 +1,44 @@
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::stream::{self, FusedStream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+
+#[test]
+fn yields_only_the_newest_item() {
+    let mut cx = noop_context();
+    let (tx, rx) = mpsc::unbounded();
+    let mut stream = rx.latest();
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+
+    for i in 0..100 {
+        tx.unbounded_send(i).unwrap();
+    }
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(99)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+
+    for i in 100..200 {
+        tx.unbounded_send(i).unwrap();
+    }
+    drop(tx);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(199)));
+    assert!(!stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(stream.is_terminated());
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn yields_last_item_before_ending() {
+    let stream = stream::iter(0..100).latest();
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![99]);
+}
+
+#[test]
+fn always_ready_stream_does_not_stall() {
+    let mut cx = noop_context();
+    let mut stream = stream::repeat(1).latest();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+}
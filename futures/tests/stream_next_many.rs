@@ -0,0 +1,63 @@
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt};
+use futures::task::{Context, Poll};
+use futures_test::task::new_count_waker;
+
+#[test]
+fn single_wake_delivers_batch() {
+    let (tx, mut rx) = mpsc::unbounded();
+    let (waker, count) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let mut buf = Vec::new();
+
+    assert_eq!(rx.poll_next_batch(&mut cx, &mut buf, 10), Poll::Pending);
+    for i in 0..5 {
+        tx.unbounded_send(i).unwrap();
+    }
+    assert_eq!(count, 1);
+
+    // Everything queued since the wake up is pulled at once.
+    assert_eq!(rx.poll_next_batch(&mut cx, &mut buf, 10), Poll::Ready(5));
+    assert_eq!(buf, vec![0, 1, 2, 3, 4]);
+    assert_eq!(rx.poll_next_batch(&mut cx, &mut buf, 10), Poll::Pending);
+
+    drop(tx);
+    assert_eq!(rx.poll_next_batch(&mut cx, &mut buf, 10), Poll::Ready(0));
+}
+
+#[test]
+fn respects_limit() {
+    let (tx, rx) = mpsc::unbounded();
+    for i in 0..5 {
+        tx.unbounded_send(i).unwrap();
+    }
+    drop(tx);
+
+    // The receiver must not be polled again after it ended, which the last
+    // batch with items does.
+    let mut rx = rx.fuse();
+
+    let mut buf = vec![-1];
+    assert_eq!(block_on(rx.next_many(&mut buf, 2)), 2);
+    assert_eq!(block_on(rx.next_many(&mut buf, 2)), 2);
+    assert_eq!(block_on(rx.next_many(&mut buf, 2)), 1);
+    assert_eq!(block_on(rx.next_many(&mut buf, 2)), 0);
+    assert_eq!(buf, vec![-1, 0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn end_after_items_is_reported_next() {
+    let mut stream = stream::iter(vec![1, 2]).fuse();
+    let mut buf = Vec::new();
+    assert_eq!(block_on(stream.next_many(&mut buf, 10)), 2);
+    assert_eq!(block_on(stream.next_many(&mut buf, 10)), 0);
+}
+
+#[test]
+#[should_panic(expected = "limit must be greater than zero")]
+fn zero_limit_panics() {
+    let mut st = stream::iter(vec![1]);
+    let (waker, _count) = new_count_waker();
+    let _ = st.poll_next_batch(&mut Context::from_waker(&waker), &mut Vec::new(), 0);
+}
@@ -1,10 +1,13 @@
 #![feature(async_await)]
 
+use futures::channel::mpsc;
 use futures::executor::block_on_stream;
 use futures::future::{self, FutureExt};
-use futures::stream::{self, FusedStream, SelectAll, StreamExt};
+use futures::stream::{self, FusedStream, SelectAll, Stream, StreamExt};
 use futures::task::Poll;
 use futures_test::task::noop_context;
+use std::pin::Pin;
+use std::sync::Arc;
 
 #[test]
 fn is_terminated() {
@@ -48,3 +51,99 @@ fn issue_1626() {
     assert_eq!(s.next(), Some(14));
     assert_eq!(s.next(), None);
 }
+
+#[test]
+fn terminated_streams_are_dropped() {
+    let token = Arc::new(());
+    let finite = {
+        let token = token.clone();
+        stream::iter(vec![1]).map(move |x| { let _ = &token; x })
+    };
+    let (tx, rx) = mpsc::unbounded::<i32>();
+
+    let mut cx = noop_context();
+    let streams: Vec<Pin<Box<dyn Stream<Item = i32>>>> = vec![Box::pin(finite), Box::pin(rx)];
+    let mut tasks = stream::select_all(streams);
+    let mut on_empty = tasks.on_empty();
+
+    assert_eq!(tasks.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(tasks.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(Arc::strong_count(&token), 1);
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(on_empty.poll_unpin(&mut cx), Poll::Pending);
+
+    drop(tx);
+    assert_eq!(tasks.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert_eq!(on_empty.poll_unpin(&mut cx), Poll::Ready(()));
+}
+
+#[test]
+fn on_empty() {
+    let mut cx = noop_context();
+    let mut tasks = SelectAll::<stream::Empty<i32>>::new();
+    assert_eq!(tasks.on_empty().poll_unpin(&mut cx), Poll::Ready(()));
+
+    let (_tx, rx) = mpsc::unbounded::<i32>();
+    let mut tasks = stream::select_all(vec![rx]);
+    let mut on_empty = tasks.on_empty();
+    assert_eq!(tasks.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(on_empty.poll_unpin(&mut cx), Poll::Pending);
+
+    // Dropping the set also resolves the future.
+    drop(tasks);
+    assert_eq!(on_empty.poll_unpin(&mut cx), Poll::Ready(()));
+}
+
+#[test]
+fn empty_select_all() {
+    let mut cx = noop_context();
+    let mut tasks = stream::select_all(Vec::<stream::Empty<i32>>::new());
+
+    assert!(tasks.is_empty());
+    assert_eq!(tasks.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(tasks.is_terminated());
+
+    // Pushing a stream revives a terminated set.
+    tasks.push(stream::empty());
+    assert!(!tasks.is_terminated());
+    assert_eq!(tasks.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(tasks.is_terminated());
+}
+
+#[cfg(feature = "async-await")]
+#[test]
+fn select_all_in_select_macro() {
+    use futures::executor::block_on;
+    use futures::select;
+
+    let mut streams = stream::select_all(vec![stream::iter(1..=2), stream::iter(3..=3)]);
+    let mut empty = stream::select_all(Vec::<stream::Iter<std::ops::Range<i32>>>::new());
+    let mut items = Vec::new();
+
+    block_on(async {
+        loop {
+            select! {
+                x = streams.select_next_some() => items.push(x),
+                _ = empty.select_next_some() => panic!("empty set yielded an item"),
+                complete => break,
+            }
+        }
+    });
+
+    items.sort();
+    assert_eq!(items, vec![1, 2, 3]);
+}
+
+#[test]
+fn always_ready_streams_are_polled_fairly() {
+    let streams = vec![stream::repeat(0), stream::repeat(1), stream::repeat(2)];
+    let items = block_on_stream(stream::select_all(streams).take(300)).collect::<Vec<usize>>();
+
+    let mut counts = [0; 3];
+    for item in items {
+        counts[item] += 1;
+    }
+    for &count in &counts {
+        assert!((90..=110).contains(&count), "unfair distribution: {:?}", counts);
+    }
+}
@@ -0,0 +1,102 @@
+use futures::channel::mpsc;
+use futures::executor::{block_on, LocalPool};
+use futures::future;
+use futures::stream::{self, FusedStream, StreamExt};
+use futures::task::{LocalSpawnExt, Poll};
+use futures_test::stream::StreamTestExt;
+use futures_test::task::noop_context;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn consumers_at_different_speeds() {
+    let mut pool = LocalPool::new();
+    let mut spawner = pool.spawner();
+
+    let fast = stream::iter(0..10).share();
+    let slow = fast.clone().interleave_pending().interleave_pending();
+
+    let fast_items = Rc::new(RefCell::new(Vec::new()));
+    let slow_items = Rc::new(RefCell::new(Vec::new()));
+    {
+        let fast_items = fast_items.clone();
+        spawner.spawn_local(fast.for_each(move |x| {
+            fast_items.borrow_mut().push(x);
+            future::ready(())
+        })).unwrap();
+    }
+    {
+        let slow_items = slow_items.clone();
+        spawner.spawn_local(slow.for_each(move |x| {
+            slow_items.borrow_mut().push(x);
+            future::ready(())
+        })).unwrap();
+    }
+    pool.run();
+
+    assert_eq!(*fast_items.borrow(), (0..10).collect::<Vec<_>>());
+    assert_eq!(*slow_items.borrow(), (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn slowest_consumer_applies_backpressure() {
+    let mut cx = noop_context();
+    let (tx, rx) = mpsc::unbounded();
+    for i in 0..3 {
+        tx.unbounded_send(i).unwrap();
+    }
+
+    let mut a = rx.share();
+    let mut b = a.clone();
+
+    assert_eq!(a.poll_next_unpin(&mut cx), Poll::Ready(Some(0)));
+    // `b` hasn't taken item 0 yet, so `a` can't advance.
+    assert_eq!(a.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(b.poll_next_unpin(&mut cx), Poll::Ready(Some(0)));
+    assert_eq!(b.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(a.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+
+    // Dropping a handle that hasn't taken the current item releases it.
+    assert_eq!(a.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    drop(b);
+    drop(tx);
+    assert_eq!(a.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(a.is_terminated());
+}
+
+#[test]
+fn new_handles_only_see_later_items() {
+    let mut cx = noop_context();
+    let mut a = stream::iter(0..3).share();
+
+    assert_eq!(a.poll_next_unpin(&mut cx), Poll::Ready(Some(0)));
+    let mut b = a.clone();
+    assert_eq!(b.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(a.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+
+    // A handle cloned from `b` doesn't see item 2, which was produced before.
+    assert_eq!(b.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    let c = b.clone();
+    let (a_items, b_items, c_items) = block_on(future::join3(
+        a.collect::<Vec<_>>(),
+        b.collect::<Vec<_>>(),
+        c.collect::<Vec<_>>(),
+    ));
+    assert_eq!(a_items, vec![2]);
+    assert_eq!(b_items, Vec::<i32>::new());
+    assert_eq!(c_items, Vec::<i32>::new());
+}
+
+#[test]
+fn handle_created_after_termination() {
+    let a = stream::iter(0..2).share();
+    let b = a.clone();
+    drop(b);
+    let late = {
+        let mut a = a;
+        assert_eq!(block_on((&mut a).collect::<Vec<_>>()), vec![0, 1]);
+        a.clone()
+    };
+    assert!(late.is_terminated());
+    assert_eq!(block_on(late.collect::<Vec<_>>()), Vec::<i32>::new());
+}
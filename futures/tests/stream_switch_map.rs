@@ -0,0 +1,69 @@
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::future;
+use futures::stream::{self, FusedStream, StreamExt};
+use futures::task::{Context, Poll};
+use futures_test::task::{new_count_waker, noop_context};
+
+#[test]
+fn new_outer_item_preempts_ready_inner_items() {
+    let mut cx = noop_context();
+    let (tx, rx) = mpsc::unbounded();
+    let mut stream = rx.switch_map(|n: i32| stream::iter(vec![n * 10, n * 10 + 1, n * 10 + 2]));
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+
+    tx.unbounded_send(1).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(10)));
+
+    // The rest of the first inner stream is ready, but never shows up.
+    tx.unbounded_send(2).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(20)));
+
+    // Only the last of several ready outer items is used.
+    tx.unbounded_send(3).unwrap();
+    tx.unbounded_send(4).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(40)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(41)));
+
+    drop(tx);
+    assert!(!stream.is_terminated());
+    assert_eq!(block_on(stream.by_ref().collect::<Vec<_>>()), vec![42]);
+    assert!(stream.is_terminated());
+}
+
+#[test]
+fn waits_for_inner_after_outer_ends() {
+    let mut cx = noop_context();
+    let (inner_tx, inner_rx) = mpsc::unbounded::<i32>();
+    let mut inner_rx = Some(inner_rx);
+    let mut stream = stream::once(future::ready(()))
+        .switch_map(move |()| inner_rx.take().unwrap());
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    inner_tx.unbounded_send(5).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(5)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    drop(inner_tx);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn ready_outer_items_replace_each_other() {
+    let stream = stream::iter(vec![1, 2]).switch_map(|n| stream::iter(vec![n; 2]));
+    assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![2, 2]);
+}
+
+#[test]
+fn always_ready_outer_stream_yields_to_the_executor() {
+    let (waker, counter) = new_count_waker();
+    let mut cx = Context::from_waker(&waker);
+    let mut stream = stream::repeat(()).switch_map(|()| stream::empty::<i32>());
+
+    // The outer stream never runs dry, so the poll has to give up after a
+    // while and wake itself to carry on.
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(counter, 1);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(counter, 2);
+}
@@ -0,0 +1,59 @@
+use futures::channel::oneshot;
+use futures::future::FutureExt;
+use futures::stream::{self, FusedStream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+
+#[test]
+fn yields_items_in_order() {
+    let mut cx = noop_context();
+    let (txs, rxs): (Vec<_>, Vec<_>) = (0..3).map(|_| oneshot::channel::<()>()).unzip();
+    let mut rxs = rxs.into_iter();
+    let mut stream = stream::iter(0..3)
+        .tap_each_concurrent(None, |_| rxs.next().unwrap().map(Result::unwrap));
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(stream.in_flight(), 3);
+
+    // Later side effects finishing first don't reorder the items.
+    let mut txs = txs.into_iter().rev();
+    txs.next().unwrap().send(()).unwrap();
+    txs.next().unwrap().send(()).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(stream.in_flight(), 3);
+
+    txs.next().unwrap().send(()).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(0)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(stream.is_terminated());
+}
+
+#[test]
+fn respects_limit() {
+    let mut cx = noop_context();
+    let (txs, rxs): (Vec<_>, Vec<_>) = (0..3).map(|_| oneshot::channel::<()>()).unzip();
+    let mut rxs = rxs.into_iter();
+    let mut started = Vec::new();
+    let mut stream = stream::iter(0..3).tap_each_concurrent(2, |x| {
+        started.push(*x);
+        rxs.next().unwrap().map(Result::unwrap)
+    });
+    let mut txs = txs.into_iter();
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(stream.in_flight(), 2);
+
+    txs.next().unwrap().send(()).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(0)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(stream.in_flight(), 2);
+
+    for tx in txs {
+        tx.send(()).unwrap();
+    }
+    let rest = futures::executor::block_on(stream.collect::<Vec<_>>());
+    assert_eq!(rest, vec![1, 2]);
+    assert_eq!(started, vec![0, 1, 2]);
+}
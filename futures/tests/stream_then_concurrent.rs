@@ -0,0 +1,64 @@
+use futures::channel::oneshot;
+use futures::future::{self, FutureExt};
+use futures::stream::{self, FusedStream, StreamExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+
+#[test]
+fn completion_order() {
+    let mut cx = noop_context();
+    let (txs, rxs): (Vec<_>, Vec<_>) = (0..3).map(|_| oneshot::channel::<i32>()).unzip();
+    let mut stream = stream::iter(rxs).then_concurrent(None, |rx| rx.map(Result::unwrap));
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(stream.in_flight(), 3);
+
+    for (i, tx) in txs.into_iter().enumerate().rev() {
+        tx.send(i as i32).unwrap();
+        assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(i as i32)));
+        assert_eq!(stream.in_flight(), i);
+    }
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+}
+
+#[test]
+fn respects_limit() {
+    let mut cx = noop_context();
+    let (txs, rxs): (Vec<_>, Vec<_>) = (0..3).map(|_| oneshot::channel::<i32>()).unzip();
+    let mut stream = stream::iter(rxs).then_concurrent(2, |rx| rx.map(Result::unwrap));
+    let mut txs = txs.into_iter();
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(stream.in_flight(), 2);
+
+    txs.next().unwrap().send(0).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(0)));
+    assert_eq!(stream.in_flight(), 1);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert_eq!(stream.in_flight(), 2);
+
+    for (i, tx) in txs.enumerate() {
+        tx.send(i as i32 + 1).unwrap();
+    }
+    let rest = futures::executor::block_on(stream.collect::<Vec<_>>());
+    assert_eq!(rest, vec![1, 2]);
+}
+
+#[test]
+fn waits_for_slow_final_future() {
+    let mut cx = noop_context();
+    let (tx, rx) = oneshot::channel::<i32>();
+    let futures = vec![rx.map(Result::unwrap).left_future(), future::ready(1).right_future()];
+    let mut stream = stream::iter(futures).then_concurrent(None, |fut| fut);
+
+    // The source stream is exhausted, but the slow future is still running.
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(stream.in_flight(), 1);
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert!(!stream.is_terminated());
+
+    tx.send(2).unwrap();
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(stream.is_terminated());
+}
@@ -0,0 +1,46 @@
+#![feature(async_await)]
+
+use futures::executor::block_on;
+use futures::future::{self, FusedFuture, FutureExt, TryFutureExt};
+use futures_test::future::FutureTestExt;
+use std::cell::RefCell;
+
+#[test]
+fn inspect_ok_and_err_compose() {
+    let seen = RefCell::new(Vec::new());
+
+    let res = block_on(
+        future::ok::<i32, i32>(1)
+            .pending_once()
+            .inspect_ok(|&x| seen.borrow_mut().push(("ok", x)))
+            .inspect_err(|&e| seen.borrow_mut().push(("err", e)))
+    );
+    assert_eq!(res, Ok(1));
+
+    let res = block_on(
+        future::err::<i32, i32>(2)
+            .pending_once()
+            .inspect_ok(|&x| seen.borrow_mut().push(("ok", x)))
+            .inspect_err(|&e| seen.borrow_mut().push(("err", e)))
+    );
+    assert_eq!(res, Err(2));
+
+    assert_eq!(*seen.borrow(), vec![("ok", 1), ("err", 2)]);
+}
+
+#[test]
+fn not_unpin_future() {
+    let mut called = false;
+    let fut = async { Ok::<_, ()>(String::from("hello")) };
+    let res = block_on(fut.inspect_ok(|s| called = s == "hello"));
+    assert_eq!(res, Ok(String::from("hello")));
+    assert!(called);
+}
+
+#[test]
+fn fused_when_inner_is_fused() {
+    let mut fut = future::ok::<i32, i32>(3).fuse().inspect_ok(|_| {}).inspect_err(|_| {});
+    assert!(!fut.is_terminated());
+    assert_eq!(block_on(&mut fut), Ok(3));
+    assert!(fut.is_terminated());
+}
@@ -0,0 +1,57 @@
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use futures::future::{self, FutureExt, TryFutureExt};
+use futures::task::Poll;
+use futures_test::future::FutureTestExt;
+use futures_test::task::noop_context;
+use std::cell::Cell;
+
+#[test]
+fn future_completes_first() {
+    let (_deadline_tx, deadline_rx) = oneshot::channel::<()>();
+    let future = future::ok::<i32, &str>(1).pending_once();
+    assert_eq!(block_on(future.timeout_with_default(deadline_rx, 0)), Ok(1));
+
+    let (_deadline_tx, deadline_rx) = oneshot::channel::<()>();
+    let future = future::err::<i32, &str>("boom").pending_once();
+    assert_eq!(block_on(future.timeout_with_default(deadline_rx, 0)), Err("boom"));
+}
+
+#[test]
+fn deadline_completes_first() {
+    let mut cx = noop_context();
+    let (mut tx, rx) = oneshot::channel::<i32>();
+    let (deadline_tx, deadline_rx) = oneshot::channel::<()>();
+    let mut future = rx.map_err(|_| "canceled").timeout_with_default(deadline_rx, 7);
+
+    assert_eq!(future.poll_unpin(&mut cx), Poll::Pending);
+    deadline_tx.send(()).unwrap();
+    assert_eq!(future.poll_unpin(&mut cx), Poll::Ready(Ok(7)));
+
+    // The timed out future is only dropped along with the combinator.
+    assert!(tx.poll_cancel(&mut cx).is_pending());
+    drop(future);
+    assert!(tx.poll_cancel(&mut cx).is_ready());
+}
+
+#[test]
+fn future_wins_ties() {
+    let future = future::ok::<i32, ()>(1).timeout_with_default(future::ready(()), 0);
+    assert_eq!(block_on(future), Ok(1));
+}
+
+#[test]
+fn fallback_is_only_computed_on_timeout() {
+    let calls = Cell::new(0);
+    let fallback = || {
+        calls.set(calls.get() + 1);
+        0
+    };
+    let future = future::ok::<i32, ()>(1).timeout_with(future::ready(()), fallback);
+    assert_eq!(block_on(future), Ok(1));
+    assert_eq!(calls.get(), 0);
+
+    let future = future::empty::<Result<i32, ()>>().timeout_with(future::ready(()), fallback);
+    assert_eq!(block_on(future), Ok(0));
+    assert_eq!(calls.get(), 1);
+}
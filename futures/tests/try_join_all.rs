@@ -3,7 +3,10 @@
 use futures_util::future::*;
 use futures_util::try_future::{try_join_all, TryJoinAll};
 use std::future::Future;
+use futures::channel::oneshot;
 use futures::executor::block_on;
+use futures::task::Poll;
+use futures_test::task::noop_context;
 use std::fmt::Debug;
 
 fn assert_done<T, F>(actual_fut: F, expected: T)
@@ -20,10 +23,65 @@ fn collect_collects() {
     assert_done(|| Box::new(try_join_all(vec![ok(1), ok(2)])), Ok::<_, usize>(vec![1, 2]));
     assert_done(|| Box::new(try_join_all(vec![ok(1), err(2)])), Err(2));
     assert_done(|| Box::new(try_join_all(vec![ok(1)])), Ok::<_, usize>(vec![1]));
-    // REVIEW: should this be implemented?
-    // assert_done(|| Box::new(try_join_all(Vec::<i32>::new())), Ok(vec![]));
+    assert_done(|| Box::new(try_join_all(Vec::<Ready<Result<i32, ()>>>::new())), Ok(vec![]));
+}
+
+// Both lists which are polled in place and longer ones driven through
+// `FuturesUnordered`.
+const SIZES: &[usize] = &[3, 100];
+
+#[test]
+fn try_join_all_keeps_input_order() {
+    for &n in SIZES {
+        let mut cx = noop_context();
+        let (txs, rxs): (Vec<_>, Vec<_>) = (0..n).map(|_| oneshot::channel::<usize>()).unzip();
+        let mut fut = try_join_all(rxs);
+        assert_eq!(fut.poll_unpin(&mut cx), Poll::Pending);
+
+        for (i, tx) in txs.into_iter().enumerate().rev() {
+            tx.send(i).unwrap();
+            let expected = if i == 0 { Poll::Ready(Ok((0..n).collect())) } else { Poll::Pending };
+            assert_eq!(fut.poll_unpin(&mut cx), expected);
+        }
+    }
+}
+
+#[test]
+fn try_join_all_error_after_successes() {
+    for &n in SIZES {
+        let mut cx = noop_context();
+        let (txs, rxs): (Vec<_>, Vec<_>) = (0..n).map(|_| oneshot::channel::<usize>()).unzip();
+        let mut fut = try_join_all(rxs);
+
+        let mut failing = None;
+        for (i, tx) in txs.into_iter().enumerate() {
+            if i == n / 2 {
+                failing = Some(tx);
+            } else {
+                tx.send(i).unwrap();
+            }
+        }
+        assert_eq!(fut.poll_unpin(&mut cx), Poll::Pending);
+
+        drop(failing);
+        assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(Err(oneshot::Canceled)));
+    }
+}
+
+#[test]
+fn try_join_all_error_before_successes() {
+    for &n in SIZES {
+        let mut cx = noop_context();
+        let (mut txs, rxs): (Vec<_>, Vec<_>) = (0..n).map(|_| oneshot::channel::<usize>()).unzip();
+        let mut fut = try_join_all(rxs);
+        assert_eq!(fut.poll_unpin(&mut cx), Poll::Pending);
 
-    // TODO: needs more tests
+        // The last future fails while the others are still running: the whole
+        // future fails right away and the others are dropped.
+        drop(txs.pop());
+        assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(Err(oneshot::Canceled)));
+        assert!(txs.iter().all(|tx| tx.is_canceled()));
+    }
 }
 
 #[test]
@@ -0,0 +1,65 @@
+use futures::executor::block_on;
+use futures::future::{err, ok, try_select_all, try_select_all_ok, TrySelectAllError};
+use futures::channel::oneshot;
+use futures::future::{Future, FutureExt};
+use futures::task::Poll;
+use futures_test::task::noop_context;
+use std::pin::Pin;
+
+#[test]
+fn first_success() {
+    let v = vec![ok(1), err(2), ok(3)];
+
+    let (i, idx, v) = block_on(try_select_all(v)).ok().unwrap();
+    assert_eq!(i, 1);
+    assert_eq!(idx, 0);
+    assert_eq!(v.len(), 2);
+
+    assert_eq!(block_on(try_select_all(v)).err(), Some(TrySelectAllError::Failed(vec![2])));
+}
+
+#[test]
+fn empty() {
+    let v: Vec<futures::future::Ready<Result<i32, i32>>> = Vec::new();
+    assert_eq!(block_on(try_select_all(v)).err(), Some(TrySelectAllError::Empty));
+
+    let v: Vec<futures::future::Ready<Result<i32, i32>>> = Vec::new();
+    assert_eq!(block_on(try_select_all_ok(v)).err(), Some(TrySelectAllError::Empty));
+}
+
+#[test]
+fn skip_errors() {
+    let v = vec![err(1), err(2), ok(3), ok(4)];
+
+    let (i, v) = block_on(try_select_all_ok(v)).ok().unwrap();
+    assert_eq!(i, 3);
+    assert_eq!(v.len(), 1);
+
+    let v = vec![err::<i32, _>(1), err(2), err(3)];
+    assert_eq!(block_on(try_select_all_ok(v)).err(), Some(TrySelectAllError::Failed(vec![1, 2, 3])));
+}
+
+#[test]
+fn skip_errors_keeps_racing() {
+    let mut cx = noop_context();
+    let (tx1, rx1) = oneshot::channel::<Result<i32, i32>>();
+    let (tx2, rx2) = oneshot::channel::<Result<i32, i32>>();
+    let (tx3, rx3) = oneshot::channel::<Result<i32, i32>>();
+    let replicas = vec![rx1, rx2, rx3]
+        .into_iter()
+        .map(|rx| rx.map(|res| res.unwrap_or(Err(-1))));
+    let mut fut = try_select_all_ok(replicas);
+
+    assert!(Pin::new(&mut fut).poll(&mut cx).is_pending());
+    tx2.send(Err(2)).unwrap();
+    assert!(Pin::new(&mut fut).poll(&mut cx).is_pending());
+    tx3.send(Ok(3)).unwrap();
+    match Pin::new(&mut fut).poll(&mut cx) {
+        Poll::Ready(Ok((value, rest))) => {
+            assert_eq!(value, 3);
+            assert_eq!(rest.len(), 1);
+        }
+        _ => panic!("expected the success of the third replica"),
+    }
+    drop(tx1);
+}
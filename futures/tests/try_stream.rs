@@ -0,0 +1,649 @@
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use futures_test::future::FutureTestExt;
+use futures_test::stream::StreamTestExt;
+
+#[test]
+fn try_concat() {
+    let chunks = (0..10_000).map(|i| Ok::<_, ()>(vec![i as u8; 100]));
+    let expected: Vec<u8> = chunks.clone().flat_map(Result::unwrap).collect();
+    let body = block_on(stream::iter(chunks).interleave_pending().try_concat()).unwrap();
+    assert_eq!(body, expected);
+
+    // The bytes of strings concatenate to the concatenated string.
+    let words = ["höher", " ", "", "schneller", " ", "weiter"];
+    let chunks = words.iter().map(|w| Ok::<_, ()>(w.as_bytes().to_vec()));
+    let body = block_on(stream::iter(chunks).try_concat()).unwrap();
+    assert_eq!(String::from_utf8(body).unwrap(), words.concat());
+
+    assert_eq!(block_on(stream::empty::<Result<Vec<u8>, ()>>().try_concat()), Ok(Vec::new()));
+    let chunks = vec![Ok(vec![1]), Err("oops"), Ok(vec![2])];
+    assert_eq!(block_on(stream::iter(chunks).try_concat()), Err("oops"));
+}
+
+#[test]
+fn try_concat_custom_accumulator() {
+    // Keeps its chunks apart, to check that every chunk but the first is
+    // appended exactly once and the first one is taken over as is.
+    #[derive(Debug, Default, PartialEq)]
+    struct Rope(Vec<Vec<u8>>);
+
+    impl IntoIterator for Rope {
+        type Item = Vec<u8>;
+        type IntoIter = std::vec::IntoIter<Vec<u8>>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.0.into_iter()
+        }
+    }
+
+    impl Extend<Vec<u8>> for Rope {
+        fn extend<I: IntoIterator<Item = Vec<u8>>>(&mut self, iter: I) {
+            self.0.extend(iter)
+        }
+    }
+
+    let chunks = vec![
+        Ok::<_, ()>(Rope(vec![b"ab".to_vec(), b"c".to_vec()])),
+        Ok(Rope(vec![b"d".to_vec()])),
+        Ok(Rope::default()),
+        Ok(Rope(vec![b"ef".to_vec()])),
+    ];
+    let rope = block_on(stream::iter(chunks).interleave_pending().try_concat()).unwrap();
+    assert_eq!(rope, Rope(vec![b"ab".to_vec(), b"c".to_vec(), b"d".to_vec(), b"ef".to_vec()]));
+}
+
+#[test]
+fn try_concat_with_capacity() {
+    let chunks = vec![Ok::<_, ()>(b"hello ".to_vec()), Ok(Vec::new()), Ok(b"world".to_vec())];
+    let body = block_on(stream::iter(chunks).interleave_pending().try_concat_with_capacity(64))
+        .unwrap();
+    assert_eq!(body, b"hello world");
+    assert!(body.capacity() >= 64);
+
+    // Growing past the capacity hint keeps all elements.
+    let chunks = (0..10u8).map(|i| Ok::<_, ()>(vec![i; 10]));
+    let body = block_on(stream::iter(chunks).try_concat_with_capacity(1)).unwrap();
+    assert_eq!(body.len(), 100);
+    assert_eq!(&body[95..], &[9; 5]);
+}
+
+#[test]
+fn try_concat_with_capacity_on_generic_try_stream() {
+    use futures::future::Future;
+    use futures::stream::TryStream;
+
+    // Only `TryStream` needs to be known about the stream to get a future.
+    fn concat<St>(st: St) -> impl Future<Output = Result<Vec<u8>, St::Error>>
+        where St: TryStream<Ok = Vec<u8>>,
+    {
+        st.try_concat_with_capacity(4)
+    }
+
+    let chunks = vec![Ok::<_, ()>(vec![1, 2]), Ok(vec![3])];
+    assert_eq!(block_on(concat(stream::iter(chunks))), Ok(vec![1, 2, 3]));
+}
+
+#[test]
+fn try_concat_with_capacity_error() {
+    let chunks = vec![Ok(vec![1]), Err("oops"), Ok(vec![2])];
+    assert_eq!(block_on(stream::iter(chunks).try_concat_with_capacity(4)), Err("oops"));
+}
+
+#[test]
+fn try_for_each_concurrent_aborts_on_error() {
+    use futures::channel::{mpsc, oneshot};
+    use futures::future::{FusedFuture, FutureExt};
+    use futures::task::Poll;
+    use futures_test::task::noop_context;
+    use std::cell::RefCell;
+
+    let mut cx = noop_context();
+    let (items_tx, items_rx) = mpsc::unbounded();
+    let senders = RefCell::new(Vec::new());
+    let mut fut = items_rx.map(Ok).try_for_each_concurrent(None, |_: i32| {
+        // Slow work which only finishes when told to.
+        let (tx, rx) = oneshot::channel::<Result<(), &str>>();
+        senders.borrow_mut().push(tx);
+        rx.map(Result::unwrap)
+    });
+
+    items_tx.unbounded_send(1).unwrap();
+    items_tx.unbounded_send(2).unwrap();
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Pending);
+    assert_eq!(senders.borrow().len(), 2);
+
+    // Another item is ready when the second future fails.
+    items_tx.unbounded_send(3).unwrap();
+    senders.borrow_mut().pop().unwrap().send(Err("boom")).unwrap();
+    assert_eq!(fut.poll_unpin(&mut cx), Poll::Ready(Err("boom")));
+    assert!(fut.is_terminated());
+    // The item wasn't pulled, and the stream has been dropped.
+    assert_eq!(senders.borrow().len(), 1);
+    assert!(items_tx.is_closed());
+    // The slow future was dropped rather than awaited.
+    assert!(senders.borrow()[0].is_canceled());
+    drop(fut);
+
+    // An error from the stream cancels the futures in flight just the same.
+    let senders = RefCell::new(Vec::new());
+    let st = stream::iter(vec![Ok(1), Ok(2), Err("stream"), Ok(4)]).interleave_pending();
+    let fut = st.try_for_each_concurrent(None, |_| {
+        let (tx, rx) = oneshot::channel::<()>();
+        senders.borrow_mut().push(tx);
+        rx.map(|_| Ok(()))
+    });
+    assert_eq!(block_on(fut), Err("stream"));
+    assert_eq!(senders.borrow().len(), 2);
+    assert!(senders.borrow().iter().all(|tx| tx.is_canceled()));
+}
+
+#[test]
+fn try_fold_with_checkpoint() {
+    use futures::future;
+    use std::cell::RefCell;
+
+    let saved = RefCell::new(Vec::new());
+    let fold = stream::iter((1..=7).map(Ok::<i32, i32>))
+        .interleave_pending()
+        .try_fold_with_checkpoint(
+            0,
+            |acc, x| future::ready(Ok(acc + x)).pending_once(),
+            3,
+            |acc| {
+                saved.borrow_mut().push(*acc);
+                future::ready(Ok(())).pending_once()
+            },
+        );
+    assert_eq!(block_on(fold), Ok(28));
+    assert_eq!(*saved.borrow(), vec![6, 21]);
+
+    // A failing checkpoint ends the fold.
+    let fold = stream::iter((1..=7).map(Ok::<i32, i32>))
+        .try_fold_with_checkpoint(
+            0,
+            |acc, x| future::ready(Ok(acc + x)),
+            2,
+            |acc| future::ready(if *acc > 5 { Err(*acc) } else { Ok(()) }),
+        );
+    assert_eq!(block_on(fold), Err(10));
+}
+
+#[test]
+fn try_last_and_try_nth() {
+    let st = stream::iter(vec![Ok::<i32, i32>(1), Ok(2)]).interleave_pending();
+    assert_eq!(block_on(st.try_last()), Ok(Some(2)));
+    assert_eq!(block_on(stream::iter(Vec::<Result<i32, i32>>::new()).try_last()), Ok(None));
+    assert_eq!(block_on(stream::iter(vec![Ok(1), Err(2), Ok(3)]).try_last()), Err(2));
+
+    let st = stream::iter(vec![Ok::<i32, i32>(1), Ok(2), Err(3)]).interleave_pending();
+    assert_eq!(block_on(st.try_nth(1)), Ok(Some(2)));
+    assert_eq!(block_on(stream::iter(vec![Ok::<i32, i32>(1)]).try_nth(1)), Ok(None));
+    assert_eq!(block_on(stream::iter(vec![Ok(1), Err(2), Ok(3)]).try_nth(2)), Err(2));
+}
+
+#[test]
+fn try_flatten_unordered() {
+    use futures::channel::mpsc;
+    use futures::stream::{FusedStream, StreamExt};
+    use futures::task::Poll;
+    use futures_test::task::noop_context;
+
+    let mut cx = noop_context();
+    let (outer_tx, outer_rx) = mpsc::unbounded();
+    let (tx1, rx1) = mpsc::unbounded::<Result<i32, &str>>();
+    let (tx2, rx2) = mpsc::unbounded::<Result<i32, &str>>();
+    let mut st = outer_rx.try_flatten_unordered(None);
+
+    outer_tx.unbounded_send(Ok(rx1)).unwrap();
+    outer_tx.unbounded_send(Err("outer")).unwrap();
+    outer_tx.unbounded_send(Ok(rx2)).unwrap();
+    assert_eq!(st.poll_next_unpin(&mut cx), Poll::Ready(Some(Err("outer"))));
+    assert_eq!(st.poll_next_unpin(&mut cx), Poll::Pending);
+
+    // An error from one inner stream doesn't affect the other ones.
+    tx1.unbounded_send(Err("inner")).unwrap();
+    assert_eq!(st.poll_next_unpin(&mut cx), Poll::Ready(Some(Err("inner"))));
+    tx2.unbounded_send(Ok(2)).unwrap();
+    assert_eq!(st.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(2))));
+    tx1.unbounded_send(Ok(1)).unwrap();
+    assert_eq!(st.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(1))));
+
+    // The stream only ends once the outer and all inner streams have ended.
+    drop(outer_tx);
+    drop(tx1);
+    assert_eq!(st.poll_next_unpin(&mut cx), Poll::Pending);
+    assert!(!st.is_terminated());
+    tx2.unbounded_send(Ok(3)).unwrap();
+    drop(tx2);
+    assert_eq!(st.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(3))));
+    assert_eq!(st.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(st.is_terminated());
+}
+
+#[test]
+fn try_flatten_unordered_limit() {
+    // With a limit of one, the inner streams are drained one after another.
+    let inner = |range: std::ops::Range<i32>| {
+        stream::iter(range.map(Ok::<_, ()>)).interleave_pending()
+    };
+    let st = stream::iter(vec![Ok(inner(0..3)), Ok(inner(3..5)), Ok(inner(5..6))])
+        .interleave_pending()
+        .try_flatten_unordered(1);
+    assert_eq!(block_on(st.try_collect::<Vec<_>>()), Ok(vec![0, 1, 2, 3, 4, 5]));
+
+    let st = stream::iter(vec![Ok(inner(0..3)), Ok(inner(3..5)), Ok(inner(5..6))])
+        .try_flatten_unordered(2);
+    let mut items = block_on(st.try_collect::<Vec<_>>()).unwrap();
+    items.sort();
+    assert_eq!(items, vec![0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn try_flatten_unordered_refills_ended_slot() {
+    use futures::channel::mpsc;
+    use futures::stream::{BoxStream, StreamExt};
+    use futures::task::Poll;
+    use futures_test::task::noop_context;
+
+    // When an inner stream ends while the others are pending, its slot goes
+    // to the next inner stream right away.
+    let mut cx = noop_context();
+    let (_tx_a, rx_a) = mpsc::unbounded::<Result<i32, ()>>();
+    let (tx_b, rx_b) = mpsc::unbounded::<Result<i32, ()>>();
+    let inners: Vec<Result<BoxStream<'static, Result<i32, ()>>, ()>> = vec![
+        Ok(rx_a.boxed()),
+        Ok(rx_b.boxed()),
+        Ok(stream::iter(vec![Ok(99)]).boxed()),
+    ];
+    let mut st = stream::iter(inners).try_flatten_unordered(2);
+
+    assert_eq!(st.poll_next_unpin(&mut cx), Poll::Pending);
+    drop(tx_b);
+    assert_eq!(st.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(99))));
+    assert_eq!(st.poll_next_unpin(&mut cx), Poll::Pending);
+}
+
+#[test]
+fn try_chunks() {
+    use futures::stream::{StreamExt, TryChunksError};
+
+    let st = stream::iter(vec![Ok(1), Ok(2), Ok(3), Ok(4), Ok(5), Ok(6), Ok(7)])
+        .interleave_pending()
+        .try_chunks(3);
+    assert_eq!(
+        block_on(st.collect::<Vec<Result<_, TryChunksError<_, ()>>>>()),
+        vec![Ok(vec![1, 2, 3]), Ok(vec![4, 5, 6]), Ok(vec![7])],
+    );
+
+    // An error mid-batch hands back the stranded items, and batching carries
+    // on with the items that follow it.
+    let st = stream::iter(vec![Ok(1), Ok(2), Ok(3), Err("a"), Err("b"), Ok(4), Ok(5), Ok(6)])
+        .interleave_pending()
+        .try_chunks(2);
+    assert_eq!(block_on(st.collect::<Vec<_>>()), vec![
+        Ok(vec![1, 2]),
+        Err(TryChunksError(vec![3], "a")),
+        Err(TryChunksError(vec![], "b")),
+        Ok(vec![4, 5]),
+        Ok(vec![6]),
+    ]);
+}
+
+#[test]
+#[should_panic]
+fn try_chunks_zero_capacity() {
+    let _ = stream::iter(vec![Ok::<i32, ()>(1)]).try_chunks(0);
+}
+
+#[test]
+fn try_ready_chunks() {
+    use futures::channel::mpsc;
+    use futures::stream::{FusedStream, StreamExt, TryReadyChunksError};
+    use futures::task::Poll;
+    use futures_test::task::noop_context;
+
+    let mut cx = noop_context();
+    let (tx, rx) = mpsc::unbounded::<Result<i32, &str>>();
+    let mut st = rx.try_ready_chunks(3);
+
+    assert_eq!(st.poll_next_unpin(&mut cx), Poll::Pending);
+
+    // Whatever is ready is yielded, up to the capacity.
+    for i in 1..=4 {
+        tx.unbounded_send(Ok(i)).unwrap();
+    }
+    assert_eq!(st.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(vec![1, 2, 3]))));
+    assert_eq!(st.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(vec![4]))));
+    assert_eq!(st.poll_next_unpin(&mut cx), Poll::Pending);
+
+    // An error hands back the stranded items, and batching carries on.
+    tx.unbounded_send(Ok(5)).unwrap();
+    tx.unbounded_send(Err("a")).unwrap();
+    tx.unbounded_send(Err("b")).unwrap();
+    tx.unbounded_send(Ok(6)).unwrap();
+    assert_eq!(
+        st.poll_next_unpin(&mut cx),
+        Poll::Ready(Some(Err(TryReadyChunksError(vec![5], "a")))),
+    );
+    assert_eq!(
+        st.poll_next_unpin(&mut cx),
+        Poll::Ready(Some(Err(TryReadyChunksError(vec![], "b")))),
+    );
+    assert_eq!(st.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(vec![6]))));
+
+    // The buffered batch is yielded before the end of the stream.
+    tx.unbounded_send(Ok(7)).unwrap();
+    drop(tx);
+    assert_eq!(st.poll_next_unpin(&mut cx), Poll::Ready(Some(Ok(vec![7]))));
+    assert_eq!(st.poll_next_unpin(&mut cx), Poll::Ready(None));
+    assert!(st.is_terminated());
+
+    // Items split by pending polls end up in separate batches.
+    let st = stream::iter(vec![Ok::<i32, ()>(1), Ok(2), Ok(3)])
+        .interleave_pending()
+        .try_ready_chunks(2);
+    assert_eq!(
+        block_on(st.collect::<Vec<_>>()),
+        vec![Ok(vec![1]), Ok(vec![2]), Ok(vec![3])],
+    );
+}
+
+#[test]
+fn try_take_while() {
+    use futures::future;
+    use futures::stream::{FusedStream, StreamExt};
+    use futures::task::Poll;
+    use std::rc::Rc;
+
+    // Panics if the inner stream is polled after the adapter terminated.
+    fn then_panic<T: 'static>(items: Vec<T>) -> impl futures::stream::Stream<Item = T> {
+        stream::iter(items).chain(stream::poll_fn(|_| -> Poll<Option<T>> {
+            panic!("polled after termination")
+        }))
+    }
+
+    // The boundary item isn't yielded.
+    let mut st = then_panic(vec![Ok::<i32, i32>(1), Ok(2), Ok(5)])
+        .interleave_pending()
+        .try_take_while(|x| future::ready(Ok(*x < 3)))
+        .interleave_pending();
+    assert_eq!(block_on(st.next()), Some(Ok(1)));
+    assert_eq!(block_on(st.next()), Some(Ok(2)));
+    assert_eq!(block_on(st.next()), None);
+    assert!(st.get_ref().is_terminated());
+    assert_eq!(block_on(st.next()), None);
+
+    // A predicate error is yielded, and the item it failed on is dropped.
+    let item = Rc::new(2);
+    let mut st = then_panic(vec![Ok::<_, i32>(Rc::new(1)), Ok(item.clone())])
+        .try_take_while(|x| future::ready(if **x == 2 { Err(-2) } else { Ok(true) }));
+    assert_eq!(block_on(st.next()), Some(Ok(Rc::new(1))));
+    assert_eq!(block_on(st.next()), Some(Err(-2)));
+    assert_eq!(Rc::strong_count(&item), 1);
+    assert_eq!(block_on(st.next()), None);
+
+    // So is an error of the stream.
+    let mut st = then_panic(vec![Ok::<i32, i32>(1), Err(9)])
+        .try_take_while(|_| future::ready(Ok(true)));
+    assert_eq!(block_on(st.next()), Some(Ok(1)));
+    assert_eq!(block_on(st.next()), Some(Err(9)));
+    assert_eq!(block_on(st.next()), None);
+}
+
+#[test]
+fn try_skip_while() {
+    use futures::future;
+    use futures::stream::StreamExt;
+    use std::cell::Cell;
+
+    let calls = Cell::new(0);
+    let st = stream::iter(vec![Ok::<i32, i32>(1), Err(-1), Ok(2), Ok(3), Ok(4), Ok(1), Err(-2), Ok(0)])
+        .interleave_pending()
+        .try_skip_while(|x| {
+            calls.set(calls.get() + 1);
+            let res = if *x == 2 { Err(-*x) } else { Ok(*x < 4) };
+            future::ready(res).interleave_pending()
+        });
+    assert_eq!(
+        block_on(st.collect::<Vec<_>>()),
+        // 1 and 3 are skipped and 2 is dropped with its predicate error, but
+        // nothing after 4 is.
+        vec![Err(-1), Err(-2), Ok(4), Ok(1), Err(-2), Ok(0)],
+    );
+    // The predicate isn't called anymore once it resolved to `Ok(false)`.
+    assert_eq!(calls.get(), 4);
+}
+
+#[test]
+fn try_all_and_try_any() {
+    use futures::future::{self, FusedFuture};
+    use futures::stream::StreamExt;
+    use futures::task::Poll;
+    use std::cell::Cell;
+
+    // Panics if the stream is polled past the item deciding the outcome.
+    fn deciding_then_panic(items: Vec<Result<i32, i32>>) -> impl futures::stream::Stream<Item = Result<i32, i32>> {
+        stream::iter(items).chain(stream::poll_fn(|_| -> Poll<Option<Result<i32, i32>>> {
+            panic!("polled after the outcome was decided")
+        }))
+    }
+
+    let even = |x: i32| future::ready(x % 2 == 0).interleave_pending();
+
+    assert_eq!(block_on(stream::iter(vec![Ok::<i32, i32>(2), Ok(4)]).try_all(even)), Ok(true));
+    assert_eq!(block_on(stream::iter(vec![Ok::<i32, i32>(1), Ok(3)]).try_any(even)), Ok(false));
+    assert_eq!(block_on(stream::empty::<Result<i32, i32>>().try_all(even)), Ok(true));
+    assert_eq!(block_on(stream::empty::<Result<i32, i32>>().try_any(even)), Ok(false));
+
+    // An error before the decision is the outcome.
+    assert_eq!(block_on(stream::iter(vec![Ok(2), Err(-1), Ok(3)]).try_all(even)), Err(-1));
+    assert_eq!(block_on(stream::iter(vec![Ok(1), Err(-1), Ok(2)]).try_any(even)), Err(-1));
+
+    // One after it isn't looked at, and neither is the predicate called.
+    let calls = Cell::new(0);
+    let counted = |x: i32| {
+        calls.set(calls.get() + 1);
+        even(x)
+    };
+    let mut fut = deciding_then_panic(vec![Ok(2), Ok(3)]).interleave_pending().try_all(counted);
+    assert_eq!(block_on(&mut fut), Ok(false));
+    assert!(fut.is_terminated());
+    assert_eq!(calls.get(), 2);
+
+    calls.set(0);
+    let counted = |x: i32| {
+        calls.set(calls.get() + 1);
+        even(x)
+    };
+    let mut fut = deciding_then_panic(vec![Ok(1), Ok(2)]).interleave_pending().try_any(counted);
+    assert_eq!(block_on(&mut fut), Ok(true));
+    assert!(fut.is_terminated());
+    assert_eq!(calls.get(), 2);
+}
+
+#[test]
+fn try_flatten() {
+    use futures::stream::{FusedStream, StreamExt};
+
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        Outer(&'static str),
+        Inner(i32),
+    }
+
+    impl From<i32> for Error {
+        fn from(e: i32) -> Error {
+            Error::Inner(e)
+        }
+    }
+
+    let mut st = stream::iter(vec![
+        Ok(stream::iter(vec![Ok(1), Ok(2)]).interleave_pending()),
+        Err(Error::Outer("skipped")),
+        Ok(stream::iter(vec![]).interleave_pending()),
+        Ok(stream::iter(vec![Err(-1), Ok(3)]).interleave_pending()),
+    ])
+    .interleave_pending()
+    .try_flatten()
+    .fuse();
+    assert_eq!(block_on(st.next()), Some(Ok(1)));
+    assert_eq!(block_on(st.next()), Some(Ok(2)));
+    // An error of the outer stream is yielded in place of an inner stream.
+    assert_eq!(block_on(st.next()), Some(Err(Error::Outer("skipped"))));
+    // Errors of the inner streams are converted and don't end them.
+    assert_eq!(block_on(st.next()), Some(Err(Error::Inner(-1))));
+    assert_eq!(block_on(st.next()), Some(Ok(3)));
+    assert_eq!(block_on(st.next()), None);
+    assert!(st.is_terminated());
+}
+
+#[test]
+fn try_flatten_nested_byte_streams() {
+    use futures::io::AsyncReadExt;
+    use std::io;
+
+    fn body(chunks: Vec<&'static [u8]>) -> impl futures::stream::Stream<Item = io::Result<Vec<u8>>> {
+        stream::iter(chunks.into_iter().map(|chunk| Ok(chunk.to_vec()))).interleave_pending()
+    }
+
+    let responses = stream::iter(vec![
+        Ok::<_, io::Error>(stream::iter(vec![
+            Ok::<_, io::Error>(body(vec![b"hello", b" "])),
+            Ok(body(vec![])),
+        ])),
+        Ok(stream::iter(vec![Ok(body(vec![b"wor", b"ld"]))])),
+    ]);
+
+    let mut reader = responses.try_flatten().try_flatten().into_async_read();
+    let mut buf = Vec::new();
+    block_on(reader.read_to_end(&mut buf)).unwrap();
+    assert_eq!(buf, b"hello world");
+}
+
+#[test]
+fn inspect_ok_and_inspect_err() {
+    use futures::sink::SinkExt;
+    use futures::stream::{FusedStream, StreamExt};
+    use std::cell::RefCell;
+
+    let seen = RefCell::new(Vec::new());
+    let mut st = stream::iter(vec![Ok(1), Err(-1), Ok(2)])
+        .inspect_ok(|x| seen.borrow_mut().push(*x))
+        .inspect_err(|e| seen.borrow_mut().push(*e))
+        .fuse();
+    assert_eq!(block_on(st.next()), Some(Ok(1)));
+    assert_eq!(block_on(st.next()), Some(Err(-1)));
+    assert_eq!(block_on(st.next()), Some(Ok(2)));
+    assert_eq!(block_on(st.next()), None);
+    assert!(st.is_terminated());
+    assert_eq!(*seen.borrow(), vec![1, -1, 2]);
+
+    // The sink half of the underlying type keeps working.
+    let echo = Echo(std::collections::VecDeque::new())
+        .inspect_ok(|x| seen.borrow_mut().push(*x))
+        .inspect_err(|e| seen.borrow_mut().push(*e));
+    let mut echo = echo.fuse();
+    block_on(echo.send(Ok(3))).unwrap();
+    block_on(echo.send(Err(-3))).unwrap();
+    assert_eq!(block_on(echo.next()), Some(Ok(3)));
+    assert_eq!(block_on(echo.next()), Some(Err(-3)));
+    assert_eq!(*seen.borrow(), vec![1, -1, 2, 3, -3]);
+}
+
+// Yields the items sent into it.
+struct Echo(std::collections::VecDeque<Result<i32, i32>>);
+
+impl futures::stream::Stream for Echo {
+    type Item = Result<i32, i32>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        _: &mut futures::task::Context<'_>,
+    ) -> futures::task::Poll<Option<Self::Item>> {
+        futures::task::Poll::Ready(self.0.pop_front())
+    }
+}
+
+impl futures::sink::Sink<Result<i32, i32>> for Echo {
+    type SinkError = ();
+
+    fn poll_ready(
+        self: std::pin::Pin<&mut Self>,
+        _: &mut futures::task::Context<'_>,
+    ) -> futures::task::Poll<Result<(), ()>> {
+        futures::task::Poll::Ready(Ok(()))
+    }
+
+    fn start_send(mut self: std::pin::Pin<&mut Self>, item: Result<i32, i32>) -> Result<(), ()> {
+        self.0.push_back(item);
+        Ok(())
+    }
+
+    fn poll_flush(
+        self: std::pin::Pin<&mut Self>,
+        _: &mut futures::task::Context<'_>,
+    ) -> futures::task::Poll<Result<(), ()>> {
+        futures::task::Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(
+        self: std::pin::Pin<&mut Self>,
+        _: &mut futures::task::Context<'_>,
+    ) -> futures::task::Poll<Result<(), ()>> {
+        futures::task::Poll::Ready(Ok(()))
+    }
+}
+
+#[test]
+fn or_else() {
+    use futures::future;
+    use futures::stream::{Stream, StreamExt, TryStream};
+
+    // Recovery futures which are pending several times, and which fail.
+    let st = stream::iter(vec![Ok(1), Err(2), Ok(3), Err(-4), Err(5)])
+        .interleave_pending()
+        .or_else(|e: i32| {
+            let res = if e > 0 { Ok(e * 10) } else { Err(format!("{} is negative", e)) };
+            future::ready(res).pending_once().pending_once().pending_once()
+        });
+    assert_eq!(
+        block_on(st.collect::<Vec<_>>()),
+        vec![Ok(1), Ok(20), Ok(3), Err("-4 is negative".to_string()), Ok(50)],
+    );
+
+    // Only `TryStream` needs to be known about the stream.
+    fn recover<St>(st: St) -> impl Stream<Item = Result<St::Ok, St::Error>>
+        where St: TryStream + Unpin,
+    {
+        st.or_else(future::err)
+    }
+
+    let st = recover(stream::iter(vec![Ok::<i32, i32>(1), Err(2)]));
+    assert_eq!(block_on(st.collect::<Vec<_>>()), vec![Ok(1), Err(2)]);
+}
+
+#[test]
+fn inspect_ok_and_inspect_err_on_generic_try_stream() {
+    use futures::stream::{Stream, StreamExt, TryStream};
+
+    // Only `TryStream` is known about `St`, which is enough to get a `Stream`.
+    fn inspect<St: TryStream + Unpin>(st: St) -> impl Stream<Item = Result<St::Ok, St::Error>> {
+        st.inspect_ok(|_| ()).inspect_err(|_| ())
+    }
+
+    let mut st = inspect(stream::iter(vec![Ok::<i32, i32>(1), Err(2)]));
+    assert_eq!(block_on(st.next()), Some(Ok(1)));
+    assert_eq!(block_on(st.next()), Some(Err(2)));
+    assert_eq!(block_on(st.next()), None);
+}
+
+#[test]
+fn try_stream_trait_object() {
+    use futures::stream::TryStream;
+
+    // `TryStream` objects don't need to name the `Item` of `Stream`.
+    let mut st: Box<dyn TryStream<Ok = i32, Error = ()> + Unpin> =
+        Box::new(stream::iter(vec![Ok(1)]));
+    assert_eq!(block_on(st.try_next()), Ok(Some(1)));
+}
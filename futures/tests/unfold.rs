@@ -1,5 +1,5 @@
 use futures::future;
-use futures::stream;
+use futures::stream::{self, FusedStream};
 
 use futures_test::{
     assert_stream_pending, assert_stream_next, assert_stream_done,
@@ -33,3 +33,45 @@ fn unfold1() {
     assert_stream_pending!(stream);
     assert_stream_done!(stream);
 }
+
+#[test]
+fn try_unfold() {
+    let mut stream = stream::try_unfold(0, |state| {
+        if state <= 1 {
+            future::ready(Ok(Some((state * 2, state + 1)))).pending_once()
+        } else if state == 2 {
+            future::ready(Err(state)).pending_once()
+        } else {
+            unreachable!("closure called after an error")
+        }
+    });
+
+    assert_stream_pending!(stream);
+    assert_stream_next!(stream, Ok(0));
+
+    assert_stream_pending!(stream);
+    assert_stream_next!(stream, Ok(2));
+
+    // The error is yielded, after which the stream is fused
+    assert_stream_pending!(stream);
+    assert_stream_next!(stream, Err(2));
+    assert_stream_done!(stream);
+    assert!(stream.is_terminated());
+    assert_stream_done!(stream);
+}
+
+#[test]
+fn try_unfold_none() {
+    let mut stream = stream::try_unfold(0, |state| {
+        if state == 0 {
+            future::ready(Ok::<_, ()>(Some((state, state + 1))))
+        } else {
+            future::ready(Ok(None))
+        }
+    });
+
+    assert_stream_next!(stream, Ok(0));
+    assert_stream_done!(stream);
+    assert!(stream.is_terminated());
+    assert_stream_done!(stream);
+}